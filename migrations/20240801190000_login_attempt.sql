@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS "LoginAttempt" (
+    source text NOT NULL,
+    source_type smallint NOT NULL,
+    failed_count integer DEFAULT 0 NOT NULL,
+    last_failed_datetime timestamp without time zone NOT NULL,
+    locked_until_datetime timestamp without time zone
+);
+
+ALTER TABLE ONLY "LoginAttempt"
+    ADD CONSTRAINT "LoginAttempt_pk" PRIMARY KEY (source_type, source);
@@ -0,0 +1,25 @@
+CREATE TABLE IF NOT EXISTS "UserTotp" (
+    user_id uuid NOT NULL,
+    secret text NOT NULL,
+    enabled boolean DEFAULT false NOT NULL,
+    last_used_step bigint,
+    added_datetime timestamp without time zone NOT NULL,
+    updated_datetime timestamp without time zone NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "UserRecoveryCode" (
+    user_id uuid NOT NULL,
+    code_hash text NOT NULL
+);
+
+ALTER TABLE ONLY "UserTotp"
+    ADD CONSTRAINT "UserTotp_pk" PRIMARY KEY (user_id);
+
+ALTER TABLE ONLY "UserRecoveryCode"
+    ADD CONSTRAINT "UserRecoveryCode_pk" PRIMARY KEY (user_id, code_hash);
+
+ALTER TABLE ONLY "UserTotp"
+    ADD CONSTRAINT "UserTotp_fk0" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "UserRecoveryCode"
+    ADD CONSTRAINT "UserRecoveryCode_fk0" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS "Invitation" (
+    id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    code text NOT NULL,
+    expiry_datetime timestamp without time zone NOT NULL,
+    used_by_user_id uuid,
+    used_datetime timestamp without time zone,
+    added_datetime timestamp without time zone NOT NULL,
+    updated_datetime timestamp without time zone NOT NULL
+);
+
+ALTER TABLE ONLY "Invitation"
+    ADD CONSTRAINT "Invitation_pk" PRIMARY KEY (id);
+
+ALTER TABLE ONLY "Invitation"
+    ADD CONSTRAINT "Invitation_unique" UNIQUE (code);
+
+ALTER TABLE ONLY "Invitation"
+    ADD CONSTRAINT "Invitation_fk0" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "Invitation"
+    ADD CONSTRAINT "Invitation_fk1" FOREIGN KEY (used_by_user_id) REFERENCES "User"(id) ON DELETE SET NULL;
@@ -0,0 +1 @@
+ALTER TABLE "User" ADD COLUMN IF NOT EXISTS must_change_password boolean DEFAULT false NOT NULL;
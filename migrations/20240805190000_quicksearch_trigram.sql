@@ -0,0 +1,12 @@
+CREATE EXTENSION IF NOT EXISTS unaccent;
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- unaccent is only stable as its dictionary could change, wrapped so it can be used on indexes
+CREATE OR REPLACE FUNCTION immutable_unaccent(text) RETURNS text AS
+$$ SELECT public.unaccent('public.unaccent', $1) $$
+LANGUAGE sql IMMUTABLE PARALLEL SAFE STRICT;
+
+CREATE INDEX IF NOT EXISTS "Game_name_trgm_idx" ON "Game" USING gin (immutable_unaccent(lower(name)) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS "DLC_name_trgm_idx" ON "DLC" USING gin (immutable_unaccent(lower(name)) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS "Platform_name_trgm_idx" ON "Platform" USING gin (immutable_unaccent(lower(name)) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS "Tag_name_trgm_idx" ON "Tag" USING gin (immutable_unaccent(lower(name)) gin_trgm_ops);
@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS "SavedSearch" (
+    id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    name text NOT NULL,
+    type smallint NOT NULL,
+    search text NOT NULL,
+    added_datetime timestamp without time zone NOT NULL,
+    updated_datetime timestamp without time zone NOT NULL
+);
+
+ALTER TABLE ONLY "SavedSearch"
+    ADD CONSTRAINT "SavedSearch_pk" PRIMARY KEY (id);
+
+ALTER TABLE ONLY "SavedSearch"
+    ADD CONSTRAINT "SavedSearch_unique" UNIQUE (user_id, type, name);
+
+ALTER TABLE ONLY "SavedSearch"
+    ADD CONSTRAINT "SavedSearch_fk0" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
@@ -0,0 +1,37 @@
+CREATE TABLE IF NOT EXISTS "Series" (
+    id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    name text NOT NULL,
+    description text NOT NULL,
+    image_filename text,
+    added_datetime timestamp without time zone NOT NULL,
+    updated_datetime timestamp without time zone NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "GameSeries" (
+    user_id uuid NOT NULL,
+    game_id uuid NOT NULL,
+    series_id uuid NOT NULL,
+    position integer NOT NULL
+);
+
+ALTER TABLE ONLY "Series"
+    ADD CONSTRAINT "Series_pk" PRIMARY KEY (id);
+
+ALTER TABLE ONLY "Series"
+    ADD CONSTRAINT "Series_unique" UNIQUE (user_id, name);
+
+ALTER TABLE ONLY "GameSeries"
+    ADD CONSTRAINT "GameSeries_pk" PRIMARY KEY (user_id, game_id, series_id);
+
+ALTER TABLE ONLY "Series"
+    ADD CONSTRAINT "Series_fk0" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "GameSeries"
+    ADD CONSTRAINT "GameSeries_fk0" FOREIGN KEY (game_id) REFERENCES "Game"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "GameSeries"
+    ADD CONSTRAINT "GameSeries_fk1" FOREIGN KEY (series_id) REFERENCES "Series"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "GameSeries"
+    ADD CONSTRAINT "GameSeries_fk2" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
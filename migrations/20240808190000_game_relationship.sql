@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS "GameRelationship" (
+    user_id uuid NOT NULL,
+    game_id uuid NOT NULL,
+    related_game_id uuid NOT NULL,
+    type smallint NOT NULL
+);
+
+ALTER TABLE ONLY "GameRelationship"
+    ADD CONSTRAINT "GameRelationship_pk" PRIMARY KEY (user_id, game_id, related_game_id);
+
+ALTER TABLE ONLY "GameRelationship"
+    ADD CONSTRAINT "GameRelationship_fk0" FOREIGN KEY (game_id) REFERENCES "Game"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "GameRelationship"
+    ADD CONSTRAINT "GameRelationship_fk1" FOREIGN KEY (related_game_id) REFERENCES "Game"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "GameRelationship"
+    ADD CONSTRAINT "GameRelationship_fk2" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
@@ -0,0 +1,40 @@
+CREATE TABLE IF NOT EXISTS "CustomField" (
+    id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    name text NOT NULL,
+    type smallint NOT NULL,
+    options text NOT NULL,
+    added_datetime timestamp without time zone NOT NULL,
+    updated_datetime timestamp without time zone NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "GameCustomValue" (
+    user_id uuid NOT NULL,
+    game_id uuid NOT NULL,
+    field_id uuid NOT NULL,
+    text_value text,
+    number_value double precision,
+    date_value date,
+    bool_value boolean
+);
+
+ALTER TABLE ONLY "CustomField"
+    ADD CONSTRAINT "CustomField_pk" PRIMARY KEY (id);
+
+ALTER TABLE ONLY "CustomField"
+    ADD CONSTRAINT "CustomField_unique" UNIQUE (user_id, name);
+
+ALTER TABLE ONLY "GameCustomValue"
+    ADD CONSTRAINT "GameCustomValue_pk" PRIMARY KEY (user_id, game_id, field_id);
+
+ALTER TABLE ONLY "CustomField"
+    ADD CONSTRAINT "CustomField_fk0" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "GameCustomValue"
+    ADD CONSTRAINT "GameCustomValue_fk0" FOREIGN KEY (game_id) REFERENCES "Game"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "GameCustomValue"
+    ADD CONSTRAINT "GameCustomValue_fk1" FOREIGN KEY (field_id) REFERENCES "CustomField"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "GameCustomValue"
+    ADD CONSTRAINT "GameCustomValue_fk2" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
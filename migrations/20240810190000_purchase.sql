@@ -0,0 +1,11 @@
+ALTER TABLE "GameAvailable" ADD COLUMN IF NOT EXISTS price numeric(12, 2);
+ALTER TABLE "GameAvailable" ADD COLUMN IF NOT EXISTS currency text;
+ALTER TABLE "GameAvailable" ADD COLUMN IF NOT EXISTS store text;
+ALTER TABLE "GameAvailable" ADD COLUMN IF NOT EXISTS gift boolean DEFAULT false NOT NULL;
+ALTER TABLE "GameAvailable" ADD COLUMN IF NOT EXISTS subscription boolean DEFAULT false NOT NULL;
+
+ALTER TABLE "DLCAvailable" ADD COLUMN IF NOT EXISTS price numeric(12, 2);
+ALTER TABLE "DLCAvailable" ADD COLUMN IF NOT EXISTS currency text;
+ALTER TABLE "DLCAvailable" ADD COLUMN IF NOT EXISTS store text;
+ALTER TABLE "DLCAvailable" ADD COLUMN IF NOT EXISTS gift boolean DEFAULT false NOT NULL;
+ALTER TABLE "DLCAvailable" ADD COLUMN IF NOT EXISTS subscription boolean DEFAULT false NOT NULL;
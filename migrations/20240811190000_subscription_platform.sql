@@ -0,0 +1,8 @@
+-- Platform type: 0 Physical, 1 Digital, 2 Subscription
+ALTER TABLE "Platform" DROP CONSTRAINT IF EXISTS "Platform_type_check";
+ALTER TABLE "Platform" ADD CONSTRAINT "Platform_type_check" CHECK (type IS NULL OR type BETWEEN 0 AND 2);
+
+ALTER TABLE "GameAvailable" ADD COLUMN IF NOT EXISTS end_date date;
+ALTER TABLE "DLCAvailable" ADD COLUMN IF NOT EXISTS end_date date;
+
+CREATE INDEX IF NOT EXISTS "GameAvailable_end_date_idx" ON "GameAvailable" (user_id, end_date) WHERE end_date IS NOT NULL;
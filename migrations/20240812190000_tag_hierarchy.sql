@@ -0,0 +1,8 @@
+ALTER TABLE "Tag" ADD COLUMN IF NOT EXISTS parent_id uuid;
+ALTER TABLE "Tag" ADD COLUMN IF NOT EXISTS color text;
+ALTER TABLE "Tag" ADD COLUMN IF NOT EXISTS icon text;
+
+ALTER TABLE ONLY "Tag"
+    ADD CONSTRAINT "Tag_fk1" FOREIGN KEY (parent_id) REFERENCES "Tag"(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS "Tag_parent_id_idx" ON "Tag" (user_id, parent_id);
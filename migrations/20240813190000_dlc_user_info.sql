@@ -0,0 +1,40 @@
+CREATE TABLE IF NOT EXISTS "DLCUserInfo" (
+    user_id uuid NOT NULL,
+    dlc_id uuid NOT NULL,
+    status smallint DEFAULT 0 NOT NULL,
+    rating integer DEFAULT 0 NOT NULL,
+    notes text DEFAULT ''::text NOT NULL,
+    added_datetime timestamp without time zone NOT NULL,
+    updated_datetime timestamp without time zone NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "DLCTag" (
+    user_id uuid NOT NULL,
+    dlc_id uuid NOT NULL,
+    tag_id uuid NOT NULL
+);
+
+ALTER TABLE ONLY "DLCUserInfo"
+    ADD CONSTRAINT "DLCUserInfo_pk" PRIMARY KEY (user_id, dlc_id);
+
+ALTER TABLE ONLY "DLCTag"
+    ADD CONSTRAINT "DLCTag_pk" PRIMARY KEY (user_id, dlc_id, tag_id);
+
+ALTER TABLE ONLY "DLCUserInfo"
+    ADD CONSTRAINT "DLCUserInfo_fk0" FOREIGN KEY (dlc_id) REFERENCES "DLC"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "DLCUserInfo"
+    ADD CONSTRAINT "DLCUserInfo_fk1" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "DLCTag"
+    ADD CONSTRAINT "DLCTag_fk0" FOREIGN KEY (dlc_id) REFERENCES "DLC"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "DLCTag"
+    ADD CONSTRAINT "DLCTag_fk1" FOREIGN KEY (tag_id) REFERENCES "Tag"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "DLCTag"
+    ADD CONSTRAINT "DLCTag_fk2" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
+
+INSERT INTO "DLCUserInfo" (user_id, dlc_id, added_datetime, updated_datetime)
+    SELECT user_id, id, added_datetime, updated_datetime FROM "DLC"
+    ON CONFLICT DO NOTHING;
@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS "GameStatusHistory" (
+    user_id uuid NOT NULL,
+    game_id uuid NOT NULL,
+    status smallint NOT NULL,
+    previous_status smallint,
+    datetime timestamp without time zone NOT NULL,
+    backfilled boolean DEFAULT false NOT NULL
+);
+
+ALTER TABLE ONLY "GameStatusHistory"
+    ADD CONSTRAINT "GameStatusHistory_pk" PRIMARY KEY (user_id, game_id, datetime);
+
+ALTER TABLE ONLY "GameStatusHistory"
+    ADD CONSTRAINT "GameStatusHistory_fk0" FOREIGN KEY (game_id) REFERENCES "Game"(id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY "GameStatusHistory"
+    ADD CONSTRAINT "GameStatusHistory_fk1" FOREIGN KEY (user_id) REFERENCES "User"(id) ON DELETE CASCADE;
+
+CREATE INDEX IF NOT EXISTS "GameStatusHistory_datetime_idx" ON "GameStatusHistory" (user_id, datetime);
+
+-- Current status is the only known one for existing games
+INSERT INTO "GameStatusHistory" (user_id, game_id, status, datetime, backfilled)
+    SELECT user_id, game_id, status, added_datetime, true FROM "GameUserInfo"
+    ON CONFLICT DO NOTHING;
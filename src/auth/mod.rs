@@ -1,5 +1,7 @@
 mod password_auth;
 mod token_auth;
+mod totp_auth;
 
 pub use password_auth::*;
 pub use token_auth::*;
+pub use totp_auth::*;
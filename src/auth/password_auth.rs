@@ -1,17 +1,118 @@
-use bcrypt::{hash, verify, DEFAULT_COST};
+use std::collections::HashSet;
+use std::sync::OnceLock;
 
-use crate::errors::{PasswordError, ValidationError};
+use argon2::password_hash::{self, SaltString};
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+
+use crate::errors::{PasswordError, PasswordPolicyError, ValidationError};
+use crate::models::PasswordPolicy;
+
+const DUMMY_PASSWORD: &str = "dummy_password";
+
+const ARGON2ID_HASH_PREFIX: &str = "$argon2id$";
+const BCRYPT_HASH_PREFIX: &str = "$2";
+const SALT_LENGTH: usize = 16;
+
+const COMMON_PASSWORDS: &str = include_str!("common_passwords.txt");
 
 pub fn verify_password(password: &str, password_hash: &str) -> Result<bool, ValidationError> {
-    verify(password, password_hash).map_err(|err| {
-        log::error!("Error verifying password. - {}", err.to_string());
+    // Hashes created before Argon2id was the default are still bcrypt
+    if password_hash.starts_with(BCRYPT_HASH_PREFIX) {
+        return bcrypt::verify(password, password_hash).map_err(|err| {
+            log::error!("Error verifying password. - {}", err.to_string());
+            ValidationError()
+        });
+    }
+
+    let parsed_hash = PasswordHash::new(password_hash).map_err(|err| {
+        log::error!("Error parsing password hash. - {}", err.to_string());
         ValidationError()
-    })
+    })?;
+    match Argon2::default().verify_password(password.as_bytes(), &parsed_hash) {
+        Ok(_) => Ok(true),
+        Err(password_hash::Error::Password) => Ok(false),
+        Err(err) => {
+            log::error!("Error verifying password. - {}", err.to_string());
+            Err(ValidationError())
+        }
+    }
+}
+
+/// Spends the same time as a real verification so non existing users cannot be told apart
+pub fn verify_dummy_password(password: &str) {
+    static DUMMY_PASSWORD_HASH: OnceLock<Option<String>> = OnceLock::new();
+
+    let dummy_hash = DUMMY_PASSWORD_HASH.get_or_init(|| hash_password(DUMMY_PASSWORD).ok());
+    if let Some(dummy_hash) = dummy_hash {
+        let _ = verify_password(password, dummy_hash);
+    }
 }
 
 pub fn hash_password(password: &str) -> Result<String, PasswordError> {
-    hash(password, DEFAULT_COST).map_err(|err| {
-        log::error!("Error hashing password. - {}", err.to_string());
+    let mut salt_bytes = [0_u8; SALT_LENGTH];
+    getrandom::getrandom(&mut salt_bytes).map_err(|err| {
+        log::error!("Error generating password salt. - {}", err.to_string());
+        PasswordError()
+    })?;
+    let salt = SaltString::encode_b64(&salt_bytes).map_err(|err| {
+        log::error!("Error encoding password salt. - {}", err.to_string());
         PasswordError()
-    })
+    })?;
+
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|err| {
+            log::error!("Error hashing password. - {}", err.to_string());
+            PasswordError()
+        })
+}
+
+/// Hashes not created with the current default algorithm should be replaced
+pub fn password_needs_rehash(password_hash: &str) -> bool {
+    !password_hash.starts_with(ARGON2ID_HASH_PREFIX)
+}
+
+pub fn check_password_policy(
+    policy: &PasswordPolicy,
+    username: &str,
+    password: &str,
+) -> Result<(), PasswordPolicyError> {
+    if password.chars().count() < policy.min_length {
+        return Err(PasswordPolicyError(format!(
+            "Password must be at least {} characters long.",
+            policy.min_length
+        )));
+    }
+
+    if password.eq_ignore_ascii_case(username) {
+        return Err(PasswordPolicyError(String::from(
+            "Password cannot be the same as the username.",
+        )));
+    }
+
+    if policy.reject_common
+        && policy
+            .common_passwords
+            .contains(password.to_lowercase().as_str())
+    {
+        return Err(PasswordPolicyError(String::from("Password is too common.")));
+    }
+
+    Ok(())
+}
+
+/// Small bundled list, a bigger one should be provided through a file when possible
+pub fn default_common_passwords() -> HashSet<String> {
+    parse_common_passwords(COMMON_PASSWORDS)
+}
+
+/// One password per line, compared ignoring case
+pub fn parse_common_passwords(content: &str) -> HashSet<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_lowercase)
+        .collect()
 }
@@ -1,16 +1,20 @@
 use actix_web::{dev::ServiceRequest, Error};
-use actix_web_httpauth::extractors::bearer::{BearerAuth, Config};
+use actix_web_httpauth::extractors::bearer::{self, BearerAuth, Config};
 use actix_web_httpauth::extractors::AuthenticationError;
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation};
 
 use crate::errors::{TokenErrors, ValidationError};
-use crate::models::{TokenResponse, UserClaims};
+use crate::models::{TokenResponse, UserClaims, PASSWORD_CHANGE_SCOPE};
 
 const KID: &str = "075d91f0-a35b-455a-9d78-8598846805e8"; // Random UUID
 const ISSUER: &str = "game_oclock";
 
 const TOKEN_TYPE_BEARER: &str = "bearer";
 
+// Only path allowed to limited tokens issued to users who must change their password
+const PASSWORD_CHANGE_PATH: &str = "/api/v1/myself/change-password";
+const SECONDS_PER_PASSWORD_CHANGE: i64 = 15 * crate::date_utils::SECONDS_PER_MINUTE;
+
 const DAYS_PER_WEEK: i64 = 7;
 const SECONDS_PER_ONE_WEEK: i64 = crate::date_utils::SECONDS_PER_DAY * DAYS_PER_WEEK;
 
@@ -28,6 +32,15 @@ pub async fn token_validator(
         Ok(token_data) => {
             // Ensure only access token is validated
             if token_data.claims.is_access() {
+                if token_data.claims.is_password_change_only() && req.path() != PASSWORD_CHANGE_PATH
+                {
+                    return Err((
+                        AuthenticationError::from(config)
+                            .with_error(bearer::Error::InsufficientScope)
+                            .into(),
+                        req,
+                    ));
+                }
                 Ok(req)
             } else {
                 Err((AuthenticationError::from(config).into(), req))
@@ -51,11 +64,17 @@ pub fn validate_token(
     })
 }
 
+/// Password change only tokens can just be used to change the password, and are short-lived
 pub fn generate_token_response(
     user_id: &str,
+    password_change_only: bool,
     encoding_key: &EncodingKey,
 ) -> Result<TokenResponse, TokenErrors> {
-    let access_token_claims = create_access_token_claims(user_id);
+    let access_token_claims = if password_change_only {
+        create_password_change_token_claims(user_id)
+    } else {
+        create_access_token_claims(user_id)
+    };
     let refresh_token_claims = create_refresh_token_claims(user_id, &access_token_claims.jti);
 
     let access_token = generate_token(&access_token_claims, encoding_key)
@@ -67,6 +86,7 @@ pub fn generate_token_response(
         refresh_token,
         token_type: String::from(TOKEN_TYPE_BEARER),
         expires_in: access_token_claims.exp,
+        scope: access_token_claims.scp,
     })
 }
 
@@ -74,6 +94,12 @@ fn create_access_token_claims(user_id: &str) -> UserClaims {
     create_token_claims(user_id, crate::date_utils::SECONDS_PER_DAY, None)
 }
 
+fn create_password_change_token_claims(user_id: &str) -> UserClaims {
+    let mut claims = create_token_claims(user_id, SECONDS_PER_PASSWORD_CHANGE, None);
+    claims.scp = Some(String::from(PASSWORD_CHANGE_SCOPE));
+    claims
+}
+
 fn create_refresh_token_claims(user_id: &str, access_token_id: &str) -> UserClaims {
     create_token_claims(
         user_id,
@@ -96,6 +122,7 @@ fn create_token_claims(
         kid: String::from(KID),
         jti: crate::uuid_utils::new_random_uuid(),
         ati: access_token_id,
+        scp: None,
     }
 }
 
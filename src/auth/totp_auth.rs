@@ -0,0 +1,302 @@
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use crate::errors::ValidationError;
+
+type HmacSha1 = Hmac<Sha1>;
+
+const ISSUER: &str = "Game o'Clock";
+
+const SECRET_LENGTH: usize = 20; // 160 bits, as recommended by RFC 4226
+const STEP_SECONDS: i64 = 30;
+const CODE_DIGITS: u32 = 6;
+// Accepted steps before and after the current one to allow for clock drift
+const ALLOWED_DRIFT_STEPS: i64 = 1;
+
+const RECOVERY_CODES_COUNT: usize = 10;
+const RECOVERY_CODE_LENGTH: usize = 10;
+const RECOVERY_CODE_SEPARATOR: char = '-';
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+pub fn generate_totp_secret() -> Result<String, ValidationError> {
+    let secret = random_bytes(SECRET_LENGTH)?;
+    Ok(base32_encode(&secret))
+}
+
+pub fn build_totp_uri(secret: &str, username: &str) -> String {
+    let issuer = percent_encode(ISSUER);
+    let account = percent_encode(username);
+    format!(
+        "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={CODE_DIGITS}&period={STEP_SECONDS}"
+    )
+}
+
+/// Returns the time step matched by the code, if any
+pub fn verify_totp_code(
+    secret: &str,
+    code: &str,
+    timestamp: i64,
+) -> Result<Option<i64>, ValidationError> {
+    let code = code.trim();
+    if code.len() != CODE_DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(None);
+    }
+
+    let secret = base32_decode(secret).ok_or_else(|| {
+        log::error!("Error decoding TOTP secret.");
+        ValidationError()
+    })?;
+
+    let current_step = timestamp / STEP_SECONDS;
+    for step in (current_step - ALLOWED_DRIFT_STEPS)..=(current_step + ALLOWED_DRIFT_STEPS) {
+        let expected_code = generate_code(&secret, step)?;
+        if format!("{:0width$}", expected_code, width = CODE_DIGITS as usize) == code {
+            return Ok(Some(step));
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn generate_recovery_codes() -> Result<Vec<String>, ValidationError> {
+    (0..RECOVERY_CODES_COUNT)
+        .map(|_| {
+            let bytes = random_bytes(RECOVERY_CODE_LENGTH)?;
+            let code: String = bytes
+                .iter()
+                .map(|byte| BASE32_ALPHABET[(byte % 32) as usize].to_ascii_lowercase() as char)
+                .collect();
+            let (first_half, second_half) = code.split_at(RECOVERY_CODE_LENGTH / 2);
+            Ok(format!(
+                "{first_half}{RECOVERY_CODE_SEPARATOR}{second_half}"
+            ))
+        })
+        .collect()
+}
+
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalized_code: String = code
+        .trim()
+        .chars()
+        .filter(|c| *c != RECOVERY_CODE_SEPARATOR)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    Sha256::digest(normalized_code.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn generate_code(secret: &[u8], step: i64) -> Result<u32, ValidationError> {
+    let mut mac = HmacSha1::new_from_slice(secret).map_err(|err| {
+        log::error!("Error creating TOTP hmac. - {}", err.to_string());
+        ValidationError()
+    })?;
+    mac.update(&step.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    // Dynamic truncation as defined in RFC 4226
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = (u32::from(hash[offset] & 0x7f) << 24)
+        | (u32::from(hash[offset + 1]) << 16)
+        | (u32::from(hash[offset + 2]) << 8)
+        | u32::from(hash[offset + 3]);
+
+    Ok(binary % 10_u32.pow(CODE_DIGITS))
+}
+
+fn random_bytes(length: usize) -> Result<Vec<u8>, ValidationError> {
+    let mut bytes = vec![0_u8; length];
+    getrandom::getrandom(&mut bytes).map_err(|err| {
+        log::error!("Error generating random bytes. - {}", err.to_string());
+        ValidationError()
+    })?;
+    Ok(bytes)
+}
+
+fn base32_encode(data: &[u8]) -> String {
+    let mut encoded = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for byte in data {
+        buffer = (buffer << 8) | u32::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            let index = (buffer >> (bits - 5)) & 0x1f;
+            encoded.push(BASE32_ALPHABET[index as usize] as char);
+            bits -= 5;
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    if bits > 0 {
+        let index = (buffer << (5 - bits)) & 0x1f;
+        encoded.push(BASE32_ALPHABET[index as usize] as char);
+    }
+
+    encoded
+}
+
+fn base32_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut decoded = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for c in encoded.trim_end_matches('=').chars() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|symbol| *symbol as char == c.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            decoded.push(((buffer >> (bits - 8)) & 0xff) as u8);
+            bits -= 8;
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(decoded)
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 4226 / RFC 6238 SHA1 secret
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+    const RFC_SECRET_BASE32: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    #[test]
+    fn base32_encode_rfc_4648_vectors() {
+        assert_eq!(base32_encode(b""), "");
+        assert_eq!(base32_encode(b"f"), "MY");
+        assert_eq!(base32_encode(b"fo"), "MZXQ");
+        assert_eq!(base32_encode(b"foo"), "MZXW6");
+        assert_eq!(base32_encode(b"foob"), "MZXW6YQ");
+        assert_eq!(base32_encode(b"fooba"), "MZXW6YTB");
+        assert_eq!(base32_encode(b"foobar"), "MZXW6YTBOI");
+        assert_eq!(base32_encode(RFC_SECRET), RFC_SECRET_BASE32);
+    }
+
+    #[test]
+    fn base32_decode_rfc_4648_vectors() {
+        assert_eq!(base32_decode("MY======").unwrap(), b"f");
+        assert_eq!(base32_decode("MZXQ====").unwrap(), b"fo");
+        assert_eq!(base32_decode("MZXW6===").unwrap(), b"foo");
+        assert_eq!(base32_decode("MZXW6YQ=").unwrap(), b"foob");
+        assert_eq!(base32_decode("MZXW6YTB").unwrap(), b"fooba");
+        assert_eq!(base32_decode("mzxw6ytboi").unwrap(), b"foobar");
+        assert!(base32_decode("MZXW1").is_none());
+    }
+
+    #[test]
+    fn base32_round_trip() {
+        let secret = generate_totp_secret().unwrap();
+        let decoded = base32_decode(&secret).unwrap();
+        assert_eq!(decoded.len(), SECRET_LENGTH);
+        assert_eq!(base32_encode(&decoded), secret);
+    }
+
+    #[test]
+    fn generate_code_rfc_4226_vectors() {
+        let expected_codes = [
+            755224, 287082, 359152, 969429, 338314, 254676, 287922, 162583, 399871, 520489,
+        ];
+        for (counter, expected_code) in expected_codes.iter().enumerate() {
+            assert_eq!(
+                generate_code(RFC_SECRET, counter as i64).unwrap(),
+                *expected_code
+            );
+        }
+    }
+
+    #[test]
+    fn verify_totp_code_rfc_6238_vectors() {
+        // 8 digit codes of the RFC truncated to the 6 digits in use
+        let vectors = [
+            (59, "287082"),
+            (1111111109, "081804"),
+            (1111111111, "050471"),
+            (1234567890, "005924"),
+            (2000000000, "279037"),
+            (20000000000, "353130"),
+        ];
+        for (timestamp, code) in vectors {
+            assert_eq!(
+                verify_totp_code(RFC_SECRET_BASE32, code, timestamp).unwrap(),
+                Some(timestamp / STEP_SECONDS)
+            );
+        }
+    }
+
+    #[test]
+    fn verify_totp_code_allows_drift() {
+        // Codes of steps 0 and 2 accepted at step 1
+        assert_eq!(
+            verify_totp_code(RFC_SECRET_BASE32, "755224", 59).unwrap(),
+            Some(0)
+        );
+        assert_eq!(
+            verify_totp_code(RFC_SECRET_BASE32, "359152", 59).unwrap(),
+            Some(2)
+        );
+        // Code of step 3 rejected at step 1
+        assert_eq!(
+            verify_totp_code(RFC_SECRET_BASE32, "969429", 59).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn verify_totp_code_rejects_malformed() {
+        assert_eq!(verify_totp_code(RFC_SECRET_BASE32, "", 59).unwrap(), None);
+        assert_eq!(
+            verify_totp_code(RFC_SECRET_BASE32, "28708", 59).unwrap(),
+            None
+        );
+        assert_eq!(
+            verify_totp_code(RFC_SECRET_BASE32, "2870822", 59).unwrap(),
+            None
+        );
+        assert_eq!(
+            verify_totp_code(RFC_SECRET_BASE32, "28708a", 59).unwrap(),
+            None
+        );
+        assert!(verify_totp_code("not base32!", "287082", 59).is_err());
+    }
+
+    #[test]
+    fn recovery_codes_round_trip() {
+        let recovery_codes = generate_recovery_codes().unwrap();
+        assert_eq!(recovery_codes.len(), RECOVERY_CODES_COUNT);
+
+        for code in &recovery_codes {
+            let (first_half, second_half) = code.split_once(RECOVERY_CODE_SEPARATOR).unwrap();
+            assert_eq!(first_half.len() + second_half.len(), RECOVERY_CODE_LENGTH);
+
+            // Typed back with other case, without separator or with spaces
+            let hash = hash_recovery_code(code);
+            assert_eq!(hash_recovery_code(&code.to_ascii_uppercase()), hash);
+            assert_eq!(
+                hash_recovery_code(&format!(" {first_half}{second_half} ")),
+                hash
+            );
+            assert_ne!(hash_recovery_code(first_half), hash);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime};
 
 pub const MICROS_PER_SECOND: i64 = 1_000_000;
 pub const SECONDS_PER_MINUTE: i64 = 60;
@@ -20,3 +20,60 @@ pub fn date_at_midnight(date: NaiveDate) -> NaiveDateTime {
     let midnight_time = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
     date.and_time(midnight_time)
 }
+
+/// Resolves a date relative to now, like "now-30d", "today" or "start_of_year+1m".
+/// Anchors are now, today, start_of_week, start_of_month and start_of_year.
+/// Offset units are h (hours), d (days), w (weeks), m (months) and y (years).
+pub fn parse_relative_datetime(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    let offset_index = value.find(|c| c == '+' || c == '-').unwrap_or(value.len());
+    let (anchor, offset) = value.split_at(offset_index);
+
+    let now = now();
+    let today = now.date();
+    let anchor_datetime = match anchor {
+        "now" => now,
+        "today" => date_at_start_of_day(today),
+        "start_of_week" => {
+            let days_from_monday = today.weekday().num_days_from_monday();
+            date_at_start_of_day(today - Duration::days(i64::from(days_from_monday)))
+        }
+        "start_of_month" => date_at_start_of_day(today.with_day(1)?),
+        "start_of_year" => date_at_start_of_day(NaiveDate::from_ymd_opt(today.year(), 1, 1)?),
+        _ => return None,
+    };
+
+    if offset.is_empty() {
+        return Some(anchor_datetime);
+    }
+    if !offset.is_ascii() || offset.len() < 3 {
+        return None;
+    }
+
+    let (sign, amount_and_unit) = offset.split_at(1);
+    let (amount, unit) = amount_and_unit.split_at(amount_and_unit.len() - 1);
+    let amount: u32 = amount.parse().ok()?;
+    let subtract = sign == "-";
+
+    let delta = match unit {
+        "h" => Duration::hours(i64::from(amount)),
+        "d" => Duration::days(i64::from(amount)),
+        "w" => Duration::weeks(i64::from(amount)),
+        "m" => return shift_months(anchor_datetime, amount, subtract),
+        "y" => return shift_months(anchor_datetime, amount.checked_mul(12)?, subtract),
+        _ => return None,
+    };
+    if subtract {
+        anchor_datetime.checked_sub_signed(delta)
+    } else {
+        anchor_datetime.checked_add_signed(delta)
+    }
+}
+
+fn shift_months(datetime: NaiveDateTime, months: u32, subtract: bool) -> Option<NaiveDateTime> {
+    if subtract {
+        datetime.checked_sub_months(Months::new(months))
+    } else {
+        datetime.checked_add_months(Months::new(months))
+    }
+}
@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use chrono::NaiveDateTime;
+use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::{FieldIden, FieldType, Search, TableIden};
+
+pub type CustomFieldSearch = Search<CustomFieldIden>;
+
+#[derive(Clone, Copy, Iden)]
+#[iden = "CustomField"]
+pub enum CustomFieldIden {
+    Table,
+    #[iden = "id"]
+    Id,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "name"]
+    Name,
+    #[iden = "type"]
+    Type,
+    #[iden = "options"]
+    Options,
+    #[iden = "added_datetime"]
+    AddedDateTime,
+    #[iden = "updated_datetime"]
+    UpdatedDateTime,
+}
+
+impl TableIden for CustomFieldIden {
+    const TABLE: Self = Self::Table;
+}
+
+#[derive(FromRow)]
+pub struct CustomField {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    #[sqlx(rename = "type")] // Fix to use type reserved name
+    pub ftype: i16,
+    pub options: String,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+}
+
+impl FromStr for FieldIden<CustomFieldIden> {
+    type Err = ();
+
+    fn from_str(field: &str) -> Result<Self, Self::Err> {
+        match field {
+            "id" => Ok(FieldIden::new(CustomFieldIden::Id, FieldType::String)),
+            "name" => Ok(FieldIden::new(CustomFieldIden::Name, FieldType::String)),
+            "added_datetime" => Ok(FieldIden::new(
+                CustomFieldIden::AddedDateTime,
+                FieldType::DateTime,
+            )),
+            "updated_datetime" => Ok(FieldIden::new(
+                CustomFieldIden::UpdatedDateTime,
+                FieldType::DateTime,
+            )),
+            _ => Err(()),
+        }
+    }
+}
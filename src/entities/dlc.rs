@@ -5,7 +5,7 @@ use sea_query::Iden;
 use sqlx::FromRow;
 use uuid::Uuid;
 
-use super::{FieldIden, FieldType, Search, TableIden};
+use super::{DLCUserInfoIden, FieldIden, FieldType, RelationField, Search, TableIden};
 
 pub type DLCSearch = Search<DLCIden>;
 
@@ -35,6 +35,16 @@ impl TableIden for DLCIden {
     const TABLE: Self = Self::Table;
 }
 
+/// Search fields computed from rows related to the DLC, translated by the DLC query
+#[derive(Clone, Copy)]
+pub enum DLCRelationField {
+    Tag,
+    TagName,
+    Platform,
+    AvailableDate,
+    AvailableEndDate,
+}
+
 #[derive(FromRow)]
 pub struct DLC {
     pub id: Uuid,
@@ -45,6 +55,9 @@ pub struct DLC {
     pub cover_filename: Option<String>,
     pub added_datetime: NaiveDateTime,
     pub updated_datetime: NaiveDateTime,
+    pub status: i16,
+    pub rating: i32,
+    pub notes: String,
 }
 
 #[derive(FromRow)]
@@ -57,6 +70,9 @@ pub struct DLCWithDate {
     pub cover_filename: Option<String>,
     pub added_datetime: NaiveDateTime,
     pub updated_datetime: NaiveDateTime,
+    pub status: i16,
+    pub rating: i32,
+    pub notes: String,
     pub query_date: NaiveDate,
 }
 
@@ -70,11 +86,37 @@ impl FromStr for FieldIden<DLCIden> {
             "base_game_id" => Ok(FieldIden::new(DLCIden::BaseGameId, FieldType::String)),
             "release_year" => Ok(FieldIden::new(DLCIden::ReleaseYear, FieldType::Integer)),
             "cover_filename" => Ok(FieldIden::new(DLCIden::CoverFilename, FieldType::String)),
+            "status" => Ok(FieldIden::new(
+                DLCUserInfoIden::Status,
+                FieldType::GameStatus,
+            )),
+            "rating" => Ok(FieldIden::new(DLCUserInfoIden::Rating, FieldType::Integer)),
+            "notes" => Ok(FieldIden::new(DLCUserInfoIden::Notes, FieldType::String)),
             "added_datetime" => Ok(FieldIden::new(DLCIden::AddedDateTime, FieldType::DateTime)),
             "updated_datetime" => Ok(FieldIden::new(
                 DLCIden::UpdatedDateTime,
                 FieldType::DateTime,
             )),
+            "tag" => Ok(FieldIden::new_relation(
+                RelationField::DLC(DLCRelationField::Tag),
+                FieldType::String,
+            )),
+            "tag_name" => Ok(FieldIden::new_relation(
+                RelationField::DLC(DLCRelationField::TagName),
+                FieldType::String,
+            )),
+            "platform" => Ok(FieldIden::new_relation(
+                RelationField::DLC(DLCRelationField::Platform),
+                FieldType::String,
+            )),
+            "available_date" => Ok(FieldIden::new_relation(
+                RelationField::DLC(DLCRelationField::AvailableDate),
+                FieldType::Date,
+            )),
+            "available_end_date" => Ok(FieldIden::new_relation(
+                RelationField::DLC(DLCRelationField::AvailableEndDate),
+                FieldType::Date,
+            )),
             _ => Err(()),
         }
     }
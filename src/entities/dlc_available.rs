@@ -12,4 +12,16 @@ pub enum DLCAvailableIden {
     PlatformId,
     #[iden = "added_date"]
     AddedDate,
+    #[iden = "end_date"]
+    EndDate,
+    #[iden = "price"]
+    Price,
+    #[iden = "currency"]
+    Currency,
+    #[iden = "store"]
+    Store,
+    #[iden = "gift"]
+    Gift,
+    #[iden = "subscription"]
+    Subscription,
 }
@@ -1,4 +1,7 @@
+use chrono::NaiveDate;
 use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
 
 use super::TableIden;
 
@@ -17,3 +20,9 @@ pub enum DLCFinishIden {
 impl TableIden for DLCFinishIden {
     const TABLE: Self = Self::Table;
 }
+
+#[derive(FromRow)]
+pub struct DLCFinishDate {
+    pub dlc_id: Uuid,
+    pub date: NaiveDate,
+}
@@ -0,0 +1,13 @@
+use sea_query::Iden;
+
+#[derive(Iden)]
+#[iden = "DLCTag"]
+pub enum DLCTagIden {
+    Table,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "dlc_id"]
+    DLCId,
+    #[iden = "tag_id"]
+    TagId,
+}
@@ -0,0 +1,27 @@
+use sea_query::Iden;
+
+use super::TableIden;
+
+#[derive(Iden)]
+#[iden = "DLCUserInfo"]
+pub enum DLCUserInfoIden {
+    Table,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "dlc_id"]
+    DLCId,
+    #[iden = "status"]
+    Status,
+    #[iden = "rating"]
+    Rating,
+    #[iden = "notes"]
+    Notes,
+    #[iden = "added_datetime"]
+    AddedDateTime,
+    #[iden = "updated_datetime"]
+    UpdatedDateTime,
+}
+
+impl TableIden for DLCUserInfoIden {
+    const TABLE: Self = Self::Table;
+}
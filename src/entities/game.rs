@@ -5,12 +5,31 @@ use sea_query::Iden;
 use sqlx::FromRow;
 use uuid::Uuid;
 
-use super::{FieldIden, FieldType, GameUserInfoIden, Search, TableIden};
+use super::{
+    FieldIden, FieldType, GameCustomValueIden, GameUserInfoIden, RelationField, Search, TableIden,
+    CUSTOM_FIELD_SEARCH_PREFIX,
+};
 
 pub type GameSearch = Search<GameIden>;
 
 pub const QUERY_DATE_ALIAS: &str = "query_date";
 
+/// Search fields computed from rows related to the game, translated by the game query
+#[derive(Clone, Copy)]
+pub enum GameRelationField {
+    Tag,
+    TagName,
+    Platform,
+    PlatformType,
+    AvailableDate,
+    AvailableEndDate,
+    FinishCount,
+    LastFinishDate,
+    TotalTime,
+    LastPlayed,
+    Series,
+}
+
 #[derive(Clone, Copy, Iden)]
 #[iden = "Game"]
 pub enum GameIden {
@@ -37,7 +56,7 @@ impl TableIden for GameIden {
     const TABLE: Self = Self::Table;
 }
 
-#[derive(FromRow)]
+#[derive(FromRow, Clone)]
 pub struct Game {
     pub id: Uuid,
     pub user_id: Uuid,
@@ -74,6 +93,15 @@ pub struct GameWithDate {
     pub query_date: NaiveDate,
 }
 
+/// How a game targeted by a bulk change ended up
+pub enum GameBulkOutcome {
+    Written,
+    /// Another game already has the resulting name and edition
+    AlreadyExists,
+    /// Cannot be wishlisted while it has platforms available
+    Available,
+}
+
 impl FromStr for FieldIden<GameIden> {
     type Err = ();
 
@@ -104,7 +132,76 @@ impl FromStr for FieldIden<GameIden> {
                 GameIden::UpdatedDateTime,
                 FieldType::DateTime,
             )),
-            _ => Err(()),
+            "tag" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::Tag),
+                FieldType::String,
+            )),
+            "tag_name" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::TagName),
+                FieldType::String,
+            )),
+            "platform" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::Platform),
+                FieldType::String,
+            )),
+            "platform_type" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::PlatformType),
+                FieldType::PlatformType,
+            )),
+            "available_date" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::AvailableDate),
+                FieldType::Date,
+            )),
+            "available_end_date" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::AvailableEndDate),
+                FieldType::Date,
+            )),
+            "finish_count" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::FinishCount),
+                FieldType::Integer,
+            )),
+            "last_finish_date" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::LastFinishDate),
+                FieldType::Date,
+            )),
+            "total_time" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::TotalTime),
+                FieldType::Duration,
+            )),
+            "last_played" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::LastPlayed),
+                FieldType::DateTime,
+            )),
+            "series" => Ok(FieldIden::new_relation(
+                RelationField::Game(GameRelationField::Series),
+                FieldType::String,
+            )),
+            _ => custom_field_iden(field),
         }
     }
 }
+
+/// Custom field value, typed by the column of the value
+fn custom_field_iden(field: &str) -> Result<FieldIden<GameIden>, ()> {
+    let (column, field_id) = field
+        .strip_prefix(CUSTOM_FIELD_SEARCH_PREFIX)
+        .and_then(|custom_field| custom_field.split_once(':'))
+        .ok_or(())?;
+
+    let (column, _type) = if column == GameCustomValueIden::TextValue.to_string() {
+        (GameCustomValueIden::TextValue, FieldType::String)
+    } else if column == GameCustomValueIden::NumberValue.to_string() {
+        (GameCustomValueIden::NumberValue, FieldType::Number)
+    } else if column == GameCustomValueIden::DateValue.to_string() {
+        (GameCustomValueIden::DateValue, FieldType::Date)
+    } else if column == GameCustomValueIden::BoolValue.to_string() {
+        (GameCustomValueIden::BoolValue, FieldType::Boolean)
+    } else {
+        return Err(());
+    };
+
+    Ok(FieldIden::new_relation(
+        RelationField::GameCustomField(column, String::from(field_id)),
+        _type,
+    ))
+}
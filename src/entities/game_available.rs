@@ -1,4 +1,7 @@
+use chrono::NaiveDate;
 use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
 
 #[derive(Iden)]
 #[iden = "GameAvailable"]
@@ -12,4 +15,30 @@ pub enum GameAvailableIden {
     PlatformId,
     #[iden = "added_date"]
     AddedDate,
+    #[iden = "end_date"]
+    EndDate,
+    #[iden = "price"]
+    Price,
+    #[iden = "currency"]
+    Currency,
+    #[iden = "store"]
+    Store,
+    #[iden = "gift"]
+    Gift,
+    #[iden = "subscription"]
+    Subscription,
+}
+
+#[derive(FromRow)]
+pub struct GameWithAvailabilityEnd {
+    pub id: Uuid,
+    pub name: String,
+    pub edition: String,
+    pub release_year: Option<i32>,
+    pub cover_filename: Option<String>,
+    pub status: i16,
+    pub platform_id: Uuid,
+    pub platform_name: String,
+    pub query_date: NaiveDate,
+    pub end_date: NaiveDate,
 }
@@ -0,0 +1,47 @@
+use chrono::NaiveDate;
+use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Game search field of a custom field value, once its name is resolved: custom_field:<column>:<field id>
+pub const CUSTOM_FIELD_SEARCH_PREFIX: &str = "custom_field:";
+
+#[derive(Clone, Copy, Iden)]
+#[iden = "GameCustomValue"]
+pub enum GameCustomValueIden {
+    Table,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "game_id"]
+    GameId,
+    #[iden = "field_id"]
+    FieldId,
+    #[iden = "text_value"]
+    TextValue,
+    #[iden = "number_value"]
+    NumberValue,
+    #[iden = "date_value"]
+    DateValue,
+    #[iden = "bool_value"]
+    BoolValue,
+}
+
+/// Value of a custom field to set on games, removed when every column is empty
+pub struct GameCustomValue {
+    pub field_id: String,
+    pub text_value: Option<String>,
+    pub number_value: Option<f64>,
+    pub date_value: Option<NaiveDate>,
+    pub bool_value: Option<bool>,
+}
+
+#[derive(FromRow)]
+pub struct GameCustomValueWithField {
+    pub game_id: Uuid,
+    pub field_id: Uuid,
+    pub name: String,
+    pub text_value: Option<String>,
+    pub number_value: Option<f64>,
+    pub date_value: Option<NaiveDate>,
+    pub bool_value: Option<bool>,
+}
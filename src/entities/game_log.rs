@@ -38,3 +38,9 @@ pub struct GameLogWithTime {
     pub end_datetime: NaiveDateTime,
     pub query_time: PgInterval,
 }
+
+#[derive(FromRow)]
+pub struct GameLogTotalTime {
+    pub game_id: Uuid,
+    pub query_time: PgInterval,
+}
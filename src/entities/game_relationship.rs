@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+pub const RELATIONSHIP_TYPE_ALIAS: &str = "relationship_type";
+
+#[derive(Clone, Copy, Iden)]
+#[iden = "GameRelationship"]
+pub enum GameRelationshipIden {
+    Table,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "game_id"]
+    GameId,
+    #[iden = "related_game_id"]
+    RelatedGameId,
+    #[iden = "type"]
+    Type,
+}
+
+#[derive(FromRow)]
+pub struct GameRelationshipIds {
+    pub game_id: Uuid,
+    pub related_game_id: Uuid,
+}
+
+#[derive(FromRow)]
+pub struct GameWithRelationship {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub edition: String,
+    pub release_year: Option<i32>,
+    pub cover_filename: Option<String>,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+    pub status: i16,
+    pub rating: i32,
+    pub notes: String,
+    pub save_folder: String,
+    pub screenshot_folder: String,
+    pub backup: bool,
+    pub relationship_type: i16,
+}
@@ -0,0 +1,37 @@
+use chrono::NaiveDateTime;
+use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Iden)]
+#[iden = "GameSeries"]
+pub enum GameSeriesIden {
+    Table,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "game_id"]
+    GameId,
+    #[iden = "series_id"]
+    SeriesId,
+    #[iden = "position"]
+    Position,
+}
+
+#[derive(FromRow)]
+pub struct GameWithPosition {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub edition: String,
+    pub release_year: Option<i32>,
+    pub cover_filename: Option<String>,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+    pub status: i16,
+    pub rating: i32,
+    pub notes: String,
+    pub save_folder: String,
+    pub screenshot_folder: String,
+    pub backup: bool,
+    pub position: i32,
+}
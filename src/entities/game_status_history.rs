@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::TableIden;
+
+#[derive(Iden)]
+#[iden = "GameStatusHistory"]
+pub enum GameStatusHistoryIden {
+    Table,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "game_id"]
+    GameId,
+    #[iden = "status"]
+    Status,
+    #[iden = "previous_status"]
+    PreviousStatus,
+    #[iden = "datetime"]
+    DateTime,
+    #[iden = "backfilled"]
+    Backfilled,
+}
+
+impl TableIden for GameStatusHistoryIden {
+    const TABLE: Self = Self::Table;
+}
+
+#[derive(FromRow)]
+pub struct GameStatusChange {
+    pub game_id: Uuid,
+    pub status: i16,
+    pub previous_status: Option<i16>,
+    pub datetime: NaiveDateTime,
+}
+
+#[derive(FromRow)]
+pub struct GameWithStatusChange {
+    pub id: Uuid,
+    pub name: String,
+    pub edition: String,
+    pub cover_filename: Option<String>,
+    pub added_datetime: NaiveDateTime,
+    pub status: i16,
+    pub previous_status: Option<i16>,
+    pub datetime: NaiveDateTime,
+}
@@ -0,0 +1,38 @@
+use chrono::NaiveDateTime;
+use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Iden)]
+#[iden = "Invitation"]
+pub enum InvitationIden {
+    Table,
+    #[iden = "id"]
+    Id,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "code"]
+    Code,
+    #[iden = "expiry_datetime"]
+    ExpiryDateTime,
+    #[iden = "used_by_user_id"]
+    UsedByUserId,
+    #[iden = "used_datetime"]
+    UsedDateTime,
+    #[iden = "added_datetime"]
+    AddedDateTime,
+    #[iden = "updated_datetime"]
+    UpdatedDateTime,
+}
+
+#[derive(FromRow)]
+pub struct Invitation {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code: String,
+    pub expiry_datetime: NaiveDateTime,
+    pub used_by_user_id: Option<Uuid>,
+    pub used_datetime: Option<NaiveDateTime>,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+}
@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use sea_query::Iden;
+use sqlx::FromRow;
+
+#[derive(Iden)]
+#[iden = "LoginAttempt"]
+pub enum LoginAttemptIden {
+    Table,
+    #[iden = "source"]
+    Source,
+    #[iden = "source_type"]
+    SourceType,
+    #[iden = "failed_count"]
+    FailedCount,
+    #[iden = "last_failed_datetime"]
+    LastFailedDateTime,
+    #[iden = "locked_until_datetime"]
+    LockedUntilDateTime,
+}
+
+#[derive(FromRow)]
+pub struct LoginAttempt {
+    pub source: String,
+    pub source_type: i16,
+    pub failed_count: i32,
+    pub last_failed_datetime: NaiveDateTime,
+    pub locked_until_datetime: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Copy)]
+pub enum LoginAttemptSourceType {
+    Username,
+    Ip,
+    TotpUser,
+}
+
+impl From<LoginAttemptSourceType> for i16 {
+    fn from(source_type: LoginAttemptSourceType) -> Self {
+        match source_type {
+            LoginAttemptSourceType::Username => 0,
+            LoginAttemptSourceType::Ip => 1,
+            LoginAttemptSourceType::TotpUser => 2,
+        }
+    }
+}
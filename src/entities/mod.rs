@@ -1,33 +1,59 @@
+mod custom_field;
 mod dlc;
 mod dlc_available;
 mod dlc_finish;
+mod dlc_tag;
+mod dlc_user_info;
 mod game;
 mod game_available;
+mod game_custom_value;
 mod game_finish;
 mod game_log;
+mod game_relationship;
+mod game_series;
+mod game_status_history;
 mod game_tag;
 mod game_user_info;
 mod game_with_log;
+mod invitation;
+mod login_attempt;
 mod pagination;
 mod platform;
+mod purchase;
+mod saved_search;
 mod search;
+mod series;
 mod table;
 mod tag;
 mod user;
+mod user_totp;
 
+pub use custom_field::*;
 pub use dlc::*;
 pub use dlc_available::*;
 pub use dlc_finish::*;
+pub use dlc_tag::*;
+pub use dlc_user_info::*;
 pub use game::*;
 pub use game_available::*;
+pub use game_custom_value::*;
 pub use game_finish::*;
 pub use game_log::*;
+pub use game_relationship::*;
+pub use game_series::*;
+pub use game_status_history::*;
 pub use game_tag::*;
 pub use game_user_info::*;
 pub use game_with_log::*;
+pub use invitation::*;
+pub use login_attempt::*;
 pub use pagination::*;
 pub use platform::*;
+pub use purchase::*;
+pub use saved_search::*;
 pub use search::*;
+pub use series::*;
 pub use table::*;
 pub use tag::*;
 pub use user::*;
+pub use user_totp::*;
@@ -1,5 +1,11 @@
+use super::FacetResult;
+
 pub struct PageResult<E> {
     pub data: Vec<E>,
     pub page: u64,
     pub size: u64,
+    pub total_count: Option<u64>,
+    pub cursor: bool,
+    pub next_cursor: Option<String>,
+    pub facets: Option<Vec<FacetResult>>,
 }
@@ -56,6 +56,32 @@ pub struct PlatformWithDate {
     pub icon_filename: Option<String>,
     pub added_datetime: NaiveDateTime,
     pub updated_datetime: NaiveDateTime,
+    pub end_date: Option<NaiveDate>,
+    pub price: Option<i64>,
+    pub currency: Option<String>,
+    pub store: Option<String>,
+    pub gift: bool,
+    pub subscription: bool,
+}
+
+#[derive(FromRow)]
+pub struct PlatformWithGameIdAndDate {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub game_id: Uuid,
+    pub query_date: NaiveDate,
+    pub name: String,
+    #[sqlx(rename = "type")] // Fix to use type reserved name
+    pub ptype: Option<i16>,
+    pub icon_filename: Option<String>,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+    pub end_date: Option<NaiveDate>,
+    pub price: Option<i64>,
+    pub currency: Option<String>,
+    pub store: Option<String>,
+    pub gift: bool,
+    pub subscription: bool,
 }
 
 impl FromStr for FieldIden<PlatformIden> {
@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+pub const PLATFORM_NAME_ALIAS: &str = "platform_name";
+pub const BASE_GAME_NAME_ALIAS: &str = "base_game_name";
+pub const BASE_GAME_EDITION_ALIAS: &str = "base_game_edition";
+pub const BASE_GAME_COVER_FILENAME_ALIAS: &str = "base_game_cover_filename";
+
+/// Availability of a game or DLC on a platform and how it was obtained
+pub struct Availability {
+    pub added_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub price: Option<i64>,
+    pub currency: Option<String>,
+    pub store: Option<String>,
+    pub gift: bool,
+    pub subscription: bool,
+}
+
+#[derive(FromRow)]
+pub struct GamePurchase {
+    pub id: Uuid,
+    pub name: String,
+    pub edition: String,
+    pub cover_filename: Option<String>,
+    pub platform_name: String,
+    pub query_date: NaiveDate,
+    pub price: Option<i64>,
+    pub currency: Option<String>,
+    pub store: Option<String>,
+    pub gift: bool,
+    pub subscription: bool,
+}
+
+#[derive(FromRow)]
+pub struct DLCPurchase {
+    pub id: Uuid,
+    pub base_game_id: Option<Uuid>,
+    pub base_game_name: Option<String>,
+    pub base_game_edition: Option<String>,
+    pub base_game_cover_filename: Option<String>,
+    pub platform_name: String,
+    pub query_date: NaiveDate,
+    pub price: Option<i64>,
+    pub currency: Option<String>,
+    pub store: Option<String>,
+    pub gift: bool,
+    pub subscription: bool,
+}
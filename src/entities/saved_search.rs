@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use chrono::NaiveDateTime;
+use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::{FieldIden, FieldType, Search, TableIden};
+
+pub type SavedSearchSearch = Search<SavedSearchIden>;
+
+#[derive(Clone, Copy, Iden)]
+#[iden = "SavedSearch"]
+pub enum SavedSearchIden {
+    Table,
+    #[iden = "id"]
+    Id,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "name"]
+    Name,
+    #[iden = "type"]
+    Type,
+    #[iden = "search"]
+    Search,
+    #[iden = "added_datetime"]
+    AddedDateTime,
+    #[iden = "updated_datetime"]
+    UpdatedDateTime,
+}
+
+impl TableIden for SavedSearchIden {
+    const TABLE: Self = Self::Table;
+}
+
+#[derive(FromRow)]
+pub struct SavedSearch {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    #[sqlx(rename = "type")] // Fix to use type reserved name
+    pub stype: i16,
+    pub search: String,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+}
+
+impl FromStr for FieldIden<SavedSearchIden> {
+    type Err = ();
+
+    fn from_str(field: &str) -> Result<Self, Self::Err> {
+        match field {
+            "id" => Ok(FieldIden::new(SavedSearchIden::Id, FieldType::String)),
+            "name" => Ok(FieldIden::new(SavedSearchIden::Name, FieldType::String)),
+            "type" => Ok(FieldIden::new(
+                SavedSearchIden::Type,
+                FieldType::SavedSearchType,
+            )),
+            "added_datetime" => Ok(FieldIden::new(
+                SavedSearchIden::AddedDateTime,
+                FieldType::DateTime,
+            )),
+            "updated_datetime" => Ok(FieldIden::new(
+                SavedSearchIden::UpdatedDateTime,
+                FieldType::DateTime,
+            )),
+            _ => Err(()),
+        }
+    }
+}
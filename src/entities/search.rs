@@ -1,23 +1,54 @@
 use sea_query::{BinOper, Iden, Order, SelectStatement};
+use sqlx::FromRow;
 
-use super::TableIden;
+use super::{DLCRelationField, GameCustomValueIden, GameRelationField, TableIden};
 
 pub struct SearchQuery {
     pub query: SelectStatement,
+    pub count_query: Option<SelectStatement>,
+    pub facet_queries: Vec<FacetQuery>,
+    pub cursor: bool,
     pub page: u64,
     pub size: u64,
 }
 
 pub struct Search<I: TableIden> {
     pub filter: Option<Vec<Filter<I>>>,
+    pub filter_group: Option<FilterGroup<I>>,
     pub sort: Option<Vec<Sort<I>>>,
     pub page: Option<u64>,
     pub size: Option<u64>,
+    pub count: bool,
+    pub cursor: Option<String>,
+    pub facets: Vec<Facet<I>>,
+}
+
+/// Field to count the searched rows by each of its values
+pub struct Facet<I: TableIden> {
+    pub name: String,
+    pub field: FieldIden<I>,
+}
+
+pub struct FacetQuery {
+    pub name: String,
+    pub _type: FieldType,
+    pub query: SelectStatement,
+}
+
+pub struct FacetResult {
+    pub name: String,
+    pub _type: FieldType,
+    pub values: Vec<FacetValue>,
+}
+
+#[derive(FromRow)]
+pub struct FacetValue {
+    pub value: Option<String>,
+    pub count: i64,
 }
 
 pub struct Filter<I: TableIden> {
-    pub table: std::rc::Rc<dyn Iden>,
-    pub field: std::rc::Rc<dyn Iden>,
+    pub field: SearchField,
     pub value: FieldValue,
     pub operator: FilterOperator,
     pub chain_operator: BinOper,
@@ -26,14 +57,12 @@ pub struct Filter<I: TableIden> {
 
 impl<T: TableIden> Filter<T> {
     pub fn new<I: TableIden>(
-        table: std::rc::Rc<dyn Iden>,
-        field: std::rc::Rc<dyn Iden>,
+        field: SearchField,
         value: FieldValue,
         operator: FilterOperator,
         chain_operator: BinOper,
     ) -> Self {
         Self {
-            table,
             field,
             value,
             operator,
@@ -43,7 +72,25 @@ impl<T: TableIden> Filter<T> {
     }
 }
 
+pub struct FilterGroup<I: TableIden> {
+    pub operator: GroupOperator,
+    pub nodes: Vec<FilterNode<I>>,
+}
+
+pub enum FilterNode<I: TableIden> {
+    Filter(Filter<I>),
+    Group(FilterGroup<I>),
+}
+
+#[derive(Clone)]
+pub enum GroupOperator {
+    All,
+    Any,
+    Not,
+}
+
 pub enum FieldValue {
+    Empty,
     Value(FieldSearchValue),
     Values(FieldSearchValues),
 }
@@ -74,24 +121,23 @@ pub enum FilterOperator {
     NotEndsWith,
     Contains,
     NotContains,
+    Similar,
+    IsNull,
+    IsNotNull,
 }
 
 pub struct Sort<I: TableIden> {
-    pub table: std::rc::Rc<dyn Iden>,
-    pub field: std::rc::Rc<dyn Iden>,
+    pub field: SearchField,
+    pub _type: FieldType,
     pub order: Order,
     resource_type: std::marker::PhantomData<I>,
 }
 
 impl<T: TableIden> Sort<T> {
-    pub fn new<I: TableIden>(
-        table: std::rc::Rc<dyn Iden>,
-        field: std::rc::Rc<dyn Iden>,
-        order: Order,
-    ) -> Self {
+    pub fn new<I: TableIden>(field: SearchField, _type: FieldType, order: Order) -> Self {
         Self {
-            table,
             field,
+            _type,
             order,
             resource_type: std::marker::PhantomData,
         }
@@ -99,8 +145,7 @@ impl<T: TableIden> Sort<T> {
 }
 
 pub struct FieldIden<I: TableIden> {
-    pub table: std::rc::Rc<dyn Iden>,
-    pub iden: std::rc::Rc<dyn Iden>,
+    pub field: SearchField,
     pub _type: FieldType,
     resource_type: std::marker::PhantomData<I>,
 }
@@ -108,21 +153,47 @@ pub struct FieldIden<I: TableIden> {
 impl<T: TableIden> FieldIden<T> {
     pub fn new<I: 'static + TableIden>(iden: I, _type: FieldType) -> Self {
         Self {
-            table: std::rc::Rc::new(I::TABLE),
-            iden: std::rc::Rc::new(iden),
+            field: SearchField::Column(std::rc::Rc::new(I::TABLE), std::rc::Rc::new(iden)),
             _type,
             resource_type: std::marker::PhantomData,
         }
     }
+
+    pub fn new_relation(relation: RelationField, _type: FieldType) -> Self {
+        Self {
+            field: SearchField::Relation(relation),
+            _type,
+            resource_type: std::marker::PhantomData,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum SearchField {
+    /// Column of the searched table or one of its joins, as table and column
+    Column(std::rc::Rc<dyn Iden>, std::rc::Rc<dyn Iden>),
+    /// Computed from rows related to the searched one, resolved by the query of the resource
+    Relation(RelationField),
+}
+
+#[derive(Clone)]
+pub enum RelationField {
+    Game(GameRelationField),
+    /// Value column and field id of a game custom field
+    GameCustomField(GameCustomValueIden, String),
+    DLC(DLCRelationField),
 }
 
 #[derive(Clone)]
 pub enum FieldType {
     Integer,
+    Number,
     String,
     Boolean,
     Date,
     DateTime,
+    Duration,
     GameStatus,
     PlatformType,
+    SavedSearchType,
 }
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use chrono::NaiveDateTime;
+use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::{FieldIden, FieldType, Search, TableIden};
+
+pub type SeriesSearch = Search<SeriesIden>;
+
+#[derive(Clone, Copy, Iden)]
+#[iden = "Series"]
+pub enum SeriesIden {
+    Table,
+    #[iden = "id"]
+    Id,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "name"]
+    Name,
+    #[iden = "description"]
+    Description,
+    #[iden = "image_filename"]
+    ImageFilename,
+    #[iden = "added_datetime"]
+    AddedDateTime,
+    #[iden = "updated_datetime"]
+    UpdatedDateTime,
+}
+
+impl TableIden for SeriesIden {
+    const TABLE: Self = Self::Table;
+}
+
+#[derive(FromRow)]
+pub struct Series {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub image_filename: Option<String>,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+}
+
+impl FromStr for FieldIden<SeriesIden> {
+    type Err = ();
+
+    fn from_str(field: &str) -> Result<Self, Self::Err> {
+        match field {
+            "id" => Ok(FieldIden::new(SeriesIden::Id, FieldType::String)),
+            "name" => Ok(FieldIden::new(SeriesIden::Name, FieldType::String)),
+            "description" => Ok(FieldIden::new(SeriesIden::Description, FieldType::String)),
+            "image_filename" => Ok(FieldIden::new(SeriesIden::ImageFilename, FieldType::String)),
+            "added_datetime" => Ok(FieldIden::new(
+                SeriesIden::AddedDateTime,
+                FieldType::DateTime,
+            )),
+            "updated_datetime" => Ok(FieldIden::new(
+                SeriesIden::UpdatedDateTime,
+                FieldType::DateTime,
+            )),
+            _ => Err(()),
+        }
+    }
+}
@@ -19,6 +19,12 @@ pub enum TagIden {
     UserId,
     #[iden = "name"]
     Name,
+    #[iden = "parent_id"]
+    ParentId,
+    #[iden = "color"]
+    Color,
+    #[iden = "icon"]
+    Icon,
     #[iden = "added_datetime"]
     AddedDateTime,
     #[iden = "updated_datetime"]
@@ -34,10 +40,46 @@ pub struct Tag {
     pub id: Uuid,
     pub user_id: Uuid,
     pub name: String,
+    pub parent_id: Option<Uuid>,
+    pub color: Option<String>,
+    pub icon: Option<String>,
     pub added_datetime: NaiveDateTime,
     pub updated_datetime: NaiveDateTime,
 }
 
+#[derive(FromRow)]
+pub struct TagWithGameId {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub game_id: Uuid,
+    pub name: String,
+    pub parent_id: Option<Uuid>,
+    pub color: Option<String>,
+    pub icon: Option<String>,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+}
+
+#[derive(FromRow)]
+pub struct TagWithDLCId {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub dlc_id: Uuid,
+    pub name: String,
+    pub parent_id: Option<Uuid>,
+    pub color: Option<String>,
+    pub icon: Option<String>,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+}
+
+/// Tag found under one of the searched ones, which are returned as their own descendants
+#[derive(FromRow)]
+pub struct TagDescendant {
+    pub root_id: Uuid,
+    pub id: Uuid,
+}
+
 impl FromStr for FieldIden<TagIden> {
     type Err = ();
 
@@ -45,6 +87,7 @@ impl FromStr for FieldIden<TagIden> {
         match field {
             "id" => Ok(FieldIden::new(TagIden::Id, FieldType::String)),
             "name" => Ok(FieldIden::new(TagIden::Name, FieldType::String)),
+            "parent_id" => Ok(FieldIden::new(TagIden::ParentId, FieldType::String)),
             "added_datetime" => Ok(FieldIden::new(TagIden::AddedDateTime, FieldType::DateTime)),
             "updated_datetime" => Ok(FieldIden::new(
                 TagIden::UpdatedDateTime,
@@ -21,6 +21,8 @@ pub enum UserIden {
     Password,
     #[iden = "admin"]
     Admin,
+    #[iden = "must_change_password"]
+    MustChangePassword,
     #[iden = "added_datetime"]
     AddedDateTime,
     #[iden = "updated_datetime"]
@@ -37,6 +39,7 @@ pub struct User {
     pub username: String,
     pub password: String,
     pub admin: bool,
+    pub must_change_password: bool,
     pub added_datetime: NaiveDateTime,
     pub updated_datetime: NaiveDateTime,
 }
@@ -49,6 +52,10 @@ impl FromStr for FieldIden<UserIden> {
             "id" => Ok(FieldIden::new(UserIden::Id, FieldType::String)),
             "name" => Ok(FieldIden::new(UserIden::Username, FieldType::String)),
             "admin" => Ok(FieldIden::new(UserIden::Admin, FieldType::Boolean)),
+            "must_change_password" => Ok(FieldIden::new(
+                UserIden::MustChangePassword,
+                FieldType::Boolean,
+            )),
             "added_datetime" => Ok(FieldIden::new(UserIden::AddedDateTime, FieldType::DateTime)),
             "updated_datetime" => Ok(FieldIden::new(
                 UserIden::UpdatedDateTime,
@@ -0,0 +1,42 @@
+use chrono::NaiveDateTime;
+use sea_query::Iden;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Iden)]
+#[iden = "UserTotp"]
+pub enum UserTotpIden {
+    Table,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "secret"]
+    Secret,
+    #[iden = "enabled"]
+    Enabled,
+    #[iden = "last_used_step"]
+    LastUsedStep,
+    #[iden = "added_datetime"]
+    AddedDateTime,
+    #[iden = "updated_datetime"]
+    UpdatedDateTime,
+}
+
+#[derive(Iden)]
+#[iden = "UserRecoveryCode"]
+pub enum UserRecoveryCodeIden {
+    Table,
+    #[iden = "user_id"]
+    UserId,
+    #[iden = "code_hash"]
+    CodeHash,
+}
+
+#[derive(FromRow)]
+pub struct UserTotp {
+    pub user_id: Uuid,
+    pub secret: String,
+    pub enabled: bool,
+    pub last_used_step: Option<i64>,
+    pub added_datetime: NaiveDateTime,
+    pub updated_datetime: NaiveDateTime,
+}
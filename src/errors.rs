@@ -51,6 +51,10 @@ pub mod error_message_builder {
         format!("Field \"{field_name}\" from {model_name} does not exist")
     }
 
+    pub fn relation_not_found(model_name: &str, relation_name: &str) -> String {
+        format!("Relation \"{relation_name}\" from {model_name} does not exist")
+    }
+
     pub fn convert_to_error(value: &str, type_string: &str) -> String {
         format!("Could not convert \"{value}\" to {type_string}")
     }
@@ -60,6 +64,8 @@ pub struct ValidationError();
 
 pub struct PasswordError();
 
+pub struct PasswordPolicyError(pub String);
+
 pub struct RepositoryError();
 
 pub struct ImageClientError();
@@ -78,6 +84,7 @@ pub enum ApiErrors {
     NotFound(String),
     UnknownError(String),
     NotSupported(String),
+    TooManyAttempts(String),
 }
 
 impl ToError for ApiErrors {
@@ -98,6 +105,9 @@ impl ToError for ApiErrors {
             ApiErrors::NotSupported(msg) => {
                 HttpResponse::NotImplemented().json(ErrorMessage::new("not_supported", msg))
             }
+            ApiErrors::TooManyAttempts(msg) => {
+                HttpResponse::TooManyRequests().json(ErrorMessage::new("too_many_attempts", msg))
+            }
         }
     }
 }
@@ -113,6 +123,8 @@ pub enum TokenErrors {
     InvalidRequest(String),
     InvalidGrant(String),
     UnsupportedGrantType(String),
+    TooManyAttempts(String),
+    TotpRequired(String),
     UnknownError(String),
 }
 
@@ -128,6 +140,12 @@ impl ToError for TokenErrors {
             TokenErrors::UnsupportedGrantType(msg) => {
                 HttpResponse::BadRequest().json(ErrorMessage::new("unsupported_grant_type", msg))
             }
+            TokenErrors::TotpRequired(msg) => {
+                HttpResponse::Unauthorized().json(ErrorMessage::new("totp_required", msg))
+            }
+            TokenErrors::TooManyAttempts(msg) => {
+                HttpResponse::TooManyRequests().json(ErrorMessage::new("too_many_attempts", msg))
+            }
             TokenErrors::UnknownError(msg) => {
                 HttpResponse::InternalServerError().json(ErrorMessage::new("unknown_error", msg))
             }
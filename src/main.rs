@@ -1,10 +1,12 @@
-use std::{env, fs::File, io::BufReader};
+use std::{collections::HashSet, env, fs::File, io::BufReader};
 
 use actix_web_httpauth::middleware::HttpAuthentication;
 use dotenvy::dotenv;
 use game_oclock_server::{
     clients::cloudinary::{CloudinaryClient, CloudinaryClientBuilder},
-    migrations, openapi,
+    migrations,
+    models::{ClientIpConfig, PasswordPolicy, RegistrationConfig, RegistrationMode},
+    openapi,
     providers::ImageClientProvider,
     routes,
 };
@@ -21,6 +23,9 @@ const DEFAULT_HOST: &str = "0.0.0.0";
 const DEFAULT_HTTP_PORT: &str = "80";
 const DEFAULT_HTTPS_PORT: &str = "443";
 
+const DEFAULT_INVITATION_EXPIRY_DAYS: i64 = 7;
+const DEFAULT_PASSWORD_MIN_LENGTH: usize = 8;
+
 const TLS_CERT_PATH: &str = "/certs/cert.pem";
 const TLS_KEY_PATH: &str = "/certs/key.pem";
 
@@ -41,6 +46,15 @@ async fn main() -> std::io::Result<()> {
     let encoding_key = generate_encoding_key(&secret_key);
     let decoding_key = generate_decoding_key(&secret_key);
 
+    // Registration
+    let registration_config = load_registration_config();
+
+    // Password policy
+    let password_policy = load_password_policy();
+
+    // Client IP
+    let client_ip_config = load_client_ip_config();
+
     // TLS
     let tls_port = env::var("TLS_PORT")
         .unwrap_or_else(|_| String::from(DEFAULT_HTTPS_PORT))
@@ -48,9 +62,19 @@ async fn main() -> std::io::Result<()> {
         .expect("TLS port is not a number");
     let tls_config = load_tls_config();
 
-    run(host, port, encoding_key, decoding_key, tls_port, tls_config)
-        .await
-        .expect("Could not start server.");
+    run(
+        host,
+        port,
+        encoding_key,
+        decoding_key,
+        registration_config,
+        password_policy,
+        client_ip_config,
+        tls_port,
+        tls_config,
+    )
+    .await
+    .expect("Could not start server.");
 
     Ok(())
 }
@@ -101,6 +125,83 @@ fn get_cloudinary_client_provider() -> Option<CloudinaryClient> {
         .map(|client| CloudinaryClient::default().connect_with(client))
 }
 
+fn load_registration_config() -> RegistrationConfig {
+    let mode = env::var("REGISTRATION_MODE")
+        .map(|mode| {
+            mode.parse()
+                .expect("Registration mode is not valid (closed, open or invitation).")
+        })
+        .unwrap_or_default();
+    let invitation_expiry_days = env::var("INVITATION_EXPIRY_DAYS")
+        .map(|days| {
+            days.parse()
+                .expect("Invitation expiry days is not a number.")
+        })
+        .unwrap_or(DEFAULT_INVITATION_EXPIRY_DAYS);
+
+    if mode != RegistrationMode::Closed {
+        log::info!("User self-registration enabled.");
+    }
+
+    RegistrationConfig {
+        mode,
+        invitation_expiry_days,
+    }
+}
+
+fn load_password_policy() -> PasswordPolicy {
+    let min_length = env::var("PASSWORD_MIN_LENGTH")
+        .map(|length| {
+            length
+                .parse()
+                .expect("Password minimum length is not a number.")
+        })
+        .unwrap_or(DEFAULT_PASSWORD_MIN_LENGTH);
+    let reject_common = env::var("PASSWORD_REJECT_COMMON")
+        .map(|reject| {
+            reject
+                .parse()
+                .expect("Password reject common is not a boolean.")
+        })
+        .unwrap_or(true);
+
+    let common_passwords = if reject_common {
+        load_common_passwords()
+    } else {
+        HashSet::new()
+    };
+
+    PasswordPolicy {
+        min_length,
+        reject_common,
+        common_passwords,
+    }
+}
+
+fn load_common_passwords() -> HashSet<String> {
+    let common_passwords = match env::var("PASSWORD_COMMON_LIST_PATH") {
+        Ok(path) => {
+            let content =
+                std::fs::read_to_string(path).expect("Password common list could not be read.");
+            game_oclock_server::auth::parse_common_passwords(&content)
+        }
+        Err(_) => game_oclock_server::auth::default_common_passwords(),
+    };
+    log::info!("{} common passwords loaded.", common_passwords.len());
+
+    common_passwords
+}
+
+fn load_client_ip_config() -> ClientIpConfig {
+    let trusted_header = env::var("CLIENT_IP_HEADER").ok();
+
+    if trusted_header.is_none() {
+        log::info!("No client IP header set, login attempts will be limited by peer address.");
+    }
+
+    ClientIpConfig { trusted_header }
+}
+
 fn generate_encoding_key(key: &str) -> EncodingKey {
     EncodingKey::from_secret(key.as_ref())
 }
@@ -114,11 +215,17 @@ async fn run(
     port: u16,
     encoding_key: EncodingKey,
     decoding_key: DecodingKey,
+    registration_config: RegistrationConfig,
+    password_policy: PasswordPolicy,
+    client_ip_config: ClientIpConfig,
     tls_port: u16,
     tls_config: Option<rustls::ServerConfig>,
 ) -> std::io::Result<()> {
     let data_encoding_key = web::Data::new(encoding_key);
     let data_decoding_key = web::Data::new(decoding_key);
+    let data_registration_config = web::Data::new(registration_config);
+    let data_password_policy = web::Data::new(password_policy);
+    let data_client_ip_config = web::Data::new(client_ip_config);
 
     // Repository
     let database_connection_pool = get_connection_pool()
@@ -149,25 +256,43 @@ async fn run(
             .app_data(data_image_client.clone())
             .app_data(data_encoding_key.clone())
             .app_data(data_decoding_key.clone())
+            .app_data(data_registration_config.clone())
+            .app_data(data_password_policy.clone())
+            .app_data(data_client_ip_config.clone())
             .service(
                 web::scope("/api").service(
                     web::scope("/v1")
                         .wrap(auth)
                         // Games
+                        // Bulk routes go first so "bulk" is not taken as a game id
+                        .service(routes::put_games_bulk)
+                        .service(routes::link_games_bulk_tags)
+                        .service(routes::link_games_bulk_platforms)
+                        .service(routes::delete_games_bulk)
+                        .service(routes::unlink_games_bulk_tags)
+                        .service(routes::unlink_games_bulk_platforms)
+                        .service(routes::get_expiring_games)
                         .service(routes::get_game)
                         .service(routes::get_tag_games)
                         .service(routes::get_platform_games)
+                        .service(routes::get_game_relationships)
                         .service(routes::get_games)
                         .service(routes::post_game)
                         .service(routes::post_game_cover)
+                        .service(routes::merge_games)
                         .service(routes::put_game)
                         .service(routes::put_game_cover)
+                        .service(routes::put_game_platform_availability)
                         .service(routes::link_game_platform)
                         .service(routes::link_game_tag)
+                        .service(routes::link_game_series)
+                        .service(routes::link_game_relationship)
                         .service(routes::delete_game)
                         .service(routes::delete_game_cover)
                         .service(routes::unlink_game_tag)
                         .service(routes::unlink_game_platform)
+                        .service(routes::unlink_game_series)
+                        .service(routes::unlink_game_relationship)
                         // Game Finish
                         .service(routes::get_game_finishes)
                         .service(routes::get_first_game_finish)
@@ -185,21 +310,28 @@ async fn run(
                         .service(routes::get_last_played_games)
                         .service(routes::post_game_log)
                         .service(routes::delete_game_log)
+                        // Game Status History
+                        .service(routes::get_game_status_history)
+                        .service(routes::get_status_games_review)
                         // DLCs
                         .service(routes::get_dlc)
                         .service(routes::get_dlc_base_game)
                         .service(routes::get_game_dlcs)
+                        .service(routes::get_tag_dlcs)
                         .service(routes::get_platform_dlcs)
                         .service(routes::get_dlcs)
                         .service(routes::post_dlc)
                         .service(routes::post_dlc_cover)
                         .service(routes::put_dlc)
                         .service(routes::put_dlc_cover)
+                        .service(routes::put_dlc_platform_availability)
                         .service(routes::link_dlc_game)
+                        .service(routes::link_dlc_tag)
                         .service(routes::link_dlc_platform)
                         .service(routes::delete_dlc)
                         .service(routes::delete_dlc_cover)
                         .service(routes::unlink_dlc_game)
+                        .service(routes::unlink_dlc_tag)
                         .service(routes::unlink_dlc_platform)
                         // DLC Finish
                         .service(routes::get_dlc_finishes)
@@ -220,12 +352,43 @@ async fn run(
                         .service(routes::delete_platform)
                         .service(routes::delete_platform_icon)
                         // Tags
+                        // Tree goes first so "tree" is not taken as a tag id
+                        .service(routes::get_tag_tree)
                         .service(routes::get_tag)
                         .service(routes::get_game_tags)
+                        .service(routes::get_dlc_tags)
                         .service(routes::get_tags)
                         .service(routes::post_tag)
                         .service(routes::put_tag)
                         .service(routes::delete_tag)
+                        // Series
+                        .service(routes::get_series)
+                        .service(routes::get_game_series)
+                        .service(routes::get_series_games)
+                        .service(routes::get_series_progress)
+                        .service(routes::get_series_list)
+                        .service(routes::post_series)
+                        .service(routes::post_series_image)
+                        .service(routes::put_series)
+                        .service(routes::put_series_image)
+                        .service(routes::delete_series)
+                        .service(routes::delete_series_image)
+                        // Search
+                        .service(routes::get_global_search)
+                        // Saved searches
+                        .service(routes::get_saved_search)
+                        .service(routes::get_saved_searches)
+                        .service(routes::post_saved_search)
+                        .service(routes::put_saved_search)
+                        .service(routes::delete_saved_search)
+                        // Custom fields
+                        .service(routes::get_custom_field)
+                        .service(routes::get_custom_fields)
+                        .service(routes::post_custom_field)
+                        .service(routes::put_custom_field)
+                        .service(routes::delete_custom_field)
+                        // Spending
+                        .service(routes::get_spending_review)
                         // Users
                         .service(routes::get_user)
                         .service(routes::get_current_user)
@@ -233,13 +396,28 @@ async fn run(
                         .service(routes::post_user)
                         .service(routes::put_user)
                         .service(routes::change_password)
+                        .service(routes::enroll_totp)
+                        .service(routes::activate_totp)
+                        .service(routes::regenerate_totp_recovery_codes)
+                        .service(routes::disable_totp)
                         .service(routes::promote_user)
                         .service(routes::demote_user)
-                        .service(routes::delete_user),
+                        .service(routes::unlock_user)
+                        .service(routes::reset_user_password)
+                        .service(routes::delete_user)
+                        // Invitations
+                        .service(routes::get_invitations)
+                        .service(routes::post_invitation)
+                        .service(routes::delete_invitation),
                 ),
             )
             // Authentication
-            .service(web::scope("/auth").service(routes::token))
+            .service(
+                web::scope("/auth")
+                    .service(routes::token)
+                    .service(routes::get_registration_info)
+                    .service(routes::register),
+            )
             // Health check
             .service(routes::health)
             // OpenAPI
@@ -0,0 +1,49 @@
+use uuid::Uuid;
+
+use crate::entities::{CustomField, GameCustomValueWithField};
+use crate::models::{CustomFieldDTO, CustomFieldType};
+
+impl From<CustomField> for CustomFieldDTO {
+    fn from(custom_field: CustomField) -> Self {
+        Self {
+            id: custom_field.id.to_string(),
+            name: custom_field.name,
+            ftype: CustomFieldType::try_from(custom_field.ftype)
+                .expect("Type was not within valid range"),
+            options: serde_json::from_str(&custom_field.options).unwrap_or_default(),
+            added_datetime: custom_field.added_datetime,
+            updated_datetime: custom_field.updated_datetime,
+        }
+    }
+}
+
+impl From<CustomFieldDTO> for CustomField {
+    fn from(custom_field: CustomFieldDTO) -> Self {
+        Self {
+            id: Uuid::default(),
+            user_id: Uuid::default(),
+            name: custom_field.name,
+            ftype: i16::from(custom_field.ftype),
+            options: serde_json::to_string(&custom_field.options)
+                .expect("Options could not be serialized"),
+            added_datetime: custom_field.added_datetime,
+            updated_datetime: custom_field.updated_datetime,
+        }
+    }
+}
+
+impl From<GameCustomValueWithField> for serde_json::Value {
+    fn from(custom_value: GameCustomValueWithField) -> Self {
+        if let Some(text_value) = custom_value.text_value {
+            serde_json::Value::from(text_value)
+        } else if let Some(number_value) = custom_value.number_value {
+            serde_json::Value::from(number_value)
+        } else if let Some(date_value) = custom_value.date_value {
+            serde_json::Value::from(date_value.to_string())
+        } else if let Some(bool_value) = custom_value.bool_value {
+            serde_json::Value::from(bool_value)
+        } else {
+            serde_json::Value::Null
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::models::CustomFieldType;
+
+impl TryFrom<i16> for CustomFieldType {
+    type Error = ();
+
+    fn try_from(ftype: i16) -> Result<Self, Self::Error> {
+        match ftype {
+            0 => Ok(CustomFieldType::Text),
+            1 => Ok(CustomFieldType::Number),
+            2 => Ok(CustomFieldType::Date),
+            3 => Ok(CustomFieldType::Bool),
+            4 => Ok(CustomFieldType::Enum),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<CustomFieldType> for i16 {
+    fn from(ftype: CustomFieldType) -> Self {
+        match ftype {
+            CustomFieldType::Text => 0,
+            CustomFieldType::Number => 1,
+            CustomFieldType::Date => 2,
+            CustomFieldType::Bool => 3,
+            CustomFieldType::Enum => 4,
+        }
+    }
+}
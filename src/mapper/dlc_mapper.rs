@@ -1,7 +1,7 @@
 use uuid::Uuid;
 
 use crate::entities::{DLCWithDate, DLC};
-use crate::models::{DLCAvailableDTO, DLCDTO};
+use crate::models::{DLCAvailableDTO, GameStatus, DLCDTO};
 
 impl From<DLC> for DLCDTO {
     fn from(dlc: DLC) -> Self {
@@ -14,6 +14,10 @@ impl From<DLC> for DLCDTO {
             cover_url: None,
             added_datetime: dlc.added_datetime,
             updated_datetime: dlc.updated_datetime,
+            status: GameStatus::try_from(dlc.status).expect("Status was not within valid range"),
+            rating: dlc.rating,
+            notes: dlc.notes,
+            tags: None,
         }
     }
 }
@@ -31,6 +35,9 @@ impl From<DLCDTO> for DLC {
             cover_filename: dlc.cover_filename,
             added_datetime: dlc.added_datetime,
             updated_datetime: dlc.updated_datetime,
+            status: i16::from(dlc.status),
+            rating: dlc.rating,
+            notes: dlc.notes,
         }
     }
 }
@@ -46,6 +53,9 @@ impl From<DLCWithDate> for DLCAvailableDTO {
             cover_url: None,
             added_datetime: dlc.added_datetime,
             updated_datetime: dlc.updated_datetime,
+            status: GameStatus::try_from(dlc.status).expect("Status was not within valid range"),
+            rating: dlc.rating,
+            notes: dlc.notes,
             available_date: dlc.query_date,
         }
     }
@@ -1,5 +1,5 @@
 use crate::entities::DLCWithDate;
-use crate::models::DLCWithFinishDTO;
+use crate::models::{DLCWithFinishDTO, GameStatus};
 
 impl From<DLCWithDate> for DLCWithFinishDTO {
     fn from(dlc: DLCWithDate) -> Self {
@@ -12,6 +12,9 @@ impl From<DLCWithDate> for DLCWithFinishDTO {
             cover_url: None,
             added_datetime: dlc.added_datetime,
             updated_datetime: dlc.updated_datetime,
+            status: GameStatus::try_from(dlc.status).expect("Status was not within valid range"),
+            rating: dlc.rating,
+            notes: dlc.notes,
             finish_date: dlc.query_date,
         }
     }
@@ -1,7 +1,7 @@
 use uuid::Uuid;
 
-use crate::entities::{Game, GameWithDate};
-use crate::models::{GameAvailableDTO, GameDTO, GameStatus};
+use crate::entities::{Game, GameWithAvailabilityEnd, GameWithDate};
+use crate::models::{GameAvailableDTO, GameDTO, GameExpiringDTO, GameStatus};
 
 impl From<Game> for GameDTO {
     fn from(game: Game) -> Self {
@@ -20,6 +20,12 @@ impl From<Game> for GameDTO {
             save_folder: game.save_folder,
             screenshot_folder: game.screenshot_folder,
             backup: game.backup,
+            tags: None,
+            platforms: None,
+            dlcs: None,
+            finishes: None,
+            total_time: None,
+            custom_fields: None,
         }
     }
 }
@@ -45,6 +51,24 @@ impl From<GameDTO> for Game {
     }
 }
 
+impl From<GameWithAvailabilityEnd> for GameExpiringDTO {
+    fn from(game: GameWithAvailabilityEnd) -> Self {
+        Self {
+            id: game.id.to_string(),
+            name: game.name,
+            edition: game.edition,
+            release_year: game.release_year,
+            cover_filename: game.cover_filename,
+            cover_url: None,
+            status: GameStatus::try_from(game.status).expect("Status was not within valid range"),
+            platform_id: game.platform_id.to_string(),
+            platform_name: game.platform_name,
+            available_date: game.query_date,
+            end_date: game.end_date,
+        }
+    }
+}
+
 impl From<GameWithDate> for GameAvailableDTO {
     fn from(game: GameWithDate) -> Self {
         Self {
@@ -0,0 +1,26 @@
+use crate::entities::GameWithRelationship;
+use crate::models::{GameRelationshipType, GameStatus, RelatedGameDTO};
+
+impl From<GameWithRelationship> for RelatedGameDTO {
+    fn from(game: GameWithRelationship) -> Self {
+        Self {
+            id: game.id.to_string(),
+            name: game.name,
+            edition: game.edition,
+            release_year: game.release_year,
+            cover_filename: game.cover_filename,
+            cover_url: None,
+            added_datetime: game.added_datetime,
+            updated_datetime: game.updated_datetime,
+            status: GameStatus::try_from(game.status).expect("Status was not within valid range"),
+            rating: game.rating,
+            notes: game.notes,
+            save_folder: game.save_folder,
+            screenshot_folder: game.screenshot_folder,
+            backup: game.backup,
+            relationship_type: GameRelationshipType::try_from(game.relationship_type)
+                .expect("Relationship type was not within valid range"),
+            inverse: false,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::models::GameRelationshipType;
+
+impl TryFrom<i16> for GameRelationshipType {
+    type Error = ();
+
+    fn try_from(rtype: i16) -> Result<Self, Self::Error> {
+        match rtype {
+            0 => Ok(GameRelationshipType::RemasterOf),
+            1 => Ok(GameRelationshipType::PortOf),
+            2 => Ok(GameRelationshipType::SequelOf),
+            3 => Ok(GameRelationshipType::CollectionContains),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<GameRelationshipType> for i16 {
+    fn from(rtype: GameRelationshipType) -> Self {
+        match rtype {
+            GameRelationshipType::RemasterOf => 0,
+            GameRelationshipType::PortOf => 1,
+            GameRelationshipType::SequelOf => 2,
+            GameRelationshipType::CollectionContains => 3,
+        }
+    }
+}
@@ -2,9 +2,10 @@ use std::collections::HashMap;
 
 use chrono::{NaiveDate, NaiveDateTime};
 
-use crate::entities::{GameWithDate, GameWithLog};
+use crate::entities::{DLCWithDate, GameWithDate, GameWithLog, GameWithStatusChange};
 use crate::models::{
-    DurationDef, GameFinishedReviewDTO, GameLogDTO, GamePlayedReviewDTO, GameStatus, GameStreakDTO,
+    DLCFinishedReviewDTO, DurationDef, GameFinishedReviewDTO, GameLogDTO, GamePlayedReviewDTO,
+    GameStatus, GameStatusReviewDTO, GameStreakDTO,
 };
 
 impl From<GameWithLog> for GamePlayedReviewDTO {
@@ -79,3 +80,43 @@ impl From<GameWithDate> for GameFinishedReviewDTO {
         }
     }
 }
+
+impl From<GameWithStatusChange> for GameStatusReviewDTO {
+    fn from(game: GameWithStatusChange) -> Self {
+        Self {
+            id: game.id.to_string(),
+            name: game.name,
+            edition: game.edition,
+            cover_filename: game.cover_filename,
+            cover_url: None,
+            added_datetime: game.added_datetime,
+            first_played: false,
+            days_to_first_played: None,
+            abandoned: false,
+            changes: vec![],
+        }
+    }
+}
+
+impl From<DLCWithDate> for DLCFinishedReviewDTO {
+    fn from(dlc: DLCWithDate) -> Self {
+        Self {
+            id: dlc.id.to_string(),
+            name: dlc.name,
+            base_game_id: dlc.base_game_id.map(|id| id.to_string()),
+            release_year: dlc.release_year,
+            cover_filename: dlc.cover_filename,
+            cover_url: None,
+            added_datetime: dlc.added_datetime,
+            updated_datetime: dlc.updated_datetime,
+            status: GameStatus::try_from(dlc.status).expect("Status was not within valid range"),
+            rating: dlc.rating,
+            notes: dlc.notes,
+            total_finished: 0,
+            first_finished: false,
+            first_finish: NaiveDate::MAX,
+            last_finish: NaiveDate::MIN,
+            finishes: vec![],
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::entities::{GameStatusChange, GameWithStatusChange};
+use crate::models::{GameStatus, GameStatusChangeDTO};
+
+impl From<GameStatusChange> for GameStatusChangeDTO {
+    fn from(change: GameStatusChange) -> Self {
+        Self {
+            status: GameStatus::try_from(change.status).expect("Status was not within valid range"),
+            previous_status: change.previous_status.map(|status| {
+                GameStatus::try_from(status).expect("Status was not within valid range")
+            }),
+            datetime: change.datetime,
+        }
+    }
+}
+
+// TODO Remove borrow
+impl From<&GameWithStatusChange> for GameStatusChangeDTO {
+    fn from(game: &GameWithStatusChange) -> Self {
+        Self {
+            status: GameStatus::try_from(game.status).expect("Status was not within valid range"),
+            previous_status: game.previous_status.map(|status| {
+                GameStatus::try_from(status).expect("Status was not within valid range")
+            }),
+            datetime: game.datetime,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::models::{GameDTO, PlatformDTO, SearchResultDTO, SearchResultType, TagDTO, DLCDTO};
+
+impl From<GameDTO> for SearchResultDTO {
+    fn from(game: GameDTO) -> Self {
+        let name = if game.edition.is_empty() {
+            game.name
+        } else {
+            format!("{} ({})", game.name, game.edition)
+        };
+
+        Self {
+            rtype: SearchResultType::Game,
+            id: game.id,
+            name,
+            image_url: game.cover_url,
+        }
+    }
+}
+
+impl From<DLCDTO> for SearchResultDTO {
+    fn from(dlc: DLCDTO) -> Self {
+        Self {
+            rtype: SearchResultType::DLC,
+            id: dlc.id,
+            name: dlc.name,
+            image_url: dlc.cover_url,
+        }
+    }
+}
+
+impl From<PlatformDTO> for SearchResultDTO {
+    fn from(platform: PlatformDTO) -> Self {
+        Self {
+            rtype: SearchResultType::Platform,
+            id: platform.id,
+            name: platform.name,
+            image_url: platform.icon_url,
+        }
+    }
+}
+
+impl From<TagDTO> for SearchResultDTO {
+    fn from(tag: TagDTO) -> Self {
+        Self {
+            rtype: SearchResultType::Tag,
+            id: tag.id,
+            name: tag.name,
+            image_url: None,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::entities::Invitation;
+use crate::models::InvitationDTO;
+
+impl From<Invitation> for InvitationDTO {
+    fn from(invitation: Invitation) -> Self {
+        Self {
+            id: invitation.id.to_string(),
+            code: invitation.code,
+            expiry_datetime: invitation.expiry_datetime,
+            used_by_user_id: invitation.used_by_user_id.map(|id| id.to_string()),
+            used_datetime: invitation.used_datetime,
+            added_datetime: invitation.added_datetime,
+            updated_datetime: invitation.updated_datetime,
+        }
+    }
+}
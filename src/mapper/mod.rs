@@ -1,14 +1,25 @@
+mod custom_field_mapper;
+mod custom_field_type_mapper;
 mod dlc_mapper;
 mod dlc_with_finish_mapper;
 mod duration_mapper;
 mod game_log_mapper;
 mod game_mapper;
+mod game_relationship_mapper;
+mod game_relationship_type_mapper;
 mod game_review_mapper;
+mod game_status_history_mapper;
 mod game_status_mapper;
 mod game_with_finish_mapper;
 mod game_with_logs_mapper;
+mod global_search_mapper;
+mod invitation_mapper;
 mod platform_mapper;
 mod platform_type_mapper;
+mod purchase_mapper;
+mod saved_search_mapper;
+mod saved_search_type_mapper;
 mod search_mapper;
+mod series_mapper;
 mod tag_mapper;
 mod user_mapper;
@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::entities::{Platform, PlatformWithDate};
+use crate::entities::{Platform, PlatformWithDate, PlatformWithGameIdAndDate};
 use crate::models::{PlatformAvailableDTO, PlatformDTO, PlatformType};
 
 impl From<Platform> for PlatformDTO {
@@ -46,6 +46,35 @@ impl From<PlatformWithDate> for PlatformAvailableDTO {
             icon_url: None,
             added_datetime: platform.added_datetime,
             updated_datetime: platform.updated_datetime,
+            end_date: platform.end_date,
+            price: platform.price,
+            currency: platform.currency,
+            store: platform.store,
+            gift: platform.gift,
+            subscription: platform.subscription,
+        }
+    }
+}
+
+impl From<PlatformWithGameIdAndDate> for PlatformAvailableDTO {
+    fn from(platform: PlatformWithGameIdAndDate) -> Self {
+        Self {
+            id: platform.id.to_string(),
+            available_date: platform.query_date,
+            name: platform.name,
+            ptype: platform.ptype.map(|ptype| {
+                PlatformType::try_from(ptype).expect("Type was not within valid range")
+            }),
+            icon_filename: platform.icon_filename,
+            icon_url: None,
+            added_datetime: platform.added_datetime,
+            updated_datetime: platform.updated_datetime,
+            end_date: platform.end_date,
+            price: platform.price,
+            currency: platform.currency,
+            store: platform.store,
+            gift: platform.gift,
+            subscription: platform.subscription,
         }
     }
 }
@@ -7,6 +7,7 @@ impl TryFrom<i16> for PlatformType {
         match ptype {
             0 => Ok(PlatformType::Physical),
             1 => Ok(PlatformType::Digital),
+            2 => Ok(PlatformType::Subscription),
             _ => Err(()),
         }
     }
@@ -17,6 +18,7 @@ impl From<PlatformType> for i16 {
         match ptype {
             PlatformType::Physical => 0,
             PlatformType::Digital => 1,
+            PlatformType::Subscription => 2,
         }
     }
 }
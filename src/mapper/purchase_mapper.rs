@@ -0,0 +1,18 @@
+use crate::entities::Availability;
+use crate::models::AvailabilityDTO;
+
+impl From<AvailabilityDTO> for Availability {
+    fn from(availability: AvailabilityDTO) -> Self {
+        Self {
+            added_date: availability.date,
+            end_date: availability.end_date,
+            price: availability.price,
+            currency: availability
+                .currency
+                .map(|currency| currency.trim().to_uppercase()),
+            store: availability.store,
+            gift: availability.gift.unwrap_or_default(),
+            subscription: availability.subscription.unwrap_or_default(),
+        }
+    }
+}
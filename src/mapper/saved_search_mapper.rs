@@ -0,0 +1,33 @@
+use uuid::Uuid;
+
+use crate::entities::SavedSearch;
+use crate::models::{SavedSearchDTO, SavedSearchType};
+
+impl From<SavedSearch> for SavedSearchDTO {
+    fn from(saved_search: SavedSearch) -> Self {
+        Self {
+            id: saved_search.id.to_string(),
+            name: saved_search.name,
+            stype: SavedSearchType::try_from(saved_search.stype)
+                .expect("Type was not within valid range"),
+            search: serde_json::from_str(&saved_search.search).unwrap_or_default(),
+            added_datetime: saved_search.added_datetime,
+            updated_datetime: saved_search.updated_datetime,
+        }
+    }
+}
+
+impl From<SavedSearchDTO> for SavedSearch {
+    fn from(saved_search: SavedSearchDTO) -> Self {
+        Self {
+            id: Uuid::default(),
+            user_id: Uuid::default(),
+            name: saved_search.name,
+            stype: i16::from(saved_search.stype),
+            search: serde_json::to_string(&saved_search.search)
+                .expect("Search could not be serialized"),
+            added_datetime: saved_search.added_datetime,
+            updated_datetime: saved_search.updated_datetime,
+        }
+    }
+}
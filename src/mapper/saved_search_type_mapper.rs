@@ -0,0 +1,26 @@
+use crate::models::SavedSearchType;
+
+impl TryFrom<i16> for SavedSearchType {
+    type Error = ();
+
+    fn try_from(stype: i16) -> Result<Self, Self::Error> {
+        match stype {
+            0 => Ok(SavedSearchType::Game),
+            1 => Ok(SavedSearchType::DLC),
+            2 => Ok(SavedSearchType::Platform),
+            3 => Ok(SavedSearchType::Tag),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<SavedSearchType> for i16 {
+    fn from(stype: SavedSearchType) -> Self {
+        match stype {
+            SavedSearchType::Game => 0,
+            SavedSearchType::DLC => 1,
+            SavedSearchType::Platform => 2,
+            SavedSearchType::Tag => 3,
+        }
+    }
+}
@@ -4,15 +4,18 @@ use chrono::{NaiveDate, NaiveDateTime};
 use sea_query::{BinOper, Order, Value};
 
 use crate::entities::{
-    FieldIden, FieldSearchValue, FieldSearchValues, FieldType, FieldValue, Filter, FilterOperator,
-    Search, Sort, TableIden,
+    Facet, FacetResult, FieldIden, FieldSearchValue, FieldSearchValues, FieldType, FieldValue,
+    Filter, FilterGroup, FilterNode, FilterOperator, GroupOperator, Search, Sort, TableIden,
 };
 use crate::errors::{error_message_builder, MappingError};
 use crate::models::{
-    ChainOperatorType, FilterDTO, GameStatus, OperatorType, OrderType, PlatformType, SearchDTO,
-    SearchValue, SortDTO,
+    ChainOperatorType, DurationDef, FacetDTO, FacetValueDTO, FilterDTO, FilterGroupDTO,
+    FilterNodeDTO, GameStatus, GroupOperatorType, OperatorType, OrderType, PlatformType,
+    SavedSearchType, SearchDTO, SearchValue, SortDTO,
 };
 
+const ID_FIELD: &str = "id";
+
 impl From<OperatorType> for FilterOperator {
     fn from(operator: OperatorType) -> Self {
         match operator {
@@ -30,6 +33,9 @@ impl From<OperatorType> for FilterOperator {
             OperatorType::NotEndsWith => FilterOperator::NotEndsWith,
             OperatorType::Contains => FilterOperator::Contains,
             OperatorType::NotContains => FilterOperator::NotContains,
+            OperatorType::Similar => FilterOperator::Similar,
+            OperatorType::IsNull => FilterOperator::IsNull,
+            OperatorType::IsNotNull => FilterOperator::IsNotNull,
         }
     }
 }
@@ -52,6 +58,16 @@ impl From<ChainOperatorType> for BinOper {
     }
 }
 
+impl From<GroupOperatorType> for GroupOperator {
+    fn from(operator: GroupOperatorType) -> Self {
+        match operator {
+            GroupOperatorType::And => GroupOperator::All,
+            GroupOperatorType::Or => GroupOperator::Any,
+            GroupOperatorType::Not => GroupOperator::Not,
+        }
+    }
+}
+
 impl<I: TableIden> TryFrom<SearchDTO> for Search<I>
 where
     FieldIden<I>: FromStr,
@@ -70,22 +86,53 @@ where
             None => None,
         };
 
+        let filter_group = match search.filter_group {
+            Some(group) => Some(FilterGroup::try_from(group)?),
+            None => None,
+        };
+
         let sort_result = search.sort.map(|sorts| {
             sorts
                 .into_iter()
                 .map(Sort::try_from)
                 .collect::<Result<Vec<Sort<I>>, MappingError>>()
         });
-        let sort = match sort_result {
+        let mut sort = match sort_result {
             Some(res) => Some(res?),
             None => None,
         };
 
+        // Unique tie breaker so rows sharing the sort values are neither repeated nor skipped
+        if search.cursor.is_some() {
+            let id_iden = FieldIden::<I>::from_str(ID_FIELD)
+                .map_err(|_| MappingError(String::from(ID_FIELD)))?;
+            sort.get_or_insert_with(Vec::new).push(Sort::new::<I>(
+                id_iden.field,
+                id_iden._type,
+                Order::Asc,
+            ));
+        }
+
+        let facets = search
+            .facets
+            .unwrap_or_default()
+            .into_iter()
+            .map(|name| {
+                let field =
+                    FieldIden::<I>::from_str(&name).map_err(|_| MappingError(name.clone()))?;
+                Ok(Facet { name, field })
+            })
+            .collect::<Result<Vec<Facet<I>>, MappingError>>()?;
+
         Ok(Self {
             filter,
+            filter_group,
             sort,
             page: search.page,
             size: search.size,
+            count: search.count.unwrap_or(false),
+            cursor: search.cursor,
+            facets,
         })
     }
 }
@@ -101,17 +148,17 @@ where
             FieldIden::<I>::from_str(&filter.field).map_err(|_| MappingError(filter.field))?;
 
         Ok(Self::new::<I>(
-            field_iden.table,
-            field_iden.iden,
+            field_iden.field,
             match filter.value {
-                SearchValue::Value(value) => FieldValue::Value(FieldSearchValue {
+                Some(SearchValue::Value(value)) => FieldValue::Value(FieldSearchValue {
                     _type: field_iden._type,
                     value,
                 }),
-                SearchValue::Values(values) => FieldValue::Values(FieldSearchValues {
+                Some(SearchValue::Values(values)) => FieldValue::Values(FieldSearchValues {
                     _type: field_iden._type,
                     values,
                 }),
+                None => FieldValue::Empty,
             },
             FilterOperator::from(filter.operator),
             match filter.chain_operator {
@@ -122,6 +169,29 @@ where
     }
 }
 
+impl<I: TableIden> TryFrom<FilterGroupDTO> for FilterGroup<I>
+where
+    FieldIden<I>: FromStr,
+{
+    type Error = MappingError;
+
+    fn try_from(group: FilterGroupDTO) -> Result<Self, Self::Error> {
+        let nodes = group
+            .filters
+            .into_iter()
+            .map(|node| match node {
+                FilterNodeDTO::Filter(filter) => Filter::try_from(filter).map(FilterNode::Filter),
+                FilterNodeDTO::Group(group) => FilterGroup::try_from(group).map(FilterNode::Group),
+            })
+            .collect::<Result<Vec<FilterNode<I>>, MappingError>>()?;
+
+        Ok(Self {
+            operator: GroupOperator::from(group.operator),
+            nodes,
+        })
+    }
+}
+
 impl<I: TableIden> TryFrom<SortDTO> for Sort<I>
 where
     FieldIden<I>: FromStr,
@@ -133,13 +203,56 @@ where
             FieldIden::<I>::from_str(&sort.field).map_err(|_| MappingError(sort.field))?;
 
         Ok(Self::new::<I>(
-            field_iden.table,
-            field_iden.iden,
+            field_iden.field,
+            field_iden._type,
             Order::from(sort.order),
         ))
     }
 }
 
+impl From<FacetResult> for FacetDTO {
+    fn from(facet: FacetResult) -> Self {
+        let _type = facet._type;
+        Self {
+            field: facet.name,
+            values: facet
+                .values
+                .into_iter()
+                .map(|facet_value| FacetValueDTO {
+                    value: facet_value
+                        .value
+                        .map(|value| facet_value_to_string(&_type, value)),
+                    count: facet_value.count.max(0) as u64,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Stored values of enum types are shown as they are used in filters
+fn facet_value_to_string(_type: &FieldType, value: String) -> String {
+    let stored_value = match value.parse::<i16>() {
+        Ok(stored_value) => stored_value,
+        Err(_) => return value,
+    };
+    let enum_value = match _type {
+        FieldType::GameStatus => GameStatus::try_from(stored_value)
+            .ok()
+            .and_then(|status| serde_json::to_value(status).ok()),
+        FieldType::PlatformType => PlatformType::try_from(stored_value)
+            .ok()
+            .and_then(|ptype| serde_json::to_value(ptype).ok()),
+        FieldType::SavedSearchType => SavedSearchType::try_from(stored_value)
+            .ok()
+            .and_then(|stype| serde_json::to_value(stype).ok()),
+        _ => None,
+    };
+
+    enum_value
+        .and_then(|enum_value| enum_value.as_str().map(String::from))
+        .unwrap_or(value)
+}
+
 impl TryFrom<FieldSearchValue> for Value {
     type Error = MappingError;
 
@@ -150,19 +263,36 @@ impl TryFrom<FieldSearchValue> for Value {
                 let int_value = convert_with_serde::<i32>(value, "integer")?;
                 Ok(int_value.into())
             }
+            FieldType::Number => {
+                let number_value = convert_with_serde::<f64>(value, "number")?;
+                Ok(number_value.into())
+            }
             FieldType::String => Ok(value.into()),
             FieldType::Boolean => {
                 let bool_value = convert_with_serde::<bool>(value, "boolean")?;
                 Ok(bool_value.into())
             }
             FieldType::Date => {
-                let date_value = convert_with_serde::<NaiveDate>(value, "date")?;
+                let date_value = match crate::date_utils::parse_relative_datetime(value) {
+                    Some(relative_date_time) => relative_date_time.date(),
+                    None => convert_with_serde::<NaiveDate>(value, "date")?,
+                };
                 Ok(date_value.into())
             }
             FieldType::DateTime => {
-                let date_time_value = convert_with_serde::<NaiveDateTime>(value, "date time")?;
+                let date_time_value = match crate::date_utils::parse_relative_datetime(value) {
+                    Some(relative_date_time) => relative_date_time,
+                    None => convert_with_serde::<NaiveDateTime>(value, "date time")?,
+                };
                 Ok(date_time_value.into())
             }
+            FieldType::Duration => {
+                let duration = DurationDef::from_str(value).map_err(|_| {
+                    MappingError(error_message_builder::convert_to_error(value, "duration"))
+                })?;
+                let duration_secs = duration.micros / crate::date_utils::MICROS_PER_SECOND;
+                Ok(duration_secs.into())
+            }
             FieldType::GameStatus => {
                 let status =
                     convert_with_serde::<GameStatus>(&format!("\"{value}\""), "game status")?;
@@ -175,6 +305,14 @@ impl TryFrom<FieldSearchValue> for Value {
                 let ptype_value = i16::from(ptype);
                 Ok(ptype_value.into())
             }
+            FieldType::SavedSearchType => {
+                let stype = convert_with_serde::<SavedSearchType>(
+                    &format!("\"{value}\""),
+                    "saved search type",
+                )?;
+                let stype_value = i16::from(stype);
+                Ok(stype_value.into())
+            }
         }
     }
 }
@@ -0,0 +1,54 @@
+use uuid::Uuid;
+
+use crate::entities::{GameWithPosition, Series};
+use crate::models::{GameInSeriesDTO, GameStatus, SeriesDTO};
+
+impl From<Series> for SeriesDTO {
+    fn from(series: Series) -> Self {
+        Self {
+            id: series.id.to_string(),
+            name: series.name,
+            description: series.description,
+            image_filename: series.image_filename,
+            image_url: None,
+            added_datetime: series.added_datetime,
+            updated_datetime: series.updated_datetime,
+        }
+    }
+}
+
+impl From<SeriesDTO> for Series {
+    fn from(series: SeriesDTO) -> Self {
+        Self {
+            id: Uuid::default(),
+            user_id: Uuid::default(),
+            name: series.name,
+            description: series.description,
+            image_filename: series.image_filename,
+            added_datetime: series.added_datetime,
+            updated_datetime: series.updated_datetime,
+        }
+    }
+}
+
+impl From<GameWithPosition> for GameInSeriesDTO {
+    fn from(game: GameWithPosition) -> Self {
+        Self {
+            id: game.id.to_string(),
+            name: game.name,
+            edition: game.edition,
+            release_year: game.release_year,
+            cover_filename: game.cover_filename,
+            cover_url: None,
+            added_datetime: game.added_datetime,
+            updated_datetime: game.updated_datetime,
+            status: GameStatus::try_from(game.status).expect("Status was not within valid range"),
+            rating: game.rating,
+            notes: game.notes,
+            save_folder: game.save_folder,
+            screenshot_folder: game.screenshot_folder,
+            backup: game.backup,
+            position: game.position,
+        }
+    }
+}
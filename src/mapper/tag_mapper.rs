@@ -1,13 +1,44 @@
 use uuid::Uuid;
 
-use crate::entities::Tag;
-use crate::models::TagDTO;
+use crate::entities::{Tag, TagWithDLCId, TagWithGameId};
+use crate::models::{TagDTO, TagTreeDTO};
 
 impl From<Tag> for TagDTO {
     fn from(tag: Tag) -> Self {
         Self {
             id: tag.id.to_string(),
             name: tag.name,
+            parent_id: tag.parent_id.map(|id| id.to_string()),
+            color: tag.color,
+            icon: tag.icon,
+            added_datetime: tag.added_datetime,
+            updated_datetime: tag.updated_datetime,
+        }
+    }
+}
+
+impl From<TagWithGameId> for TagDTO {
+    fn from(tag: TagWithGameId) -> Self {
+        Self {
+            id: tag.id.to_string(),
+            name: tag.name,
+            parent_id: tag.parent_id.map(|id| id.to_string()),
+            color: tag.color,
+            icon: tag.icon,
+            added_datetime: tag.added_datetime,
+            updated_datetime: tag.updated_datetime,
+        }
+    }
+}
+
+impl From<TagWithDLCId> for TagDTO {
+    fn from(tag: TagWithDLCId) -> Self {
+        Self {
+            id: tag.id.to_string(),
+            name: tag.name,
+            parent_id: tag.parent_id.map(|id| id.to_string()),
+            color: tag.color,
+            icon: tag.icon,
             added_datetime: tag.added_datetime,
             updated_datetime: tag.updated_datetime,
         }
@@ -20,8 +51,23 @@ impl From<TagDTO> for Tag {
             id: Uuid::default(),
             user_id: Uuid::default(),
             name: tag.name,
+            parent_id: tag.parent_id.map(|id| crate::uuid_utils::parse_uuid(&id)),
+            color: tag.color,
+            icon: tag.icon,
             added_datetime: tag.added_datetime,
             updated_datetime: tag.updated_datetime,
         }
     }
 }
+
+impl From<TagDTO> for TagTreeDTO {
+    fn from(tag: TagDTO) -> Self {
+        Self {
+            id: tag.id,
+            name: tag.name,
+            color: tag.color,
+            icon: tag.icon,
+            children: vec![],
+        }
+    }
+}
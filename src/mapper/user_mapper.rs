@@ -9,6 +9,7 @@ impl From<User> for UserDTO {
             id: user.id.to_string(),
             username: user.username,
             admin: user.admin,
+            must_change_password: user.must_change_password,
             added_datetime: user.added_datetime,
             updated_datetime: user.updated_datetime,
         }
@@ -22,6 +23,7 @@ impl From<UserDTO> for User {
             username: user.username,
             password: String::default(),
             admin: user.admin,
+            must_change_password: user.must_change_password,
             added_datetime: user.added_datetime,
             updated_datetime: user.updated_datetime,
         }
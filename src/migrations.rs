@@ -22,7 +22,7 @@ pub async fn apply_migrations(pool: &PgPool) {
     match exists_admin {
         true => log::info!("Database admin present."),
         false => {
-            users_service::create_user(
+            users_service::create_user_with_temporary_password(
                 pool,
                 NewUserDTO {
                     username: String::from("admin"),
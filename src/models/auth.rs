@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+pub const PASSWORD_CHANGE_SCOPE: &str = "password_change";
+
 #[derive(Deserialize, ToSchema)]
 pub struct TokenRequest {
     pub grant_type: GrantType,
     pub username: Option<String>,
     pub password: Option<String>,
     pub refresh_token: Option<String>,
+    pub totp: Option<String>,
 }
 
 #[derive(Clone, Deserialize, ToSchema)]
@@ -23,6 +26,8 @@ pub struct TokenResponse {
     pub refresh_token: String,
     pub token_type: String,
     pub expires_in: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -41,6 +46,9 @@ pub struct UserClaims {
     pub jti: String,
     // Refresh id
     pub ati: Option<String>,
+    // Scope (only present on limited tokens)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scp: Option<String>,
 }
 
 impl UserClaims {
@@ -52,6 +60,12 @@ impl UserClaims {
         !self.is_access()
     }
 
+    pub fn is_password_change_only(&self) -> bool {
+        self.scp
+            .as_deref()
+            .is_some_and(|scope| scope == PASSWORD_CHANGE_SCOPE)
+    }
+
     pub fn sub_as_user_id(&self) -> String {
         self.sub.parse().unwrap()
     }
@@ -60,3 +74,9 @@ impl UserClaims {
 pub struct LoggedUser {
     pub id: String,
 }
+
+pub struct ClientIpConfig {
+    /// Header set by a trusted reverse proxy with the client IP (e.g. X-Forwarded-For).
+    /// When not set, the peer address of the connection is used.
+    pub trusted_header: Option<String>,
+}
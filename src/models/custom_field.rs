@@ -0,0 +1,59 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{Merge, ModelInfo};
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum CustomFieldType {
+    #[default]
+    Text,
+    Number,
+    Date,
+    Bool,
+    Enum,
+}
+
+#[derive(Default, Serialize, ToSchema)]
+pub struct CustomFieldDTO {
+    pub id: String,
+    pub name: String,
+    // Fix to use type reserved name
+    #[serde(rename = "type")]
+    pub ftype: CustomFieldType,
+    /// Values allowed on Enum fields
+    pub options: Vec<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub added_datetime: NaiveDateTime,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_datetime: NaiveDateTime,
+}
+
+impl Merge<NewCustomFieldDTO> for CustomFieldDTO {
+    fn merge(self, other: NewCustomFieldDTO) -> Self {
+        Self {
+            id: self.id,
+            name: other.name.unwrap_or(self.name),
+            ftype: other.ftype.unwrap_or(self.ftype),
+            options: other.options.unwrap_or(self.options),
+            added_datetime: self.added_datetime,
+            updated_datetime: self.updated_datetime,
+        }
+    }
+}
+
+impl ModelInfo for CustomFieldDTO {
+    const MODEL_NAME: &'static str = "Custom field";
+    const ID_FIELDS: &'static [&'static str] = &["id"];
+    const UNIQUE_FIELDS: &'static [&'static str] = &["name"];
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewCustomFieldDTO {
+    pub name: Option<String>,
+    /// Cannot be changed once created
+    // Fix to use type reserved name
+    #[serde(rename = "type")]
+    pub ftype: Option<CustomFieldType>,
+    pub options: Option<Vec<String>>,
+}
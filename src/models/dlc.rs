@@ -2,7 +2,7 @@ use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use super::{Merge, ModelInfo};
+use super::{GameStatus, Merge, ModelInfo, TagDTO};
 
 #[derive(Default, Serialize, ToSchema)]
 pub struct DLCDTO {
@@ -20,6 +20,11 @@ pub struct DLCDTO {
     pub added_datetime: NaiveDateTime,
     #[schema(value_type = String, format = DateTime)]
     pub updated_datetime: NaiveDateTime,
+    pub status: GameStatus,
+    pub rating: i32,
+    pub notes: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<TagDTO>>,
 }
 
 impl Merge<NewDLCDTO> for DLCDTO {
@@ -33,6 +38,10 @@ impl Merge<NewDLCDTO> for DLCDTO {
             cover_url: self.cover_url,
             added_datetime: self.added_datetime,
             updated_datetime: self.updated_datetime,
+            status: other.status.unwrap_or(self.status),
+            rating: other.rating.unwrap_or(self.rating),
+            notes: other.notes.unwrap_or(self.notes),
+            tags: self.tags,
         }
     }
 }
@@ -48,6 +57,9 @@ pub struct NewDLCDTO {
     pub name: Option<String>,
     pub base_game_id: Option<String>,
     pub release_year: Option<i32>,
+    pub status: Option<GameStatus>,
+    pub rating: Option<i32>,
+    pub notes: Option<String>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -66,6 +78,9 @@ pub struct DLCAvailableDTO {
     pub added_datetime: NaiveDateTime,
     #[schema(value_type = String, format = DateTime)]
     pub updated_datetime: NaiveDateTime,
+    pub status: GameStatus,
+    pub rating: i32,
+    pub notes: String,
     #[schema(value_type = String, format = Date)]
     pub available_date: NaiveDate,
 }
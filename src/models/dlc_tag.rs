@@ -0,0 +1,9 @@
+use super::ModelInfo;
+
+pub struct DLCTag();
+
+impl ModelInfo for DLCTag {
+    const MODEL_NAME: &'static str = "Relation of DLC and Tag";
+    const ID_FIELDS: &'static [&'static str] = &["dlc id", "tag id"];
+    const UNIQUE_FIELDS: &'static [&'static str] = DLCTag::ID_FIELDS;
+}
@@ -2,7 +2,7 @@ use chrono::{NaiveDate, NaiveDateTime};
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use super::{ModelInfo, DLCDTO};
+use super::{GameStatus, ModelInfo, DLCDTO};
 
 #[derive(Serialize, ToSchema)]
 pub struct DLCWithFinishDTO {
@@ -20,6 +20,9 @@ pub struct DLCWithFinishDTO {
     pub added_datetime: NaiveDateTime,
     #[schema(value_type = String, format = DateTime)]
     pub updated_datetime: NaiveDateTime,
+    pub status: GameStatus,
+    pub rating: i32,
+    pub notes: String,
     #[schema(value_type = String, format = Date)]
     pub finish_date: NaiveDate,
 }
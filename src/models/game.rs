@@ -1,8 +1,12 @@
+use std::collections::HashMap;
+
 use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use super::{GameStatus, Merge, ModelInfo};
+use super::{
+    DurationDef, FieldsInfo, GameStatus, Merge, ModelInfo, PlatformAvailableDTO, TagDTO, DLCDTO,
+};
 
 #[derive(Default, Serialize, ToSchema)]
 pub struct GameDTO {
@@ -25,6 +29,22 @@ pub struct GameDTO {
     pub save_folder: String,
     pub screenshot_folder: String,
     pub backup: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<TagDTO>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platforms: Option<Vec<PlatformAvailableDTO>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dlcs: Option<Vec<DLCDTO>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Vec<String>>)]
+    pub finishes: Option<Vec<NaiveDate>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    pub total_time: Option<DurationDef>,
+    /// Values by custom field name, always returned when getting a single game
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub custom_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 impl Merge<NewGameDTO> for GameDTO {
@@ -44,6 +64,12 @@ impl Merge<NewGameDTO> for GameDTO {
             save_folder: other.save_folder.unwrap_or(self.save_folder),
             screenshot_folder: other.screenshot_folder.unwrap_or(self.screenshot_folder),
             backup: other.backup.unwrap_or(self.backup),
+            tags: self.tags,
+            platforms: self.platforms,
+            dlcs: self.dlcs,
+            finishes: self.finishes,
+            total_time: self.total_time,
+            custom_fields: self.custom_fields,
         }
     }
 }
@@ -54,7 +80,26 @@ impl ModelInfo for GameDTO {
     const UNIQUE_FIELDS: &'static [&'static str] = &["name", "edition"];
 }
 
-#[derive(Deserialize, ToSchema)]
+impl FieldsInfo for GameDTO {
+    const FIELDS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "edition",
+        "release_year",
+        "cover_filename",
+        "cover_url",
+        "added_datetime",
+        "updated_datetime",
+        "status",
+        "rating",
+        "notes",
+        "save_folder",
+        "screenshot_folder",
+        "backup",
+    ];
+}
+
+#[derive(Clone, Deserialize, ToSchema)]
 pub struct NewGameDTO {
     pub name: Option<String>,
     pub edition: Option<String>,
@@ -65,6 +110,9 @@ pub struct NewGameDTO {
     pub save_folder: Option<String>,
     pub screenshot_folder: Option<String>,
     pub backup: Option<bool>,
+    /// Values by custom field name, null removes the value
+    #[schema(value_type = Option<Object>)]
+    pub custom_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -92,6 +140,26 @@ pub struct GameAvailableDTO {
     pub available_date: NaiveDate,
 }
 
+#[derive(Serialize, ToSchema)]
+pub struct GameExpiringDTO {
+    pub id: String,
+    pub name: String,
+    pub edition: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_year: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    pub status: GameStatus,
+    pub platform_id: String,
+    pub platform_name: String,
+    #[schema(value_type = String, format = Date)]
+    pub available_date: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub end_date: NaiveDate,
+}
+
 impl ModelInfo for GameAvailableDTO {
     const MODEL_NAME: &'static str = "Relation of Game and Platform";
     const ID_FIELDS: &'static [&'static str] = &["game id", "platform id"];
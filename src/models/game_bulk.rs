@@ -0,0 +1,52 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{NewGameDTO, SearchDTO};
+
+/// Games are selected by ids or, without ids, by every game matching the search
+#[derive(Deserialize, ToSchema)]
+pub struct GameBulkDTO {
+    pub ids: Option<Vec<String>>,
+    pub search: Option<SearchDTO>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct GameBulkUpdateDTO {
+    pub ids: Option<Vec<String>>,
+    pub search: Option<SearchDTO>,
+    /// Fields to change in every game, the rest are kept
+    pub game: NewGameDTO,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct GameBulkTagsDTO {
+    pub ids: Option<Vec<String>>,
+    pub search: Option<SearchDTO>,
+    pub tag_ids: Vec<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct GameBulkPlatformsDTO {
+    pub ids: Option<Vec<String>>,
+    pub search: Option<SearchDTO>,
+    pub platform_ids: Vec<String>,
+    /// Only used when linking, today by default
+    #[schema(value_type = Option<String>, format = Date)]
+    pub available_date: Option<NaiveDate>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GameBulkResultDTO {
+    pub succeeded: u64,
+    pub failed: u64,
+    pub results: Vec<GameBulkItemResultDTO>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GameBulkItemResultDTO {
+    pub id: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct GameMergeDTO {
+    /// How to resolve the user info (status, rating, notes...) of both games, Target by default
+    pub strategy: Option<GameMergeStrategy>,
+}
+
+#[derive(Clone, Default, Deserialize, ToSchema)]
+pub enum GameMergeStrategy {
+    /// Keep the user info of the game merged into
+    #[default]
+    Target,
+    /// Take the user info of the merged game
+    Source,
+    /// Keep the user info of the game merged into, filling the blanks from the merged game and keeping the highest rating
+    Combine,
+}
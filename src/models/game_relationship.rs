@@ -0,0 +1,69 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::{GameStatus, ModelInfo};
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum GameRelationshipType {
+    RemasterOf,
+    PortOf,
+    SequelOf,
+    CollectionContains,
+}
+
+pub struct GameRelationship();
+
+impl ModelInfo for GameRelationship {
+    const MODEL_NAME: &'static str = "Relationship of Games";
+    const ID_FIELDS: &'static [&'static str] = &["game id", "related game id"];
+    const UNIQUE_FIELDS: &'static [&'static str] = GameRelationship::ID_FIELDS;
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewGameRelationshipDTO {
+    // Fix to use type reserved name
+    #[serde(rename = "type")]
+    pub rtype: GameRelationshipType,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct GameRelationshipQuery {
+    /// Only return relationships of this type
+    #[serde(rename = "type")]
+    #[param(inline)]
+    pub rtype: Option<GameRelationshipType>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct RelatedGameDTO {
+    pub id: String,
+    pub name: String,
+    pub edition: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_year: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub added_datetime: NaiveDateTime,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_datetime: NaiveDateTime,
+    pub status: GameStatus,
+    pub rating: i32,
+    pub notes: String,
+    pub save_folder: String,
+    pub screenshot_folder: String,
+    pub backup: bool,
+    pub relationship_type: GameRelationshipType,
+    /// Whether the relationship goes from this game to the requested one,
+    /// e.g. this game is a remaster of the requested one
+    pub inverse: bool,
+}
+
+impl ModelInfo for RelatedGameDTO {
+    const MODEL_NAME: &'static str = GameRelationship::MODEL_NAME;
+    const ID_FIELDS: &'static [&'static str] = GameRelationship::ID_FIELDS;
+    const UNIQUE_FIELDS: &'static [&'static str] = GameRelationship::UNIQUE_FIELDS;
+}
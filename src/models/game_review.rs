@@ -4,7 +4,7 @@ use chrono::{NaiveDate, NaiveDateTime};
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use super::{DurationDef, GameLogDTO, GameStatus};
+use super::{DurationDef, GameLogDTO, GameStatus, GameStatusChangeDTO, DLCDTO};
 
 #[derive(Serialize, ToSchema)]
 pub struct GamesPlayedReviewDTO {
@@ -25,6 +25,8 @@ pub struct GamesPlayedReviewDTO {
     pub total_rated: i32,
     pub total_rated_by_rating: HashMap<i32, i32>,
     pub games: Vec<GamePlayedReviewDTO>,
+    /// DLCs of the played games finished or updated in the period
+    pub dlcs: Vec<DLCDTO>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -73,6 +75,9 @@ pub struct GamesFinishedReviewDTO {
     pub total_finished_grouped: HashMap<u32, i32>,
     pub total_finished_by_release_year: HashMap<i32, i32>,
     pub games: Vec<GameFinishedReviewDTO>,
+    pub total_dlcs_finished: i32,
+    pub total_dlcs_first_finished: i32,
+    pub dlcs: Vec<DLCFinishedReviewDTO>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -107,6 +112,65 @@ pub struct GameFinishedReviewDTO {
     pub finishes: Vec<NaiveDate>,
 }
 
+#[derive(Serialize, ToSchema)]
+pub struct DLCFinishedReviewDTO {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_game_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_year: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub added_datetime: NaiveDateTime,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_datetime: NaiveDateTime,
+    pub status: GameStatus,
+    pub rating: i32,
+    pub notes: String,
+    pub total_finished: i32,
+    pub first_finished: bool,
+    #[schema(value_type = String, format = Date)]
+    pub first_finish: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub last_finish: NaiveDate,
+    #[serde(skip)]
+    pub finishes: Vec<NaiveDate>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GamesStatusReviewDTO {
+    pub total_changes: i32,
+    pub total_first_played: i32,
+    /// Average days from being added to first set to playing, of the games first played
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_days_to_first_played: Option<f64>,
+    pub total_abandoned: i32,
+    pub games: Vec<GameStatusReviewDTO>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GameStatusReviewDTO {
+    pub id: String,
+    pub name: String,
+    pub edition: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub added_datetime: NaiveDateTime,
+    pub first_played: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_to_first_played: Option<i64>,
+    /// Stopped playing without reaching played
+    pub abandoned: bool,
+    pub changes: Vec<GameStatusChangeDTO>,
+}
+
 #[derive(Serialize, ToSchema)]
 pub struct GameStreakDTO {
     pub days: i64,
@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::{GameStatus, ModelInfo};
+
+#[derive(Serialize, ToSchema)]
+pub struct GameStatusChangeDTO {
+    pub status: GameStatus,
+    /// Missing when the game was added with the status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_status: Option<GameStatus>,
+    #[schema(value_type = String, format = DateTime)]
+    pub datetime: NaiveDateTime,
+}
+
+impl ModelInfo for GameStatusChangeDTO {
+    const MODEL_NAME: &'static str = "Game status change";
+    const ID_FIELDS: &'static [&'static str] = &["game id", "datetime"];
+    const UNIQUE_FIELDS: &'static [&'static str] = GameStatusChangeDTO::ID_FIELDS;
+}
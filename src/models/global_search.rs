@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, IntoParams)]
+pub struct GlobalSearchQuery {
+    pub q: Option<String>,
+    /// Maximum number of results of each type, 5 by default and 20 at most
+    pub limit: Option<u64>,
+}
+
+/// Results grouped by type, each group ordered by relevance
+#[derive(Serialize, ToSchema)]
+pub struct GlobalSearchDTO {
+    pub games: Vec<SearchResultDTO>,
+    pub dlcs: Vec<SearchResultDTO>,
+    pub platforms: Vec<SearchResultDTO>,
+    pub tags: Vec<SearchResultDTO>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchResultDTO {
+    // Fix to use type reserved name
+    #[serde(rename = "type")]
+    pub rtype: SearchResultType,
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub enum SearchResultType {
+    Game,
+    DLC,
+    Platform,
+    Tag,
+}
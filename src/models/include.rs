@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+pub struct FieldsQuery {
+    /// Comma-separated list of fields to return, id and included relations are always returned
+    pub fields: Option<String>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct GameIncludeQuery {
+    /// Comma-separated list of relations to embed: tags, platforms, dlcs, finishes, total_time, custom_fields.
+    /// Custom fields are always embedded when getting a single game.
+    pub include: Option<String>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct DLCIncludeQuery {
+    /// Comma-separated list of relations to embed: tags
+    pub include: Option<String>,
+}
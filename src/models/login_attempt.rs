@@ -0,0 +1,9 @@
+use super::ModelInfo;
+
+pub struct LoginAttempts();
+
+impl ModelInfo for LoginAttempts {
+    const MODEL_NAME: &'static str = "Login attempts";
+    const ID_FIELDS: &'static [&'static str] = &["source"];
+    const UNIQUE_FIELDS: &'static [&'static str] = LoginAttempts::ID_FIELDS;
+}
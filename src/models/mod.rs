@@ -1,53 +1,81 @@
 mod auth;
+mod custom_field;
 mod date;
 mod dlc;
 mod dlc_finish;
+mod dlc_tag;
 mod dlc_with_finish;
 mod duration;
 mod error;
 mod file_temp_path;
 mod game;
+mod game_bulk;
 mod game_finish;
 mod game_log;
+mod game_merge;
+mod game_relationship;
 mod game_review;
 mod game_status;
+mod game_status_history;
 mod game_tag;
 mod game_with_finish;
 mod game_with_logs;
+mod global_search;
 mod id_param;
 mod image;
+mod include;
+mod login_attempt;
 mod merge;
 mod model_name;
 mod pagination;
 mod platform;
 mod platform_type;
+mod purchase;
+mod registration;
+mod saved_search;
 mod search;
+mod series;
 mod tag;
+mod totp;
 mod user;
 
 pub use auth::*;
+pub use custom_field::*;
 pub use date::*;
 pub use dlc::*;
 pub use dlc_finish::*;
+pub use dlc_tag::*;
 pub use dlc_with_finish::*;
 pub use duration::*;
 pub use error::*;
 pub use file_temp_path::*;
 pub use game::*;
+pub use game_bulk::*;
 pub use game_finish::*;
 pub use game_log::*;
+pub use game_merge::*;
+pub use game_relationship::*;
 pub use game_review::*;
 pub use game_status::*;
+pub use game_status_history::*;
 pub use game_tag::*;
 pub use game_with_finish::*;
 pub use game_with_logs::*;
+pub use global_search::*;
 pub use id_param::*;
 pub use image::*;
+pub use include::*;
+pub use login_attempt::*;
 pub use merge::*;
 pub use model_name::*;
 pub use pagination::*;
 pub use platform::*;
 pub use platform_type::*;
+pub use purchase::*;
+pub use registration::*;
+pub use saved_search::*;
 pub use search::*;
+pub use series::*;
 pub use tag::*;
+pub use totp::*;
 pub use user::*;
@@ -8,3 +8,8 @@ pub trait ModelInfo {
     /// Obtain the unique fields
     const UNIQUE_FIELDS: &'static [&'static str];
 }
+
+pub trait FieldsInfo {
+    /// Obtain the fields that can be selected in the response
+    const FIELDS: &'static [&'static str];
+}
@@ -2,15 +2,16 @@ use serde::Serialize;
 use utoipa::ToSchema;
 
 use super::{
-    DLCWithFinishDTO, GameDTO, GameWithFinishDTO, GameWithLogDTO, ModelInfo, PlatformDTO, TagDTO,
-    UserDTO, DLCDTO,
+    CustomFieldDTO, DLCWithFinishDTO, GameDTO, GameWithFinishDTO, GameWithLogDTO, ModelInfo,
+    PlatformDTO, SavedSearchDTO, SeriesDTO, TagDTO, UserDTO, DLCDTO,
 };
 
 #[derive(Serialize, ToSchema)]
 #[aliases(DLCWithFinishPageResult = PageResultDTO<DLCWithFinishDTO>, GamePageResult = PageResultDTO<GameDTO>,
     GameWithFinishPageResult = PageResultDTO<GameWithFinishDTO>, GameWithLogPageResult = PageResultDTO<GameWithLogDTO>,
     PlatformPageResult = PageResultDTO<PlatformDTO>, TagPageResult = PageResultDTO<TagDTO>, UserPageResult = PageResultDTO<UserDTO>,
-    DLCPageResult = PageResultDTO<DLCDTO>)]
+    DLCPageResult = PageResultDTO<DLCDTO>, SavedSearchPageResult = PageResultDTO<SavedSearchDTO>,
+    SeriesPageResult = PageResultDTO<SeriesDTO>, CustomFieldPageResult = PageResultDTO<CustomFieldDTO>)]
 pub struct PageResultDTO<T>
 where
     T: ModelInfo,
@@ -18,4 +19,26 @@ where
     pub data: Vec<T>,
     pub page: u64,
     pub size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_next: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<FacetDTO>>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct FacetDTO {
+    pub field: String,
+    pub values: Vec<FacetValueDTO>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct FacetValueDTO {
+    pub value: Option<String>,
+    pub count: u64,
 }
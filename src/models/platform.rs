@@ -66,6 +66,18 @@ pub struct PlatformAvailableDTO {
     pub updated_datetime: NaiveDateTime,
     #[schema(value_type = String, format = Date)]
     pub available_date: NaiveDate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, format = Date)]
+    pub end_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// In cents
+    pub price: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<String>,
+    pub gift: bool,
+    pub subscription: bool,
 }
 
 impl ModelInfo for PlatformAvailableDTO {
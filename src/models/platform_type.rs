@@ -5,4 +5,6 @@ use utoipa::ToSchema;
 pub enum PlatformType {
     Physical,
     Digital,
+    /// Catalogue of a subscription service, availability may have an end date
+    Subscription,
 }
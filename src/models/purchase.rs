@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::DurationDef;
+
+#[derive(Deserialize, ToSchema)]
+pub struct AvailabilityDTO {
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    /// Only for subscription platforms, when the game leaves the catalogue
+    #[schema(value_type = Option<String>, format = Date)]
+    pub end_date: Option<NaiveDate>,
+    /// In cents, or the hundredth part of the currency unit
+    pub price: Option<i64>,
+    /// Currency code of the price (e.g. EUR), required with it
+    pub currency: Option<String>,
+    pub store: Option<String>,
+    pub gift: Option<bool>,
+    pub subscription: Option<bool>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct ExpiringQuery {
+    /// Days from today to look for availabilities ending, 30 by default
+    pub days: Option<u32>,
+}
+
+/// Amounts are in cents and grouped by currency, as they are never converted
+#[derive(Serialize, ToSchema)]
+pub struct SpendingReviewDTO {
+    pub total_purchases: i32,
+    pub total_gifts: i32,
+    pub total_subscriptions: i32,
+    pub total_spent: HashMap<String, i64>,
+    /// By year and month, as YYYY-MM
+    pub total_spent_by_month: HashMap<String, HashMap<String, i64>>,
+    pub total_spent_by_platform: HashMap<String, HashMap<String, i64>>,
+    pub total_spent_by_store: HashMap<String, HashMap<String, i64>>,
+    pub games: Vec<GameSpendingReviewDTO>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GameSpendingReviewDTO {
+    pub id: String,
+    pub name: String,
+    pub edition: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    /// In cents, including the DLCs of the game
+    pub total_spent: HashMap<String, i64>,
+    #[schema(value_type = String)]
+    pub total_time: DurationDef,
+    /// In cents, only when the game has been played
+    pub cost_per_hour: HashMap<String, i64>,
+}
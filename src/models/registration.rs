@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::ModelInfo;
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, ToSchema)]
+pub enum RegistrationMode {
+    #[default]
+    Closed,
+    Open,
+    Invitation,
+}
+
+impl FromStr for RegistrationMode {
+    type Err = ();
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode.to_lowercase().as_str() {
+            "closed" => Ok(RegistrationMode::Closed),
+            "open" => Ok(RegistrationMode::Open),
+            "invitation" => Ok(RegistrationMode::Invitation),
+            _ => Err(()),
+        }
+    }
+}
+
+pub struct RegistrationConfig {
+    pub mode: RegistrationMode,
+    pub invitation_expiry_days: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct RegistrationInfoDTO {
+    pub mode: RegistrationMode,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct RegistrationDTO {
+    pub username: String,
+    pub password: String,
+    pub invitation_code: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct InvitationDTO {
+    pub id: String,
+    pub code: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub expiry_datetime: NaiveDateTime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub used_by_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub used_datetime: Option<NaiveDateTime>,
+    #[schema(value_type = String, format = DateTime)]
+    pub added_datetime: NaiveDateTime,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_datetime: NaiveDateTime,
+}
+
+impl ModelInfo for InvitationDTO {
+    const MODEL_NAME: &'static str = "Invitation";
+    const ID_FIELDS: &'static [&'static str] = &["id"];
+    const UNIQUE_FIELDS: &'static [&'static str] = &["code"];
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewInvitationDTO {
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub expiry_datetime: Option<NaiveDateTime>,
+}
@@ -0,0 +1,62 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::{Merge, ModelInfo, SearchDTO};
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum SavedSearchType {
+    #[default]
+    Game,
+    DLC,
+    Platform,
+    Tag,
+}
+
+#[derive(Default, Serialize, ToSchema)]
+pub struct SavedSearchDTO {
+    pub id: String,
+    pub name: String,
+    // Fix to use type reserved name
+    #[serde(rename = "type")]
+    pub stype: SavedSearchType,
+    pub search: SearchDTO,
+    #[schema(value_type = String, format = DateTime)]
+    pub added_datetime: NaiveDateTime,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_datetime: NaiveDateTime,
+}
+
+impl Merge<NewSavedSearchDTO> for SavedSearchDTO {
+    fn merge(self, other: NewSavedSearchDTO) -> Self {
+        Self {
+            id: self.id,
+            name: other.name.unwrap_or(self.name),
+            stype: other.stype.unwrap_or(self.stype),
+            search: other.search.unwrap_or(self.search),
+            added_datetime: self.added_datetime,
+            updated_datetime: self.updated_datetime,
+        }
+    }
+}
+
+impl ModelInfo for SavedSearchDTO {
+    const MODEL_NAME: &'static str = "Saved search";
+    const ID_FIELDS: &'static [&'static str] = &["id"];
+    const UNIQUE_FIELDS: &'static [&'static str] = &["name"];
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewSavedSearchDTO {
+    pub name: Option<String>,
+    // Fix to use type reserved name
+    #[serde(rename = "type")]
+    pub stype: Option<SavedSearchType>,
+    pub search: Option<SearchDTO>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct SavedSearchQuery {
+    /// Saved search to execute, paging in the body overrides the saved one
+    pub saved_search: Option<String>,
+}
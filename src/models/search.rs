@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
 #[derive(Deserialize, IntoParams)]
@@ -6,30 +6,67 @@ pub struct QuicksearchQuery {
     pub q: Option<String>,
 }
 
-#[derive(Deserialize, ToSchema)]
+#[derive(Deserialize, IntoParams)]
+pub struct CountQuery {
+    /// Also count every item matching the filters (total_count, total_pages and has_next)
+    pub count: Option<bool>,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, ToSchema)]
 pub struct SearchDTO {
     pub filter: Option<Vec<FilterDTO>>,
+    pub filter_group: Option<FilterGroupDTO>,
     pub sort: Option<Vec<SortDTO>>,
     pub page: Option<u64>,
     pub size: Option<u64>,
+    /// Alias of the count query parameter, which takes precedence
+    pub count: Option<bool>,
+    /// Keyset pagination instead of page: empty to get the first page, then next_cursor from the previous one
+    pub cursor: Option<String>,
+    /// Also count the items matching the filters by each value of these fields (e.g. status, platform, tag)
+    pub facets: Option<Vec<String>>,
 }
 
-#[derive(Deserialize, ToSchema)]
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 pub struct FilterDTO {
     pub field: String,
-    pub value: SearchValue,
+    /// Not needed for IsNull and IsNotNull.
+    /// Dates also accept values relative to now, like now-30d or start_of_year.
+    pub value: Option<SearchValue>,
     pub operator: OperatorType,
     pub chain_operator: Option<ChainOperatorType>,
 }
 
-#[derive(Clone, Deserialize, ToSchema)]
+/// Filters (or nested groups) combined with the group operator.
+/// Not negates all of them combined with And.
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
+pub struct FilterGroupDTO {
+    pub operator: GroupOperatorType,
+    pub filters: Vec<FilterNodeDTO>,
+}
+
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum FilterNodeDTO {
+    Filter(FilterDTO),
+    Group(FilterGroupDTO),
+}
+
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
+pub enum GroupOperatorType {
+    And,
+    Or,
+    Not,
+}
+
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 #[serde(untagged)]
 pub enum SearchValue {
     Value(String),
     Values(Vec<String>),
 }
 
-#[derive(Clone, Deserialize, ToSchema)]
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 pub enum OperatorType {
     Eq,
     NotEq,
@@ -45,21 +82,24 @@ pub enum OperatorType {
     NotEndsWith,
     Contains,
     NotContains,
+    Similar,
+    IsNull,
+    IsNotNull,
 }
 
-#[derive(Clone, Deserialize, ToSchema)]
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 pub enum ChainOperatorType {
     And,
     Or,
 }
 
-#[derive(Deserialize, ToSchema)]
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 pub struct SortDTO {
     pub field: String,
     pub order: OrderType,
 }
 
-#[derive(Clone, Deserialize, ToSchema)]
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 pub enum OrderType {
     Asc,
     Desc,
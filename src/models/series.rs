@@ -0,0 +1,93 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{DurationDef, GameStatus, Merge, ModelInfo};
+
+#[derive(Default, Serialize, ToSchema)]
+pub struct SeriesDTO {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub added_datetime: NaiveDateTime,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_datetime: NaiveDateTime,
+}
+
+impl Merge<NewSeriesDTO> for SeriesDTO {
+    fn merge(self, other: NewSeriesDTO) -> Self {
+        Self {
+            id: self.id,
+            name: other.name.unwrap_or(self.name),
+            description: other.description.unwrap_or(self.description),
+            image_filename: self.image_filename,
+            image_url: self.image_url,
+            added_datetime: self.added_datetime,
+            updated_datetime: self.updated_datetime,
+        }
+    }
+}
+
+impl ModelInfo for SeriesDTO {
+    const MODEL_NAME: &'static str = "Series";
+    const ID_FIELDS: &'static [&'static str] = &["id"];
+    const UNIQUE_FIELDS: &'static [&'static str] = &["name"];
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewSeriesDTO {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GameInSeriesDTO {
+    pub id: String,
+    pub name: String,
+    pub edition: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_year: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub added_datetime: NaiveDateTime,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_datetime: NaiveDateTime,
+    pub status: GameStatus,
+    pub rating: i32,
+    pub notes: String,
+    pub save_folder: String,
+    pub screenshot_folder: String,
+    pub backup: bool,
+    pub position: i32,
+}
+
+impl ModelInfo for GameInSeriesDTO {
+    const MODEL_NAME: &'static str = "Relation of Game and Series";
+    const ID_FIELDS: &'static [&'static str] = &["game id", "series id"];
+    const UNIQUE_FIELDS: &'static [&'static str] = GameInSeriesDTO::ID_FIELDS;
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct GameSeriesPositionDTO {
+    /// Position of the game in the series, appended at the end when missing
+    pub position: Option<i32>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SeriesProgressDTO {
+    pub total_games: u64,
+    pub finished_games: u64,
+    #[schema(value_type = String)]
+    pub total_time: DurationDef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, format = Date)]
+    pub last_finish_date: Option<NaiveDate>,
+}
@@ -8,6 +8,13 @@ use super::{Merge, ModelInfo};
 pub struct TagDTO {
     pub id: String,
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Hex color, as #RRGGBB
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
     #[schema(value_type = String, format = DateTime)]
     pub added_datetime: NaiveDateTime,
     #[schema(value_type = String, format = DateTime)]
@@ -19,6 +26,9 @@ impl Merge<NewTagDTO> for TagDTO {
         Self {
             id: self.id,
             name: other.name.unwrap_or(self.name),
+            parent_id: other.parent_id,
+            color: other.color,
+            icon: other.icon,
             added_datetime: self.added_datetime,
             updated_datetime: self.updated_datetime,
         }
@@ -34,4 +44,19 @@ impl ModelInfo for TagDTO {
 #[derive(Deserialize, ToSchema)]
 pub struct NewTagDTO {
     pub name: Option<String>,
+    pub parent_id: Option<String>,
+    /// Hex color, as #RRGGBB
+    pub color: Option<String>,
+    pub icon: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct TagTreeDTO {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    pub children: Vec<TagTreeDTO>,
 }
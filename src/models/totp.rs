@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::ModelInfo;
+
+#[derive(Serialize, ToSchema)]
+pub struct TotpEnrollmentDTO {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+impl ModelInfo for TotpEnrollmentDTO {
+    const MODEL_NAME: &'static str = "Two-factor authentication";
+    const ID_FIELDS: &'static [&'static str] = &["user id"];
+    const UNIQUE_FIELDS: &'static [&'static str] = TotpEnrollmentDTO::ID_FIELDS;
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct TotpRecoveryCodesDTO {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct TotpCodeDTO {
+    pub code: String,
+}
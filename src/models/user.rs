@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
@@ -9,6 +11,7 @@ pub struct UserDTO {
     pub id: String,
     pub username: String,
     pub admin: bool,
+    pub must_change_password: bool,
     #[schema(value_type = String, format = DateTime)]
     pub added_datetime: NaiveDateTime,
     #[schema(value_type = String, format = DateTime)]
@@ -21,6 +24,7 @@ impl Merge<NewUserDTO> for UserDTO {
             id: self.id,
             username: other.username,
             admin: self.admin,
+            must_change_password: self.must_change_password,
             added_datetime: self.added_datetime,
             updated_datetime: self.updated_datetime,
         }
@@ -43,6 +47,12 @@ pub struct PasswordQuery {
     pub password: String,
 }
 
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub reject_common: bool,
+    pub common_passwords: HashSet<String>,
+}
+
 #[derive(Deserialize, ToSchema)]
 pub struct PasswordChangeDTO {
     pub current_password: String,
@@ -13,17 +13,31 @@ pub fn get_openapi() -> utoipa::openapi::OpenApi {
             routes::get_game,
             routes::get_tag_games,
             routes::get_platform_games,
+            routes::get_expiring_games,
+            routes::get_game_relationships,
             routes::get_games,
             routes::post_game,
             routes::post_game_cover,
+            routes::merge_games,
             routes::put_game,
             routes::put_game_cover,
+            routes::put_game_platform_availability,
             routes::link_game_tag,
             routes::link_game_platform,
+            routes::link_game_series,
+            routes::link_game_relationship,
             routes::delete_game,
             routes::delete_game_cover,
             routes::unlink_game_tag,
             routes::unlink_game_platform,
+            routes::unlink_game_series,
+            routes::unlink_game_relationship,
+            routes::put_games_bulk,
+            routes::link_games_bulk_tags,
+            routes::link_games_bulk_platforms,
+            routes::delete_games_bulk,
+            routes::unlink_games_bulk_tags,
+            routes::unlink_games_bulk_platforms,
             // Game Finish
             routes::get_game_finishes,
             routes::get_first_game_finish,
@@ -41,21 +55,28 @@ pub fn get_openapi() -> utoipa::openapi::OpenApi {
             routes::get_last_played_games,
             routes::post_game_log,
             routes::delete_game_log,
+            // Game Status History
+            routes::get_game_status_history,
+            routes::get_status_games_review,
             // DLCs
             routes::get_dlc,
             routes::get_dlc_base_game,
             routes::get_game_dlcs,
+            routes::get_tag_dlcs,
             routes::get_platform_dlcs,
             routes::get_dlcs,
             routes::post_dlc,
             routes::post_dlc_cover,
             routes::put_dlc,
             routes::put_dlc_cover,
+            routes::put_dlc_platform_availability,
             routes::link_dlc_game,
+            routes::link_dlc_tag,
             routes::link_dlc_platform,
             routes::delete_dlc,
             routes::delete_dlc_cover,
             routes::unlink_dlc_game,
+            routes::unlink_dlc_tag,
             routes::unlink_dlc_platform,
             // DLC Finish
             routes::get_dlc_finishes,
@@ -76,12 +97,42 @@ pub fn get_openapi() -> utoipa::openapi::OpenApi {
             routes::delete_platform,
             routes::delete_platform_icon,
             // Tags
+            routes::get_tag_tree,
             routes::get_tag,
             routes::get_game_tags,
+            routes::get_dlc_tags,
             routes::get_tags,
             routes::post_tag,
             routes::put_tag,
             routes::delete_tag,
+            // Series
+            routes::get_series,
+            routes::get_game_series,
+            routes::get_series_games,
+            routes::get_series_progress,
+            routes::get_series_list,
+            routes::post_series,
+            routes::post_series_image,
+            routes::put_series,
+            routes::put_series_image,
+            routes::delete_series,
+            routes::delete_series_image,
+            // Search
+            routes::get_global_search,
+            // Saved searches
+            routes::get_saved_search,
+            routes::get_saved_searches,
+            routes::post_saved_search,
+            routes::put_saved_search,
+            routes::delete_saved_search,
+            // Custom fields
+            routes::get_custom_field,
+            routes::get_custom_fields,
+            routes::post_custom_field,
+            routes::put_custom_field,
+            routes::delete_custom_field,
+            // Spending
+            routes::get_spending_review,
             // Users
             routes::get_user,
             routes::get_current_user,
@@ -89,11 +140,23 @@ pub fn get_openapi() -> utoipa::openapi::OpenApi {
             routes::post_user,
             routes::put_user,
             routes::change_password,
+            routes::enroll_totp,
+            routes::activate_totp,
+            routes::regenerate_totp_recovery_codes,
+            routes::disable_totp,
             routes::promote_user,
             routes::demote_user,
+            routes::unlock_user,
+            routes::reset_user_password,
             routes::delete_user,
+            // Invitations
+            routes::get_invitations,
+            routes::post_invitation,
+            routes::delete_invitation,
             // Authentication
             routes::token,
+            routes::get_registration_info,
+            routes::register,
             // Health check
             routes::health,
         ),
@@ -102,6 +165,18 @@ pub fn get_openapi() -> utoipa::openapi::OpenApi {
             models::GamePageResult,
             models::NewGameDTO,
             models::GameAvailableDTO,
+            models::GameExpiringDTO,
+            models::GameBulkDTO,
+            models::GameBulkUpdateDTO,
+            models::GameBulkTagsDTO,
+            models::GameBulkPlatformsDTO,
+            models::GameBulkResultDTO,
+            models::GameBulkItemResultDTO,
+            models::GameMergeDTO,
+            models::GameMergeStrategy,
+            models::GameRelationshipType,
+            models::NewGameRelationshipDTO,
+            models::RelatedGameDTO,
             models::GameWithFinishDTO,
             models::GameWithLogDTO,
             models::GameWithLogsDTO,
@@ -115,7 +190,11 @@ pub fn get_openapi() -> utoipa::openapi::OpenApi {
             models::GameStreakDTO,
             models::GameLogDTO,
             models::NewGameLogDTO,
+            models::GameStatusChangeDTO,
+            models::GamesStatusReviewDTO,
+            models::GameStatusReviewDTO,
             models::DLCDTO,
+            models::DLCFinishedReviewDTO,
             models::DLCPageResult,
             models::NewDLCDTO,
             models::DLCAvailableDTO,
@@ -124,13 +203,42 @@ pub fn get_openapi() -> utoipa::openapi::OpenApi {
             models::PlatformPageResult,
             models::NewPlatformDTO,
             models::PlatformAvailableDTO,
+            models::AvailabilityDTO,
             models::PlatformType,
             models::TagDTO,
             models::TagPageResult,
             models::NewTagDTO,
+            models::TagTreeDTO,
+            models::SeriesDTO,
+            models::SeriesPageResult,
+            models::NewSeriesDTO,
+            models::GameInSeriesDTO,
+            models::GameSeriesPositionDTO,
+            models::SeriesProgressDTO,
+            models::GlobalSearchDTO,
+            models::SearchResultDTO,
+            models::SearchResultType,
+            models::SavedSearchDTO,
+            models::SavedSearchPageResult,
+            models::NewSavedSearchDTO,
+            models::SavedSearchType,
+            models::CustomFieldDTO,
+            models::CustomFieldPageResult,
+            models::NewCustomFieldDTO,
+            models::CustomFieldType,
+            models::SpendingReviewDTO,
+            models::GameSpendingReviewDTO,
             models::UserDTO,
             models::NewUserDTO,
             models::PasswordChangeDTO,
+            models::TotpEnrollmentDTO,
+            models::TotpRecoveryCodesDTO,
+            models::TotpCodeDTO,
+            models::InvitationDTO,
+            models::NewInvitationDTO,
+            models::RegistrationDTO,
+            models::RegistrationInfoDTO,
+            models::RegistrationMode,
             models::TokenRequest,
             models::TokenResponse,
             models::GrantType,
@@ -140,11 +248,16 @@ pub fn get_openapi() -> utoipa::openapi::OpenApi {
 
             models::SearchDTO,
             models::FilterDTO,
+            models::FilterGroupDTO,
+            models::FilterNodeDTO,
+            models::GroupOperatorType,
             models::SearchValue,
             models::OperatorType,
             models::ChainOperatorType,
             models::SortDTO,
             models::OrderType,
+            models::FacetDTO,
+            models::FacetValueDTO,
 
             models::Image,
         )),
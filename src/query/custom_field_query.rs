@@ -0,0 +1,167 @@
+use sea_query::{Expr, Query, QueryStatementWriter, SelectStatement, SimpleExpr};
+
+use crate::entities::{CustomField, CustomFieldIden, CustomFieldSearch, SearchQuery};
+use crate::errors::SearchErrors;
+
+use super::search::apply_search;
+
+pub fn select_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id(&mut select, id);
+    add_fields(&mut select);
+
+    select
+}
+
+pub fn select_all_with_search(
+    user_id: &str,
+    search: CustomFieldSearch,
+) -> Result<SearchQuery, SearchErrors> {
+    let select = select_all_internal(user_id);
+
+    apply_search(select, search)
+}
+
+pub fn select_all(user_id: &str) -> impl QueryStatementWriter {
+    select_all_internal(user_id)
+}
+
+fn select_all_internal(user_id: &str) -> SelectStatement {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    add_fields(&mut select);
+
+    select
+}
+
+pub fn insert(user_id: &str, id: &str, custom_field: &CustomField) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(CustomFieldIden::Table)
+        .columns([
+            CustomFieldIden::UserId,
+            CustomFieldIden::Id,
+            CustomFieldIden::Name,
+            CustomFieldIden::Type,
+            CustomFieldIden::Options,
+            CustomFieldIden::AddedDateTime,
+            CustomFieldIden::UpdatedDateTime,
+        ])
+        .values_panic([
+            user_id.into(),
+            id.into(),
+            custom_field.name.clone().into(),
+            custom_field.ftype.into(),
+            custom_field.options.clone().into(),
+            crate::date_utils::now().into(),
+            crate::date_utils::now().into(),
+        ]);
+
+    insert
+}
+
+pub fn update_by_id(
+    user_id: &str,
+    id: &str,
+    custom_field: &CustomField,
+) -> impl QueryStatementWriter {
+    update_values_by_id(
+        user_id,
+        id,
+        vec![
+            (CustomFieldIden::Name, custom_field.name.clone().into()),
+            (
+                CustomFieldIden::Options,
+                custom_field.options.clone().into(),
+            ),
+        ],
+    )
+}
+
+fn update_values_by_id(
+    user_id: &str,
+    id: &str,
+    mut values: Vec<(CustomFieldIden, SimpleExpr)>,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    values.push((
+        CustomFieldIden::UpdatedDateTime,
+        crate::date_utils::now().into(),
+    ));
+    update
+        .table(CustomFieldIden::Table)
+        .values(values)
+        .and_where(Expr::col(CustomFieldIden::UserId).eq(user_id))
+        .and_where(Expr::col(CustomFieldIden::Id).eq(id));
+
+    update
+}
+
+pub fn delete_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(CustomFieldIden::Table)
+        .and_where(Expr::col(CustomFieldIden::UserId).eq(user_id))
+        .and_where(Expr::col(CustomFieldIden::Id).eq(id));
+
+    delete
+}
+
+pub fn exists_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id(&mut select, id);
+    add_id_field(&mut select);
+
+    select
+}
+
+pub fn exists_by_name(user_id: &str, name: &str) -> SelectStatement {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    add_id_field(&mut select);
+    select.and_where(Expr::col(CustomFieldIden::Name).eq(name));
+
+    select
+}
+
+pub fn exists_by_name_and_id_not(user_id: &str, name: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = exists_by_name(user_id, name);
+
+    select.and_where(Expr::col(CustomFieldIden::Id).ne(id));
+
+    select
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
+    select
+        .from(CustomFieldIden::Table)
+        .and_where(Expr::col((CustomFieldIden::Table, CustomFieldIden::UserId)).eq(user_id));
+}
+
+fn where_id(select: &mut SelectStatement, id: &str) {
+    select.and_where(Expr::col((CustomFieldIden::Table, CustomFieldIden::Id)).eq(id));
+}
+
+fn add_id_field(select: &mut SelectStatement) {
+    select.column((CustomFieldIden::Table, CustomFieldIden::Id));
+}
+
+fn add_fields(select: &mut SelectStatement) {
+    add_id_field(select);
+    select
+        .column((CustomFieldIden::Table, CustomFieldIden::UserId))
+        .column((CustomFieldIden::Table, CustomFieldIden::Name))
+        .column((CustomFieldIden::Table, CustomFieldIden::Type))
+        .column((CustomFieldIden::Table, CustomFieldIden::Options))
+        .column((CustomFieldIden::Table, CustomFieldIden::AddedDateTime))
+        .column((CustomFieldIden::Table, CustomFieldIden::UpdatedDateTime));
+}
@@ -1,9 +1,13 @@
 use chrono::NaiveDate;
 use sea_query::{Alias, Expr, Order, Query, QueryStatementWriter, SelectStatement};
 
-use crate::entities::{DLCAvailableIden, DLCIden, PlatformIden, QUERY_DATE_ALIAS};
+use crate::entities::{
+    Availability, DLCAvailableIden, DLCIden, GameIden, PlatformIden,
+    BASE_GAME_COVER_FILENAME_ALIAS, BASE_GAME_EDITION_ALIAS, BASE_GAME_NAME_ALIAS,
+    PLATFORM_NAME_ALIAS, QUERY_DATE_ALIAS,
+};
 
-use super::{dlc_query, platform_query};
+use super::{dlc_query, game_available_query, platform_query};
 
 pub fn select_all_dlcs_by_platform_id_order_by_added_date(
     user_id: &str,
@@ -31,11 +35,31 @@ pub fn select_all_platforms_by_dlc_id_order_by_added_date(
     select
 }
 
+pub fn select_all_purchases_by_added_date_gte_and_added_date_lte_order_by_added_date(
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> impl QueryStatementWriter {
+    let mut select = dlc_query::select_all(user_id);
+
+    join_dlc_available_and_platform(&mut select);
+    join_base_game(&mut select);
+    select
+        .and_where(
+            Expr::col((DLCAvailableIden::Table, DLCAvailableIden::AddedDate)).gte(start_date),
+        )
+        .and_where(Expr::col((DLCAvailableIden::Table, DLCAvailableIden::AddedDate)).lte(end_date));
+    add_fields(&mut select);
+    add_order_by_added_date(&mut select);
+
+    select
+}
+
 pub fn insert(
     user_id: &str,
     dlc_id: &str,
     platform_id: &str,
-    added_date: NaiveDate,
+    availability: &Availability,
 ) -> impl QueryStatementWriter {
     let mut insert = Query::insert();
 
@@ -46,17 +70,64 @@ pub fn insert(
             DLCAvailableIden::DLCId,
             DLCAvailableIden::PlatformId,
             DLCAvailableIden::AddedDate,
+            DLCAvailableIden::EndDate,
+            DLCAvailableIden::Price,
+            DLCAvailableIden::Currency,
+            DLCAvailableIden::Store,
+            DLCAvailableIden::Gift,
+            DLCAvailableIden::Subscription,
         ])
         .values_panic([
             user_id.into(),
             dlc_id.into(),
             platform_id.into(),
-            added_date.into(),
+            availability.added_date.into(),
+            availability.end_date.into(),
+            game_available_query::price_from_cents(availability.price),
+            availability.currency.clone().into(),
+            availability.store.clone().into(),
+            availability.gift.into(),
+            availability.subscription.into(),
         ]);
 
     insert
 }
 
+pub fn update_by_id(
+    user_id: &str,
+    dlc_id: &str,
+    platform_id: &str,
+    availability: &Availability,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    update
+        .table(DLCAvailableIden::Table)
+        .values([
+            (DLCAvailableIden::AddedDate, availability.added_date.into()),
+            (DLCAvailableIden::EndDate, availability.end_date.into()),
+            (
+                DLCAvailableIden::Price,
+                game_available_query::price_from_cents(availability.price),
+            ),
+            (
+                DLCAvailableIden::Currency,
+                availability.currency.clone().into(),
+            ),
+            (DLCAvailableIden::Store, availability.store.clone().into()),
+            (DLCAvailableIden::Gift, availability.gift.into()),
+            (
+                DLCAvailableIden::Subscription,
+                availability.subscription.into(),
+            ),
+        ])
+        .and_where(Expr::col(DLCAvailableIden::UserId).eq(user_id))
+        .and_where(Expr::col(DLCAvailableIden::DLCId).eq(dlc_id))
+        .and_where(Expr::col(DLCAvailableIden::PlatformId).eq(platform_id));
+
+    update
+}
+
 pub fn delete_by_id(user_id: &str, dlc_id: &str, platform_id: &str) -> impl QueryStatementWriter {
     let mut delete = Query::delete();
 
@@ -97,6 +168,57 @@ fn join_dlc_available_by_platform_id(select: &mut SelectStatement, platform_id:
         );
 }
 
+fn join_dlc_available_and_platform(select: &mut SelectStatement) {
+    select
+        .inner_join(
+            DLCAvailableIden::Table,
+            Expr::col((DLCIden::Table, DLCIden::UserId))
+                .equals((DLCAvailableIden::Table, DLCAvailableIden::UserId))
+                .and(
+                    Expr::col((DLCIden::Table, DLCIden::Id))
+                        .equals((DLCAvailableIden::Table, DLCAvailableIden::DLCId)),
+                ),
+        )
+        .inner_join(
+            PlatformIden::Table,
+            Expr::col((DLCAvailableIden::Table, DLCAvailableIden::UserId))
+                .equals((PlatformIden::Table, PlatformIden::UserId))
+                .and(
+                    Expr::col((DLCAvailableIden::Table, DLCAvailableIden::PlatformId))
+                        .equals((PlatformIden::Table, PlatformIden::Id)),
+                ),
+        )
+        .expr_as(
+            Expr::col((PlatformIden::Table, PlatformIden::Name)),
+            Alias::new(PLATFORM_NAME_ALIAS),
+        );
+}
+
+fn join_base_game(select: &mut SelectStatement) {
+    select
+        .left_join(
+            GameIden::Table,
+            Expr::col((DLCIden::Table, DLCIden::UserId))
+                .equals((GameIden::Table, GameIden::UserId))
+                .and(
+                    Expr::col((DLCIden::Table, DLCIden::BaseGameId))
+                        .equals((GameIden::Table, GameIden::Id)),
+                ),
+        )
+        .expr_as(
+            Expr::col((GameIden::Table, GameIden::Name)),
+            Alias::new(BASE_GAME_NAME_ALIAS),
+        )
+        .expr_as(
+            Expr::col((GameIden::Table, GameIden::Edition)),
+            Alias::new(BASE_GAME_EDITION_ALIAS),
+        )
+        .expr_as(
+            Expr::col((GameIden::Table, GameIden::CoverFilename)),
+            Alias::new(BASE_GAME_COVER_FILENAME_ALIAS),
+        );
+}
+
 fn join_dlc_available_by_dlc_id(select: &mut SelectStatement, dlc_id: &str) {
     select
         .left_join(
@@ -118,10 +240,23 @@ fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
 }
 
 fn add_fields(select: &mut SelectStatement) {
-    select.expr_as(
-        Expr::col((DLCAvailableIden::Table, DLCAvailableIden::AddedDate)),
-        Alias::new(QUERY_DATE_ALIAS),
-    );
+    select
+        .expr_as(
+            Expr::col((DLCAvailableIden::Table, DLCAvailableIden::AddedDate)),
+            Alias::new(QUERY_DATE_ALIAS),
+        )
+        .column((DLCAvailableIden::Table, DLCAvailableIden::EndDate))
+        .expr_as(
+            game_available_query::price_to_cents((
+                DLCAvailableIden::Table,
+                DLCAvailableIden::Price,
+            )),
+            DLCAvailableIden::Price,
+        )
+        .column((DLCAvailableIden::Table, DLCAvailableIden::Currency))
+        .column((DLCAvailableIden::Table, DLCAvailableIden::Store))
+        .column((DLCAvailableIden::Table, DLCAvailableIden::Gift))
+        .column((DLCAvailableIden::Table, DLCAvailableIden::Subscription));
 }
 
 fn add_order_by_added_date(select: &mut SelectStatement) {
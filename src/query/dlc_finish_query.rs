@@ -4,7 +4,7 @@ use sea_query::{Alias, Expr, Order, Query, QueryStatementWriter, SelectStatement
 use crate::entities::{DLCFinishIden, DLCIden, DLCSearch, SearchQuery, QUERY_DATE_ALIAS};
 use crate::errors::SearchErrors;
 
-use super::{dlc_query, search::apply_search};
+use super::dlc_query;
 
 pub fn select_max_by_user_id_and_dlc_id(user_id: &str, dlc_id: &str) -> impl QueryStatementWriter {
     let mut select = Query::select();
@@ -24,6 +24,30 @@ pub fn select_all_by_user_id_and_dlc_id(user_id: &str, dlc_id: &str) -> SelectSt
     select
 }
 
+pub fn select_all_first_by_user_id_and_dlc_id_in(
+    user_id: &str,
+    dlc_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select.and_where(Expr::col((DLCFinishIden::Table, DLCFinishIden::DLCId)).is_in(dlc_ids));
+    select.column((DLCFinishIden::Table, DLCFinishIden::DLCId));
+    select.expr_as(
+        Expr::col((DLCFinishIden::Table, DLCFinishIden::Date)).min(),
+        DLCFinishIden::Date,
+    );
+    select.order_by_expr(
+        Expr::col((DLCFinishIden::Table, DLCFinishIden::Date)).min(),
+        Order::Asc,
+    );
+    select
+        .group_by_col((DLCFinishIden::Table, DLCFinishIden::UserId))
+        .group_by_col((DLCFinishIden::Table, DLCFinishIden::DLCId));
+
+    select
+}
+
 fn select_all_dlc_with_finish_by_date_gte_and_date_lte(
     user_id: &str,
     start_date: Option<NaiveDate>,
@@ -64,7 +88,7 @@ pub fn select_all_first_dlc_with_finish_with_search_by_date_gte_and_date_lte_ord
 
     // Ignore sort, might conflict with date ordering
     search.sort = None;
-    apply_search(select, search)
+    dlc_query::apply_search(select, search)
 }
 
 pub fn select_all_last_dlc_with_finish_with_search_by_date_gte_and_date_lte_order_by_date_desc(
@@ -87,7 +111,27 @@ pub fn select_all_last_dlc_with_finish_with_search_by_date_gte_and_date_lte_orde
 
     // Ignore sort, might conflict with date ordering
     search.sort = None;
-    apply_search(select, search)
+    dlc_query::apply_search(select, search)
+}
+
+pub fn select_all_dlcs_finish_by_date_gte_and_date_lte_order_by_date_desc(
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> impl QueryStatementWriter {
+    let mut select = dlc_query::select_all(user_id);
+
+    join_dlc_finish(&mut select);
+    select
+        .and_where(Expr::col((DLCFinishIden::Table, DLCFinishIden::Date)).gte(start_date))
+        .and_where(Expr::col((DLCFinishIden::Table, DLCFinishIden::Date)).lte(end_date))
+        .order_by((DLCFinishIden::Table, DLCFinishIden::Date), Order::Desc);
+    select.expr_as(
+        Expr::col((DLCFinishIden::Table, DLCFinishIden::Date)),
+        Alias::new(QUERY_DATE_ALIAS),
+    );
+
+    select
 }
 
 pub fn insert(user_id: &str, dlc_id: &str, date: NaiveDate) -> impl QueryStatementWriter {
@@ -141,10 +185,14 @@ fn join_dlc_finish(select: &mut SelectStatement) {
 }
 
 fn from_and_where_user_id_and_dlc_id(select: &mut SelectStatement, user_id: &str, dlc_id: &str) {
+    from_and_where_user_id(select, user_id);
+    select.and_where(Expr::col((DLCFinishIden::Table, DLCFinishIden::DLCId)).eq(dlc_id));
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
     select
         .from(DLCFinishIden::Table)
-        .and_where(Expr::col((DLCFinishIden::Table, DLCFinishIden::UserId)).eq(user_id))
-        .and_where(Expr::col((DLCFinishIden::Table, DLCFinishIden::DLCId)).eq(dlc_id));
+        .and_where(Expr::col((DLCFinishIden::Table, DLCFinishIden::UserId)).eq(user_id));
 }
 
 fn add_date_field(select: &mut SelectStatement) {
@@ -1,15 +1,20 @@
+use chrono::NaiveDateTime;
 use sea_query::{Expr, Query, QueryStatementWriter, SelectStatement, SimpleExpr};
 
-use crate::entities::{DLCIden, DLCSearch, SearchQuery, DLC};
+use crate::entities::{
+    DLCAvailableIden, DLCFinishIden, DLCIden, DLCRelationField, DLCSearch, DLCTagIden,
+    DLCUserInfoIden, RelationField, SearchQuery, TagIden, DLC,
+};
 use crate::errors::SearchErrors;
 
-use super::search::apply_search;
+use super::search::{apply_search_with_relations, FieldRelation};
 
 pub fn select_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
     let mut select = Query::select();
 
     from_and_where_user_id(&mut select, user_id);
     where_id(&mut select, id);
+    join_user_info(&mut select);
     add_fields(&mut select);
 
     select
@@ -19,12 +24,64 @@ pub fn select_all_by_base_game_id(user_id: &str, base_game_id: &str) -> impl Que
     let mut select = Query::select();
 
     from_and_where_user_id(&mut select, user_id);
+    join_user_info(&mut select);
     add_fields(&mut select);
     select.and_where(Expr::col(DLCIden::BaseGameId).eq(base_game_id));
 
     select
 }
 
+pub fn select_all_by_base_game_id_in(
+    user_id: &str,
+    base_game_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    join_user_info(&mut select);
+    add_fields(&mut select);
+    select.and_where(Expr::col(DLCIden::BaseGameId).is_in(base_game_ids));
+
+    select
+}
+
+pub fn select_all_by_base_game_id_in_with_activity_between(
+    user_id: &str,
+    base_game_ids: Vec<String>,
+    start_datetime: NaiveDateTime,
+    end_datetime: NaiveDateTime,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    join_user_info(&mut select);
+    add_fields(&mut select);
+    select
+        .and_where(Expr::col((DLCIden::Table, DLCIden::BaseGameId)).is_in(base_game_ids))
+        .and_where(
+            Expr::col((DLCIden::Table, DLCIden::Id))
+                .in_subquery(
+                    Query::select()
+                        .column((DLCFinishIden::Table, DLCFinishIden::DLCId))
+                        .from(DLCFinishIden::Table)
+                        .and_where(
+                            Expr::col((DLCFinishIden::Table, DLCFinishIden::UserId)).eq(user_id),
+                        )
+                        .and_where(
+                            Expr::col((DLCFinishIden::Table, DLCFinishIden::Date))
+                                .between(start_datetime.date(), end_datetime.date()),
+                        )
+                        .take(),
+                )
+                .or(
+                    Expr::col((DLCUserInfoIden::Table, DLCUserInfoIden::UpdatedDateTime))
+                        .between(start_datetime, end_datetime),
+                ),
+        );
+
+    select
+}
+
 pub fn select_all_with_search(
     user_id: &str,
     search: DLCSearch,
@@ -34,10 +91,97 @@ pub fn select_all_with_search(
     apply_search(select, search)
 }
 
+/// Applies a DLC search, translating fields computed from related rows into subqueries
+pub(super) fn apply_search(
+    select: SelectStatement,
+    search: DLCSearch,
+) -> Result<SearchQuery, SearchErrors> {
+    apply_search_with_relations(select, search, search_relation)
+}
+
+fn search_relation(relation: &RelationField) -> Option<FieldRelation> {
+    match relation {
+        RelationField::DLC(field) => Some(dlc_relation(*field)),
+        _ => None,
+    }
+}
+
+fn dlc_relation(field: DLCRelationField) -> FieldRelation {
+    match field {
+        DLCRelationField::Tag => FieldRelation::Any(
+            related_tags(false),
+            Expr::col((DLCTagIden::Table, DLCTagIden::TagId)).into(),
+        ),
+        DLCRelationField::TagName => FieldRelation::Any(
+            related_tags(true),
+            Expr::col((TagIden::Table, TagIden::Name)).into(),
+        ),
+        DLCRelationField::Platform => FieldRelation::Any(
+            related_platforms(),
+            Expr::col((DLCAvailableIden::Table, DLCAvailableIden::PlatformId)).into(),
+        ),
+        DLCRelationField::AvailableDate => FieldRelation::Any(
+            related_platforms(),
+            Expr::col((DLCAvailableIden::Table, DLCAvailableIden::AddedDate)).into(),
+        ),
+        DLCRelationField::AvailableEndDate => FieldRelation::Any(
+            related_platforms(),
+            Expr::col((DLCAvailableIden::Table, DLCAvailableIden::EndDate)).into(),
+        ),
+    }
+}
+
+/// Tags of the searched DLC, joined with their info when it is filtered by it
+fn related_tags(join_tag: bool) -> SelectStatement {
+    let mut select = Query::select();
+
+    select
+        .from(DLCTagIden::Table)
+        .and_where(
+            Expr::col((DLCTagIden::Table, DLCTagIden::UserId))
+                .equals((DLCIden::Table, DLCIden::UserId)),
+        )
+        .and_where(
+            Expr::col((DLCTagIden::Table, DLCTagIden::DLCId)).equals((DLCIden::Table, DLCIden::Id)),
+        );
+    if join_tag {
+        select.inner_join(
+            TagIden::Table,
+            Expr::col((DLCTagIden::Table, DLCTagIden::UserId))
+                .equals((TagIden::Table, TagIden::UserId))
+                .and(
+                    Expr::col((DLCTagIden::Table, DLCTagIden::TagId))
+                        .equals((TagIden::Table, TagIden::Id)),
+                ),
+        );
+    }
+
+    select
+}
+
+/// Platforms the searched DLC is available on
+fn related_platforms() -> SelectStatement {
+    let mut select = Query::select();
+
+    select
+        .from(DLCAvailableIden::Table)
+        .and_where(
+            Expr::col((DLCAvailableIden::Table, DLCAvailableIden::UserId))
+                .equals((DLCIden::Table, DLCIden::UserId)),
+        )
+        .and_where(
+            Expr::col((DLCAvailableIden::Table, DLCAvailableIden::DLCId))
+                .equals((DLCIden::Table, DLCIden::Id)),
+        );
+
+    select
+}
+
 pub(super) fn select_all(user_id: &str) -> SelectStatement {
     let mut select = Query::select();
 
     from_and_where_user_id(&mut select, user_id);
+    join_user_info(&mut select);
     add_fields(&mut select);
 
     select
@@ -46,7 +190,10 @@ pub(super) fn select_all(user_id: &str) -> SelectStatement {
 pub(super) fn select_all_group_by_id(user_id: &str) -> SelectStatement {
     let mut select = select_all(user_id);
 
-    select.group_by_col((DLCIden::Table, DLCIden::Id));
+    select
+        .group_by_col((DLCIden::Table, DLCIden::Id))
+        .group_by_col((DLCUserInfoIden::Table, DLCUserInfoIden::UserId))
+        .group_by_col((DLCUserInfoIden::Table, DLCUserInfoIden::DLCId));
 
     select
 }
@@ -80,6 +227,33 @@ pub fn insert(user_id: &str, id: &str, dlc: &DLC) -> impl QueryStatementWriter {
     insert
 }
 
+pub fn insert_user_info(user_id: &str, dlc_id: &str, dlc: &DLC) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(DLCUserInfoIden::Table)
+        .columns([
+            DLCUserInfoIden::UserId,
+            DLCUserInfoIden::DLCId,
+            DLCUserInfoIden::Status,
+            DLCUserInfoIden::Rating,
+            DLCUserInfoIden::Notes,
+            DLCUserInfoIden::AddedDateTime,
+            DLCUserInfoIden::UpdatedDateTime,
+        ])
+        .values_panic([
+            user_id.into(),
+            dlc_id.into(),
+            dlc.status.into(),
+            dlc.rating.into(),
+            dlc.notes.clone().into(),
+            crate::date_utils::now().into(),
+            crate::date_utils::now().into(),
+        ]);
+
+    insert
+}
+
 pub fn update_by_id(user_id: &str, id: &str, dlc: &DLC) -> impl QueryStatementWriter {
     update_values_by_id(
         user_id,
@@ -108,6 +282,25 @@ pub fn update_base_game_id_by_id(
     )
 }
 
+pub fn update_base_game_id_by_base_game_id(
+    user_id: &str,
+    base_game_id: &str,
+    new_base_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    update
+        .table(DLCIden::Table)
+        .values([
+            (DLCIden::BaseGameId, new_base_game_id.into()),
+            (DLCIden::UpdatedDateTime, crate::date_utils::now().into()),
+        ])
+        .and_where(Expr::col(DLCIden::UserId).eq(user_id))
+        .and_where(Expr::col(DLCIden::BaseGameId).eq(base_game_id));
+
+    update
+}
+
 pub fn update_cover_filename_by_id(
     user_id: &str,
     id: &str,
@@ -137,6 +330,26 @@ fn update_values_by_id(
     update
 }
 
+pub fn update_user_info_by_id(user_id: &str, dlc_id: &str, dlc: &DLC) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    update
+        .table(DLCUserInfoIden::Table)
+        .values([
+            (DLCUserInfoIden::Status, dlc.status.into()),
+            (DLCUserInfoIden::Rating, dlc.rating.into()),
+            (DLCUserInfoIden::Notes, dlc.notes.clone().into()),
+            (
+                DLCUserInfoIden::UpdatedDateTime,
+                crate::date_utils::now().into(),
+            ),
+        ])
+        .and_where(Expr::col(DLCUserInfoIden::UserId).eq(user_id))
+        .and_where(Expr::col(DLCUserInfoIden::DLCId).eq(dlc_id));
+
+    update
+}
+
 pub fn delete_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
     let mut delete = Query::delete();
 
@@ -186,6 +399,18 @@ fn where_id(select: &mut SelectStatement, id: &str) {
     select.and_where(Expr::col((DLCIden::Table, DLCIden::Id)).eq(id));
 }
 
+fn join_user_info(select: &mut SelectStatement) {
+    select.left_join(
+        DLCUserInfoIden::Table,
+        Expr::col((DLCIden::Table, DLCIden::UserId))
+            .equals((DLCUserInfoIden::Table, DLCUserInfoIden::UserId))
+            .and(
+                Expr::col((DLCIden::Table, DLCIden::Id))
+                    .equals((DLCUserInfoIden::Table, DLCUserInfoIden::DLCId)),
+            ),
+    );
+}
+
 fn add_id_field(select: &mut SelectStatement) {
     select.column((DLCIden::Table, DLCIden::Id));
 }
@@ -199,5 +424,8 @@ fn add_fields(select: &mut SelectStatement) {
         .column((DLCIden::Table, DLCIden::ReleaseYear))
         .column((DLCIden::Table, DLCIden::CoverFilename))
         .column((DLCIden::Table, DLCIden::AddedDateTime))
-        .column((DLCIden::Table, DLCIden::UpdatedDateTime));
+        .column((DLCIden::Table, DLCIden::UpdatedDateTime))
+        .column((DLCUserInfoIden::Table, DLCUserInfoIden::Status))
+        .column((DLCUserInfoIden::Table, DLCUserInfoIden::Rating))
+        .column((DLCUserInfoIden::Table, DLCUserInfoIden::Notes));
 }
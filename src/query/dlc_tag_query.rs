@@ -0,0 +1,107 @@
+use sea_query::{Expr, Query, QueryStatementWriter, SelectStatement};
+
+use crate::entities::{DLCIden, DLCTagIden, TagIden};
+
+use super::{dlc_query, tag_query};
+
+pub fn select_all_dlcs_by_tag_id(user_id: &str, tag_id: &str) -> impl QueryStatementWriter {
+    let mut select = dlc_query::select_all(user_id);
+
+    join_dlc_tag_by_tag_id(&mut select, tag_id);
+
+    select
+}
+
+pub fn select_all_tags_by_dlc_id(user_id: &str, dlc_id: &str) -> impl QueryStatementWriter {
+    let mut select = tag_query::select_all(user_id);
+
+    join_dlc_tag_by_dlc_id(&mut select, dlc_id);
+
+    select
+}
+
+pub fn select_all_tags_by_dlc_id_in(
+    user_id: &str,
+    dlc_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = tag_query::select_all(user_id);
+
+    join_dlc_tag(&mut select);
+    select
+        .and_where(Expr::col((DLCTagIden::Table, DLCTagIden::DLCId)).is_in(dlc_ids))
+        .column((DLCTagIden::Table, DLCTagIden::DLCId));
+
+    select
+}
+
+pub fn insert(user_id: &str, dlc_id: &str, tag_id: &str) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(DLCTagIden::Table)
+        .columns([DLCTagIden::UserId, DLCTagIden::DLCId, DLCTagIden::TagId])
+        .values_panic([user_id.into(), dlc_id.into(), tag_id.into()]);
+
+    insert
+}
+
+pub fn delete_by_id(user_id: &str, dlc_id: &str, tag_id: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(DLCTagIden::Table)
+        .and_where(Expr::col(DLCTagIden::UserId).eq(user_id))
+        .and_where(Expr::col(DLCTagIden::DLCId).eq(dlc_id))
+        .and_where(Expr::col(DLCTagIden::TagId).eq(tag_id));
+
+    delete
+}
+
+pub fn exists_by_id(user_id: &str, dlc_id: &str, tag_id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .column((DLCTagIden::Table, DLCTagIden::UserId))
+        .and_where(Expr::col(DLCTagIden::DLCId).eq(dlc_id))
+        .and_where(Expr::col(DLCTagIden::TagId).eq(tag_id));
+
+    select
+}
+
+fn join_dlc_tag_by_tag_id(select: &mut SelectStatement, tag_id: &str) {
+    select
+        .left_join(
+            DLCTagIden::Table,
+            Expr::col((DLCIden::Table, DLCIden::UserId))
+                .equals((DLCTagIden::Table, DLCTagIden::UserId))
+                .and(
+                    Expr::col((DLCIden::Table, DLCIden::Id))
+                        .equals((DLCTagIden::Table, DLCTagIden::DLCId)),
+                ),
+        )
+        .and_where(Expr::col((DLCTagIden::Table, DLCTagIden::TagId)).eq(tag_id));
+}
+
+fn join_dlc_tag_by_dlc_id(select: &mut SelectStatement, dlc_id: &str) {
+    join_dlc_tag(select);
+    select.and_where(Expr::col((DLCTagIden::Table, DLCTagIden::DLCId)).eq(dlc_id));
+}
+
+fn join_dlc_tag(select: &mut SelectStatement) {
+    select.left_join(
+        DLCTagIden::Table,
+        Expr::col((TagIden::Table, TagIden::UserId))
+            .equals((DLCTagIden::Table, DLCTagIden::UserId))
+            .and(
+                Expr::col((TagIden::Table, TagIden::Id))
+                    .equals((DLCTagIden::Table, DLCTagIden::TagId)),
+            ),
+    );
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
+    select
+        .from(DLCTagIden::Table)
+        .and_where(Expr::col((DLCTagIden::Table, DLCTagIden::UserId)).eq(user_id));
+}
@@ -1,7 +1,12 @@
 use chrono::NaiveDate;
-use sea_query::{Alias, Expr, Order, Query, QueryStatementWriter, SelectStatement};
+use sea_query::{
+    Alias, Expr, Func, IntoColumnRef, Order, Query, QueryStatementWriter, SelectStatement,
+    SimpleExpr,
+};
 
-use crate::entities::{GameAvailableIden, GameIden, PlatformIden, QUERY_DATE_ALIAS};
+use crate::entities::{
+    Availability, GameAvailableIden, GameIden, PlatformIden, PLATFORM_NAME_ALIAS, QUERY_DATE_ALIAS,
+};
 
 use super::{game_query, platform_query};
 
@@ -31,11 +36,71 @@ pub fn select_all_platforms_by_game_id_order_by_added_date(
     select
 }
 
+pub fn select_all_platforms_by_game_id_in_order_by_added_date(
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = platform_query::select_all(user_id);
+
+    join_game_available(&mut select);
+    select
+        .and_where(Expr::col((GameAvailableIden::Table, GameAvailableIden::GameId)).is_in(game_ids))
+        .column((GameAvailableIden::Table, GameAvailableIden::GameId));
+    add_fields(&mut select);
+    add_order_by_added_date(&mut select);
+
+    select
+}
+
+pub fn select_all_purchases_by_added_date_gte_and_added_date_lte_order_by_added_date(
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> impl QueryStatementWriter {
+    let mut select = game_query::select_all(user_id);
+
+    join_game_available_and_platform(&mut select);
+    select
+        .and_where(
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::AddedDate)).gte(start_date),
+        )
+        .and_where(
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::AddedDate)).lte(end_date),
+        );
+    add_fields(&mut select);
+    add_order_by_added_date(&mut select);
+
+    select
+}
+
+pub fn select_all_games_by_end_date_gte_and_end_date_lte_order_by_end_date(
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> impl QueryStatementWriter {
+    let mut select = game_query::select_all(user_id);
+
+    join_game_available_and_platform(&mut select);
+    select
+        .column((GameAvailableIden::Table, GameAvailableIden::PlatformId))
+        .and_where(
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::EndDate)).gte(start_date),
+        )
+        .and_where(Expr::col((GameAvailableIden::Table, GameAvailableIden::EndDate)).lte(end_date))
+        .order_by(
+            (GameAvailableIden::Table, GameAvailableIden::EndDate),
+            Order::Asc,
+        );
+    add_fields(&mut select);
+
+    select
+}
+
 pub fn insert(
     user_id: &str,
     game_id: &str,
     platform_id: &str,
-    added_date: NaiveDate,
+    availability: &Availability,
 ) -> impl QueryStatementWriter {
     let mut insert = Query::insert();
 
@@ -46,17 +111,93 @@ pub fn insert(
             GameAvailableIden::GameId,
             GameAvailableIden::PlatformId,
             GameAvailableIden::AddedDate,
+            GameAvailableIden::EndDate,
+            GameAvailableIden::Price,
+            GameAvailableIden::Currency,
+            GameAvailableIden::Store,
+            GameAvailableIden::Gift,
+            GameAvailableIden::Subscription,
         ])
         .values_panic([
             user_id.into(),
             game_id.into(),
             platform_id.into(),
-            added_date.into(),
+            availability.added_date.into(),
+            availability.end_date.into(),
+            price_from_cents(availability.price),
+            availability.currency.clone().into(),
+            availability.store.clone().into(),
+            availability.gift.into(),
+            availability.subscription.into(),
         ]);
 
     insert
 }
 
+pub fn update_game_id_by_game_id(
+    user_id: &str,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let existing_alias = Alias::new("ExistingGameAvailable");
+    let mut existing_select = Query::select();
+    existing_select
+        .expr(Expr::val(1))
+        .from_as(GameAvailableIden::Table, existing_alias.clone())
+        .and_where(Expr::col((existing_alias.clone(), GameAvailableIden::UserId)).eq(user_id))
+        .and_where(Expr::col((existing_alias.clone(), GameAvailableIden::GameId)).eq(new_game_id))
+        .and_where(
+            Expr::col((existing_alias, GameAvailableIden::PlatformId))
+                .equals((GameAvailableIden::Table, GameAvailableIden::PlatformId)),
+        );
+
+    update
+        .table(GameAvailableIden::Table)
+        .value(GameAvailableIden::GameId, new_game_id)
+        .and_where(Expr::col((GameAvailableIden::Table, GameAvailableIden::UserId)).eq(user_id))
+        .and_where(Expr::col((GameAvailableIden::Table, GameAvailableIden::GameId)).eq(game_id))
+        .and_where(Expr::exists(existing_select).not());
+
+    update
+}
+
+pub fn update_by_id(
+    user_id: &str,
+    game_id: &str,
+    platform_id: &str,
+    availability: &Availability,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    update
+        .table(GameAvailableIden::Table)
+        .values([
+            (GameAvailableIden::AddedDate, availability.added_date.into()),
+            (GameAvailableIden::EndDate, availability.end_date.into()),
+            (
+                GameAvailableIden::Price,
+                price_from_cents(availability.price),
+            ),
+            (
+                GameAvailableIden::Currency,
+                availability.currency.clone().into(),
+            ),
+            (GameAvailableIden::Store, availability.store.clone().into()),
+            (GameAvailableIden::Gift, availability.gift.into()),
+            (
+                GameAvailableIden::Subscription,
+                availability.subscription.into(),
+            ),
+        ])
+        .and_where(Expr::col(GameAvailableIden::UserId).eq(user_id))
+        .and_where(Expr::col(GameAvailableIden::GameId).eq(game_id))
+        .and_where(Expr::col(GameAvailableIden::PlatformId).eq(platform_id));
+
+    update
+}
+
 pub fn delete_by_id(user_id: &str, game_id: &str, platform_id: &str) -> impl QueryStatementWriter {
     let mut delete = Query::delete();
 
@@ -81,6 +222,24 @@ pub fn exists_by_id(user_id: &str, game_id: &str, platform_id: &str) -> impl Que
     select
 }
 
+/// Ids of the given games with some platform available
+pub fn select_all_game_ids_by_game_id_in(
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .distinct()
+        .column((GameAvailableIden::Table, GameAvailableIden::GameId))
+        .and_where(
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::GameId)).is_in(game_ids),
+        );
+
+    select
+}
+
 pub fn exists_platforms_by_game_id(user_id: &str, game_id: &str) -> impl QueryStatementWriter {
     let mut select = Query::select();
 
@@ -108,18 +267,47 @@ fn join_game_available_by_platform_id(select: &mut SelectStatement, platform_id:
         );
 }
 
-fn join_game_available_by_game_id(select: &mut SelectStatement, game_id: &str) {
+fn join_game_available_and_platform(select: &mut SelectStatement) {
     select
-        .left_join(
+        .inner_join(
             GameAvailableIden::Table,
-            Expr::col((PlatformIden::Table, PlatformIden::UserId))
+            Expr::col((GameIden::Table, GameIden::UserId))
                 .equals((GameAvailableIden::Table, GameAvailableIden::UserId))
                 .and(
-                    Expr::col((PlatformIden::Table, PlatformIden::Id))
-                        .equals((GameAvailableIden::Table, GameAvailableIden::PlatformId)),
+                    Expr::col((GameIden::Table, GameIden::Id))
+                        .equals((GameAvailableIden::Table, GameAvailableIden::GameId)),
+                ),
+        )
+        .inner_join(
+            PlatformIden::Table,
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::UserId))
+                .equals((PlatformIden::Table, PlatformIden::UserId))
+                .and(
+                    Expr::col((GameAvailableIden::Table, GameAvailableIden::PlatformId))
+                        .equals((PlatformIden::Table, PlatformIden::Id)),
                 ),
         )
-        .and_where(Expr::col((GameAvailableIden::Table, GameAvailableIden::GameId)).eq(game_id));
+        .expr_as(
+            Expr::col((PlatformIden::Table, PlatformIden::Name)),
+            Alias::new(PLATFORM_NAME_ALIAS),
+        );
+}
+
+fn join_game_available_by_game_id(select: &mut SelectStatement, game_id: &str) {
+    join_game_available(select);
+    select.and_where(Expr::col((GameAvailableIden::Table, GameAvailableIden::GameId)).eq(game_id));
+}
+
+fn join_game_available(select: &mut SelectStatement) {
+    select.left_join(
+        GameAvailableIden::Table,
+        Expr::col((PlatformIden::Table, PlatformIden::UserId))
+            .equals((GameAvailableIden::Table, GameAvailableIden::UserId))
+            .and(
+                Expr::col((PlatformIden::Table, PlatformIden::Id))
+                    .equals((GameAvailableIden::Table, GameAvailableIden::PlatformId)),
+            ),
+    );
 }
 
 fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
@@ -128,11 +316,30 @@ fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
         .and_where(Expr::col((GameAvailableIden::Table, GameAvailableIden::UserId)).eq(user_id));
 }
 
+/// Prices are stored with two decimals, but handled in cents
+pub fn price_from_cents(price: Option<i64>) -> SimpleExpr {
+    Expr::expr(Func::cast_as(price, Alias::new("numeric"))).div(100)
+}
+
+pub fn price_to_cents<T: IntoColumnRef>(column: T) -> SimpleExpr {
+    Func::cast_as(Expr::col(column).mul(100), Alias::new("bigint")).into()
+}
+
 fn add_fields(select: &mut SelectStatement) {
-    select.expr_as(
-        Expr::col((GameAvailableIden::Table, GameAvailableIden::AddedDate)),
-        Alias::new(QUERY_DATE_ALIAS),
-    );
+    select
+        .expr_as(
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::AddedDate)),
+            Alias::new(QUERY_DATE_ALIAS),
+        )
+        .column((GameAvailableIden::Table, GameAvailableIden::EndDate))
+        .expr_as(
+            price_to_cents((GameAvailableIden::Table, GameAvailableIden::Price)),
+            GameAvailableIden::Price,
+        )
+        .column((GameAvailableIden::Table, GameAvailableIden::Currency))
+        .column((GameAvailableIden::Table, GameAvailableIden::Store))
+        .column((GameAvailableIden::Table, GameAvailableIden::Gift))
+        .column((GameAvailableIden::Table, GameAvailableIden::Subscription));
 }
 
 fn add_order_by_added_date(select: &mut SelectStatement) {
@@ -0,0 +1,104 @@
+use sea_query::{Alias, Expr, Query, QueryStatementWriter};
+
+use crate::entities::{CustomFieldIden, GameCustomValue, GameCustomValueIden};
+
+pub fn select_all_by_game_id_in(user_id: &str, game_ids: Vec<String>) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    select
+        .from(GameCustomValueIden::Table)
+        .inner_join(
+            CustomFieldIden::Table,
+            Expr::col((CustomFieldIden::Table, CustomFieldIden::UserId))
+                .equals((GameCustomValueIden::Table, GameCustomValueIden::UserId))
+                .and(
+                    Expr::col((CustomFieldIden::Table, CustomFieldIden::Id))
+                        .equals((GameCustomValueIden::Table, GameCustomValueIden::FieldId)),
+                ),
+        )
+        .and_where(Expr::col((GameCustomValueIden::Table, GameCustomValueIden::UserId)).eq(user_id))
+        .and_where(
+            Expr::col((GameCustomValueIden::Table, GameCustomValueIden::GameId)).is_in(game_ids),
+        )
+        .column((GameCustomValueIden::Table, GameCustomValueIden::GameId))
+        .column((GameCustomValueIden::Table, GameCustomValueIden::FieldId))
+        .column((CustomFieldIden::Table, CustomFieldIden::Name))
+        .column((GameCustomValueIden::Table, GameCustomValueIden::TextValue))
+        .column((GameCustomValueIden::Table, GameCustomValueIden::NumberValue))
+        .column((GameCustomValueIden::Table, GameCustomValueIden::DateValue))
+        .column((GameCustomValueIden::Table, GameCustomValueIden::BoolValue));
+
+    select
+}
+
+pub fn insert(
+    user_id: &str,
+    game_id: &str,
+    custom_value: &GameCustomValue,
+) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(GameCustomValueIden::Table)
+        .columns([
+            GameCustomValueIden::UserId,
+            GameCustomValueIden::GameId,
+            GameCustomValueIden::FieldId,
+            GameCustomValueIden::TextValue,
+            GameCustomValueIden::NumberValue,
+            GameCustomValueIden::DateValue,
+            GameCustomValueIden::BoolValue,
+        ])
+        .values_panic([
+            user_id.into(),
+            game_id.into(),
+            custom_value.field_id.clone().into(),
+            custom_value.text_value.clone().into(),
+            custom_value.number_value.into(),
+            custom_value.date_value.into(),
+            custom_value.bool_value.into(),
+        ]);
+
+    insert
+}
+
+pub fn update_game_id_by_game_id(
+    user_id: &str,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let existing_alias = Alias::new("ExistingGameCustomValue");
+    let mut existing_select = Query::select();
+    existing_select
+        .expr(Expr::val(1))
+        .from_as(GameCustomValueIden::Table, existing_alias.clone())
+        .and_where(Expr::col((existing_alias.clone(), GameCustomValueIden::UserId)).eq(user_id))
+        .and_where(Expr::col((existing_alias.clone(), GameCustomValueIden::GameId)).eq(new_game_id))
+        .and_where(
+            Expr::col((existing_alias, GameCustomValueIden::FieldId))
+                .equals((GameCustomValueIden::Table, GameCustomValueIden::FieldId)),
+        );
+
+    update
+        .table(GameCustomValueIden::Table)
+        .value(GameCustomValueIden::GameId, new_game_id)
+        .and_where(Expr::col((GameCustomValueIden::Table, GameCustomValueIden::UserId)).eq(user_id))
+        .and_where(Expr::col((GameCustomValueIden::Table, GameCustomValueIden::GameId)).eq(game_id))
+        .and_where(Expr::exists(existing_select).not());
+
+    update
+}
+
+pub fn delete_by_id(user_id: &str, game_id: &str, field_id: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(GameCustomValueIden::Table)
+        .and_where(Expr::col(GameCustomValueIden::UserId).eq(user_id))
+        .and_where(Expr::col(GameCustomValueIden::GameId).eq(game_id))
+        .and_where(Expr::col(GameCustomValueIden::FieldId).eq(field_id));
+
+    delete
+}
@@ -5,7 +5,6 @@ use crate::entities::{GameFinishIden, GameIden, GameSearch, SearchQuery, QUERY_D
 use crate::errors::SearchErrors;
 
 use super::game_query;
-use super::search::apply_search;
 
 pub fn select_min_date_by_user_id_and_game_id(
     user_id: &str,
@@ -28,6 +27,21 @@ pub fn select_all_by_user_id_and_game_id(user_id: &str, game_id: &str) -> Select
     select
 }
 
+pub fn select_all_by_user_id_and_game_id_in(
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select.and_where(Expr::col((GameFinishIden::Table, GameFinishIden::GameId)).is_in(game_ids));
+    select.column((GameFinishIden::Table, GameFinishIden::GameId));
+    add_date_field(&mut select);
+    select.order_by((GameFinishIden::Table, GameFinishIden::Date), Order::Asc);
+
+    select
+}
+
 pub fn select_all_first_by_user_id_and_game_id_in(
     user_id: &str,
     game_ids: Vec<String>,
@@ -85,7 +99,7 @@ pub fn select_all_first_game_with_finish_with_search_by_date_gte_and_date_lte_or
 
     // Ignore sort, might conflict with date ordering
     search.sort = None;
-    apply_search(select, search)
+    game_query::apply_search(select, search)
 }
 
 pub fn select_all_last_game_with_finish_with_search_by_date_gte_and_date_lte_order_by_date_desc(
@@ -108,7 +122,7 @@ pub fn select_all_last_game_with_finish_with_search_by_date_gte_and_date_lte_ord
 
     // Ignore sort, might conflict with date ordering
     search.sort = None;
-    apply_search(select, search)
+    game_query::apply_search(select, search)
 }
 
 pub fn select_all_games_order_by_date_desc(user_id: &str) -> SelectStatement {
@@ -163,6 +177,35 @@ pub fn insert(user_id: &str, game_id: &str, date: NaiveDate) -> impl QueryStatem
     insert
 }
 
+pub fn update_game_id_by_game_id(
+    user_id: &str,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let existing_alias = Alias::new("ExistingGameFinish");
+    let mut existing_select = Query::select();
+    existing_select
+        .expr(Expr::val(1))
+        .from_as(GameFinishIden::Table, existing_alias.clone())
+        .and_where(Expr::col((existing_alias.clone(), GameFinishIden::UserId)).eq(user_id))
+        .and_where(Expr::col((existing_alias.clone(), GameFinishIden::GameId)).eq(new_game_id))
+        .and_where(
+            Expr::col((existing_alias, GameFinishIden::Date))
+                .equals((GameFinishIden::Table, GameFinishIden::Date)),
+        );
+
+    update
+        .table(GameFinishIden::Table)
+        .value(GameFinishIden::GameId, new_game_id)
+        .and_where(Expr::col((GameFinishIden::Table, GameFinishIden::UserId)).eq(user_id))
+        .and_where(Expr::col((GameFinishIden::Table, GameFinishIden::GameId)).eq(game_id))
+        .and_where(Expr::exists(existing_select).not());
+
+    update
+}
+
 pub fn delete_by_id(user_id: &str, game_id: &str, date: NaiveDate) -> impl QueryStatementWriter {
     let mut delete = Query::delete();
 
@@ -11,7 +11,7 @@ use crate::entities::{
 use crate::errors::SearchErrors;
 
 use super::game_query;
-use super::search::apply_search;
+use super::search::scalar_subquery;
 
 pub fn select_sum_time_by_user_id_and_game_id(
     user_id: &str,
@@ -25,6 +25,23 @@ pub fn select_sum_time_by_user_id_and_game_id(
     select
 }
 
+pub fn select_sum_time_by_user_id_and_game_id_in(
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select.and_where(Expr::col((GameLogIden::Table, GameLogIden::GameId)).is_in(game_ids));
+    select.column((GameLogIden::Table, GameLogIden::GameId));
+    select.expr_as(coalesce_time_sum(), Alias::new(QUERY_TIME_ALIAS));
+    select
+        .group_by_col((GameLogIden::Table, GameLogIden::UserId))
+        .group_by_col((GameLogIden::Table, GameLogIden::GameId));
+
+    select
+}
+
 pub fn select_all_by_user_id_and_game_id(
     user_id: &str,
     game_id: &str,
@@ -68,6 +85,26 @@ pub fn select_all_first_by_user_id_and_game_id_in(
     select
 }
 
+/// Earliest session of each game
+pub fn select_all_first_sessions_by_game_id_in(
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .distinct_on([(GameLogIden::Table, GameLogIden::GameId)])
+        .and_where(Expr::col((GameLogIden::Table, GameLogIden::GameId)).is_in(game_ids))
+        .column((GameLogIden::Table, GameLogIden::GameId));
+    add_start_datetime_and_end_datetime_and_time_fields(&mut select);
+    select
+        .order_by((GameLogIden::Table, GameLogIden::GameId), Order::Asc)
+        .order_by((GameLogIden::Table, GameLogIden::StartDateTime), Order::Asc);
+
+    select
+}
+
 fn select_all_game_with_log_by_start_datetime_gte_and_start_datetime_lte(
     user_id: &str,
     start_datetime: Option<NaiveDateTime>,
@@ -114,7 +151,7 @@ pub fn select_all_first_game_with_log_with_search_by_start_datetime_gte_and_star
 
     // Ignore sort, might conflict with date ordering
     search.sort = None;
-    apply_search(select, search)
+    game_query::apply_search(select, search)
 }
 
 pub fn select_all_last_game_with_log_with_search_by_start_datetime_gte_and_start_datetime_lte_order_by_start_datetime_desc(
@@ -146,7 +183,7 @@ pub fn select_all_last_game_with_log_with_search_by_start_datetime_gte_and_start
 
     // Ignore sort, might conflict with date ordering
     search.sort = None;
-    apply_search(select, search)
+    game_query::apply_search(select, search)
 }
 
 pub fn select_all_games_order_by_start_datetime_desc(user_id: &str) -> SelectStatement {
@@ -217,6 +254,73 @@ pub fn insert(user_id: &str, game_id: &str, log: &GameLog) -> impl QueryStatemen
     insert
 }
 
+/// Extends the logs of the new game starting at the same time as a longer log of the game,
+/// so the longer session is the one kept when the logs are moved
+pub fn update_end_datetime_by_game_id_with_longer(
+    user_id: &str,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let longer_alias = Alias::new("LongerGameLog");
+    let mut longer_select = Query::select();
+    longer_select
+        .from_as(GameLogIden::Table, longer_alias.clone())
+        .and_where(Expr::col((longer_alias.clone(), GameLogIden::UserId)).eq(user_id))
+        .and_where(Expr::col((longer_alias.clone(), GameLogIden::GameId)).eq(game_id))
+        .and_where(
+            Expr::col((longer_alias.clone(), GameLogIden::StartDateTime))
+                .equals((GameLogIden::Table, GameLogIden::StartDateTime)),
+        )
+        .and_where(
+            Expr::col((longer_alias.clone(), GameLogIden::EndDateTime))
+                .gt(Expr::col((GameLogIden::Table, GameLogIden::EndDateTime))),
+        );
+
+    let mut end_select = longer_select.clone();
+    end_select.column((longer_alias, GameLogIden::EndDateTime));
+    longer_select.expr(Expr::val(1));
+
+    update
+        .table(GameLogIden::Table)
+        .value(GameLogIden::EndDateTime, scalar_subquery(end_select))
+        .and_where(Expr::col((GameLogIden::Table, GameLogIden::UserId)).eq(user_id))
+        .and_where(Expr::col((GameLogIden::Table, GameLogIden::GameId)).eq(new_game_id))
+        .and_where(Expr::exists(longer_select));
+
+    update
+}
+
+pub fn update_game_id_by_game_id(
+    user_id: &str,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let existing_alias = Alias::new("ExistingGameLog");
+    let mut existing_select = Query::select();
+    existing_select
+        .expr(Expr::val(1))
+        .from_as(GameLogIden::Table, existing_alias.clone())
+        .and_where(Expr::col((existing_alias.clone(), GameLogIden::UserId)).eq(user_id))
+        .and_where(Expr::col((existing_alias.clone(), GameLogIden::GameId)).eq(new_game_id))
+        .and_where(
+            Expr::col((existing_alias, GameLogIden::StartDateTime))
+                .equals((GameLogIden::Table, GameLogIden::StartDateTime)),
+        );
+
+    update
+        .table(GameLogIden::Table)
+        .value(GameLogIden::GameId, new_game_id)
+        .and_where(Expr::col((GameLogIden::Table, GameLogIden::UserId)).eq(user_id))
+        .and_where(Expr::col((GameLogIden::Table, GameLogIden::GameId)).eq(game_id))
+        .and_where(Expr::exists(existing_select).not());
+
+    update
+}
+
 pub fn delete_by_id(
     user_id: &str,
     game_id: &str,
@@ -332,6 +436,21 @@ fn coalesce_time_sum() -> FunctionCall {
     ])
 }
 
+/// Total time in seconds, so it can be compared with durations
+pub(super) fn coalesce_time_sum_secs() -> FunctionCall {
+    Func::coalesce([
+        Func::cast_as(
+            Func::cust(Alias::new("date_part")).args([
+                Expr::val("epoch").into(),
+                Expr::expr(derived_time_expr()).sum(),
+            ]),
+            Alias::new("bigint"),
+        )
+        .into(),
+        Expr::val(0).into(),
+    ])
+}
+
 fn derived_time_expr() -> SimpleExpr {
     Expr::col((GameLogIden::Table, GameLogIden::EndDateTime))
         .sub(Expr::col((GameLogIden::Table, GameLogIden::StartDateTime)))
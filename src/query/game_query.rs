@@ -1,9 +1,14 @@
-use sea_query::{Expr, Query, QueryStatementWriter, SelectStatement, SimpleExpr};
+use sea_query::{Cond, Expr, LockType, Query, QueryStatementWriter, SelectStatement, SimpleExpr};
 
-use crate::entities::{Game, GameIden, GameSearch, GameUserInfoIden, SearchQuery};
+use crate::entities::{
+    Game, GameAvailableIden, GameCustomValueIden, GameFinishIden, GameIden, GameLogIden,
+    GameRelationField, GameSearch, GameSeriesIden, GameTagIden, GameUserInfoIden, PlatformIden,
+    RelationField, SearchQuery, TagIden,
+};
 use crate::errors::SearchErrors;
 
-use super::search::apply_search;
+use super::game_log_query;
+use super::search::{apply_search_with_relations, scalar_subquery, FieldRelation};
 
 pub fn select_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
     let mut select = Query::select();
@@ -16,6 +21,59 @@ pub fn select_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
     select
 }
 
+pub fn select_all_by_id_in(user_id: &str, ids: Vec<String>) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id_in(&mut select, ids);
+    join_user_info(&mut select);
+    add_fields(&mut select);
+
+    select
+}
+
+/// Ids of the found games, locked until the end of the transaction so they cannot change meanwhile
+pub fn select_all_ids_by_id_in_for_update(
+    user_id: &str,
+    ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id_in(&mut select, ids);
+    add_id_field(&mut select);
+    select.lock(LockType::Update);
+
+    select
+}
+
+/// Names and editions from the given ones already used by games other than the given ids
+pub fn select_all_name_and_edition_by_name_and_edition_in_and_id_not_in(
+    user_id: &str,
+    name_editions: &[(String, String)],
+    ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    let mut name_edition_cond = Cond::any();
+    for (name, edition) in name_editions {
+        name_edition_cond = name_edition_cond.add(
+            Expr::col((GameIden::Table, GameIden::Name))
+                .eq(name.as_str())
+                .and(Expr::col((GameIden::Table, GameIden::Edition)).eq(edition.as_str())),
+        );
+    }
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .column((GameIden::Table, GameIden::Name))
+        .column((GameIden::Table, GameIden::Edition))
+        .cond_where(name_edition_cond)
+        .and_where(Expr::col((GameIden::Table, GameIden::Id)).is_not_in(ids));
+
+    select
+}
+
 pub fn select_all_with_search(
     user_id: &str,
     search: GameSearch,
@@ -25,6 +83,224 @@ pub fn select_all_with_search(
     apply_search(select, search)
 }
 
+/// Ids of every game matching the search, without paging
+pub fn select_all_ids_with_search(
+    user_id: &str,
+    search: GameSearch,
+) -> Result<SelectStatement, SearchErrors> {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    join_user_info(&mut select);
+    add_id_field(&mut select);
+
+    let mut search_query = apply_search(select, search)?;
+    search_query.query.reset_limit().reset_offset();
+
+    Ok(search_query.query)
+}
+
+/// Applies a game search, translating fields computed from related rows into subqueries
+pub(super) fn apply_search(
+    select: SelectStatement,
+    search: GameSearch,
+) -> Result<SearchQuery, SearchErrors> {
+    apply_search_with_relations(select, search, search_relation)
+}
+
+fn search_relation(relation: &RelationField) -> Option<FieldRelation> {
+    match relation {
+        RelationField::Game(field) => Some(game_relation(*field)),
+        RelationField::GameCustomField(column, field_id) => {
+            Some(custom_field_relation(*column, field_id))
+        }
+        _ => None,
+    }
+}
+
+fn game_relation(field: GameRelationField) -> FieldRelation {
+    match field {
+        GameRelationField::Tag => FieldRelation::Any(
+            related_tags(false),
+            Expr::col((GameTagIden::Table, GameTagIden::TagId)).into(),
+        ),
+        GameRelationField::TagName => FieldRelation::Any(
+            related_tags(true),
+            Expr::col((TagIden::Table, TagIden::Name)).into(),
+        ),
+        GameRelationField::Platform => FieldRelation::Any(
+            related_platforms(false),
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::PlatformId)).into(),
+        ),
+        GameRelationField::PlatformType => FieldRelation::Any(
+            related_platforms(true),
+            Expr::col((PlatformIden::Table, PlatformIden::Type)).into(),
+        ),
+        GameRelationField::AvailableDate => FieldRelation::Any(
+            related_platforms(false),
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::AddedDate)).into(),
+        ),
+        GameRelationField::AvailableEndDate => FieldRelation::Any(
+            related_platforms(false),
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::EndDate)).into(),
+        ),
+        GameRelationField::FinishCount => {
+            let mut select = related_finishes();
+            select.expr(Expr::col((GameFinishIden::Table, GameFinishIden::Date)).count());
+            FieldRelation::Value(scalar_subquery(select))
+        }
+        GameRelationField::LastFinishDate => {
+            let mut select = related_finishes();
+            select.expr(Expr::col((GameFinishIden::Table, GameFinishIden::Date)).max());
+            FieldRelation::Value(scalar_subquery(select))
+        }
+        GameRelationField::TotalTime => {
+            let mut select = related_logs();
+            select.expr(game_log_query::coalesce_time_sum_secs());
+            FieldRelation::Value(scalar_subquery(select))
+        }
+        GameRelationField::LastPlayed => {
+            let mut select = related_logs();
+            select.expr(Expr::col((GameLogIden::Table, GameLogIden::EndDateTime)).max());
+            FieldRelation::Value(scalar_subquery(select))
+        }
+        GameRelationField::Series => FieldRelation::Any(
+            related_series(),
+            Expr::col((GameSeriesIden::Table, GameSeriesIden::SeriesId)).into(),
+        ),
+    }
+}
+
+/// Tags of the searched game, joined with their info when it is filtered by it
+fn related_tags(join_tag: bool) -> SelectStatement {
+    let mut select = Query::select();
+
+    select
+        .from(GameTagIden::Table)
+        .and_where(
+            Expr::col((GameTagIden::Table, GameTagIden::UserId))
+                .equals((GameIden::Table, GameIden::UserId)),
+        )
+        .and_where(
+            Expr::col((GameTagIden::Table, GameTagIden::GameId))
+                .equals((GameIden::Table, GameIden::Id)),
+        );
+    if join_tag {
+        select.inner_join(
+            TagIden::Table,
+            Expr::col((TagIden::Table, TagIden::UserId))
+                .equals((GameTagIden::Table, GameTagIden::UserId))
+                .and(
+                    Expr::col((TagIden::Table, TagIden::Id))
+                        .equals((GameTagIden::Table, GameTagIden::TagId)),
+                ),
+        );
+    }
+
+    select
+}
+
+/// Platforms the searched game is available on, joined with their info when it is filtered by it
+fn related_platforms(join_platform: bool) -> SelectStatement {
+    let mut select = Query::select();
+
+    select
+        .from(GameAvailableIden::Table)
+        .and_where(
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::UserId))
+                .equals((GameIden::Table, GameIden::UserId)),
+        )
+        .and_where(
+            Expr::col((GameAvailableIden::Table, GameAvailableIden::GameId))
+                .equals((GameIden::Table, GameIden::Id)),
+        );
+    if join_platform {
+        select.inner_join(
+            PlatformIden::Table,
+            Expr::col((PlatformIden::Table, PlatformIden::UserId))
+                .equals((GameAvailableIden::Table, GameAvailableIden::UserId))
+                .and(
+                    Expr::col((PlatformIden::Table, PlatformIden::Id))
+                        .equals((GameAvailableIden::Table, GameAvailableIden::PlatformId)),
+                ),
+        );
+    }
+
+    select
+}
+
+fn related_series() -> SelectStatement {
+    let mut select = Query::select();
+
+    select
+        .from(GameSeriesIden::Table)
+        .and_where(
+            Expr::col((GameSeriesIden::Table, GameSeriesIden::UserId))
+                .equals((GameIden::Table, GameIden::UserId)),
+        )
+        .and_where(
+            Expr::col((GameSeriesIden::Table, GameSeriesIden::GameId))
+                .equals((GameIden::Table, GameIden::Id)),
+        );
+
+    select
+}
+
+/// Value of a custom field of the searched game, as <column>:<field id>
+fn custom_field_relation(column: GameCustomValueIden, field_id: &str) -> FieldRelation {
+    let mut select = Query::select();
+    select
+        .expr(Expr::col((GameCustomValueIden::Table, column)))
+        .from(GameCustomValueIden::Table)
+        .and_where(
+            Expr::col((GameCustomValueIden::Table, GameCustomValueIden::UserId))
+                .equals((GameIden::Table, GameIden::UserId)),
+        )
+        .and_where(
+            Expr::col((GameCustomValueIden::Table, GameCustomValueIden::GameId))
+                .equals((GameIden::Table, GameIden::Id)),
+        )
+        .and_where(
+            Expr::col((GameCustomValueIden::Table, GameCustomValueIden::FieldId)).eq(field_id),
+        );
+
+    FieldRelation::Value(scalar_subquery(select))
+}
+
+fn related_finishes() -> SelectStatement {
+    let mut select = Query::select();
+
+    select
+        .from(GameFinishIden::Table)
+        .and_where(
+            Expr::col((GameFinishIden::Table, GameFinishIden::UserId))
+                .equals((GameIden::Table, GameIden::UserId)),
+        )
+        .and_where(
+            Expr::col((GameFinishIden::Table, GameFinishIden::GameId))
+                .equals((GameIden::Table, GameIden::Id)),
+        );
+
+    select
+}
+
+fn related_logs() -> SelectStatement {
+    let mut select = Query::select();
+
+    select
+        .from(GameLogIden::Table)
+        .and_where(
+            Expr::col((GameLogIden::Table, GameLogIden::UserId))
+                .equals((GameIden::Table, GameIden::UserId)),
+        )
+        .and_where(
+            Expr::col((GameLogIden::Table, GameLogIden::GameId))
+                .equals((GameIden::Table, GameIden::Id)),
+        );
+
+    select
+}
+
 pub(super) fn select_all(user_id: &str) -> SelectStatement {
     let mut select = Query::select();
 
@@ -262,6 +538,10 @@ fn where_id(select: &mut SelectStatement, id: &str) {
     select.and_where(Expr::col((GameIden::Table, GameIden::Id)).eq(id));
 }
 
+fn where_id_in(select: &mut SelectStatement, ids: Vec<String>) {
+    select.and_where(Expr::col((GameIden::Table, GameIden::Id)).is_in(ids));
+}
+
 fn join_user_info(select: &mut SelectStatement) {
     select.left_join(
         GameUserInfoIden::Table,
@@ -0,0 +1,255 @@
+use sea_query::{Alias, Cond, Expr, Order, Query, QueryStatementWriter, SelectStatement};
+
+use crate::entities::{GameIden, GameRelationshipIden, RELATIONSHIP_TYPE_ALIAS};
+
+use super::game_query;
+
+/// Games the given one points to, e.g. the original of a remaster
+pub fn select_all_related_games_by_game_id(
+    user_id: &str,
+    game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut select = game_query::select_all(user_id);
+
+    join_game_relationship(&mut select, GameRelationshipIden::RelatedGameId);
+    select.and_where(
+        Expr::col((GameRelationshipIden::Table, GameRelationshipIden::GameId)).eq(game_id),
+    );
+    add_fields(&mut select);
+
+    select
+}
+
+/// Games pointing to the given one, e.g. the remasters of an original
+pub fn select_all_relating_games_by_game_id(
+    user_id: &str,
+    game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut select = game_query::select_all(user_id);
+
+    join_game_relationship(&mut select, GameRelationshipIden::GameId);
+    select.and_where(
+        Expr::col((
+            GameRelationshipIden::Table,
+            GameRelationshipIden::RelatedGameId,
+        ))
+        .eq(game_id),
+    );
+    add_fields(&mut select);
+
+    select
+}
+
+/// Relationships of the type going from any of the given games
+pub fn select_all_by_game_id_in_and_type(
+    user_id: &str,
+    game_ids: Vec<String>,
+    rtype: i16,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .column((GameRelationshipIden::Table, GameRelationshipIden::GameId))
+        .column((
+            GameRelationshipIden::Table,
+            GameRelationshipIden::RelatedGameId,
+        ))
+        .and_where(
+            Expr::col((GameRelationshipIden::Table, GameRelationshipIden::GameId)).is_in(game_ids),
+        )
+        .and_where(Expr::col((GameRelationshipIden::Table, GameRelationshipIden::Type)).eq(rtype));
+
+    select
+}
+
+pub fn insert(
+    user_id: &str,
+    game_id: &str,
+    related_game_id: &str,
+    rtype: i16,
+) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(GameRelationshipIden::Table)
+        .columns([
+            GameRelationshipIden::UserId,
+            GameRelationshipIden::GameId,
+            GameRelationshipIden::RelatedGameId,
+            GameRelationshipIden::Type,
+        ])
+        .values_panic([
+            user_id.into(),
+            game_id.into(),
+            related_game_id.into(),
+            rtype.into(),
+        ]);
+
+    insert
+}
+
+pub fn update_game_id_by_game_id(
+    user_id: &str,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    update_game_column_by_game_id(
+        user_id,
+        GameRelationshipIden::GameId,
+        GameRelationshipIden::RelatedGameId,
+        game_id,
+        new_game_id,
+    )
+}
+
+pub fn update_related_game_id_by_related_game_id(
+    user_id: &str,
+    related_game_id: &str,
+    new_related_game_id: &str,
+) -> impl QueryStatementWriter {
+    update_game_column_by_game_id(
+        user_id,
+        GameRelationshipIden::RelatedGameId,
+        GameRelationshipIden::GameId,
+        related_game_id,
+        new_related_game_id,
+    )
+}
+
+pub fn delete_by_id(
+    user_id: &str,
+    game_id: &str,
+    related_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(GameRelationshipIden::Table)
+        .and_where(Expr::col(GameRelationshipIden::UserId).eq(user_id))
+        .and_where(Expr::col(GameRelationshipIden::GameId).eq(game_id))
+        .and_where(Expr::col(GameRelationshipIden::RelatedGameId).eq(related_game_id));
+
+    delete
+}
+
+pub fn delete_between_games(
+    user_id: &str,
+    game_id: &str,
+    other_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(GameRelationshipIden::Table)
+        .and_where(Expr::col(GameRelationshipIden::UserId).eq(user_id))
+        .cond_where(between_games_condition(game_id, other_game_id));
+
+    delete
+}
+
+pub fn exists_by_id(
+    user_id: &str,
+    game_id: &str,
+    related_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .column((GameRelationshipIden::Table, GameRelationshipIden::UserId))
+        .and_where(Expr::col(GameRelationshipIden::GameId).eq(game_id))
+        .and_where(Expr::col(GameRelationshipIden::RelatedGameId).eq(related_game_id));
+
+    select
+}
+
+/// Any relationship between both games, whatever its direction
+pub fn exists_between_games(
+    user_id: &str,
+    game_id: &str,
+    other_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .column((GameRelationshipIden::Table, GameRelationshipIden::UserId))
+        .cond_where(between_games_condition(game_id, other_game_id));
+
+    select
+}
+
+fn update_game_column_by_game_id(
+    user_id: &str,
+    column: GameRelationshipIden,
+    other_column: GameRelationshipIden,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let existing_alias = Alias::new("ExistingGameRelationship");
+    let mut existing_select = Query::select();
+    existing_select
+        .expr(Expr::val(1))
+        .from_as(GameRelationshipIden::Table, existing_alias.clone())
+        .and_where(Expr::col((existing_alias.clone(), GameRelationshipIden::UserId)).eq(user_id))
+        .and_where(Expr::col((existing_alias.clone(), column)).eq(new_game_id))
+        .and_where(
+            Expr::col((existing_alias, other_column))
+                .equals((GameRelationshipIden::Table, other_column)),
+        );
+
+    update
+        .table(GameRelationshipIden::Table)
+        .value(column, new_game_id)
+        .and_where(
+            Expr::col((GameRelationshipIden::Table, GameRelationshipIden::UserId)).eq(user_id),
+        )
+        .and_where(Expr::col((GameRelationshipIden::Table, column)).eq(game_id))
+        .and_where(Expr::exists(existing_select).not());
+
+    update
+}
+
+fn between_games_condition(game_id: &str, other_game_id: &str) -> Cond {
+    Cond::any()
+        .add(
+            Cond::all()
+                .add(Expr::col(GameRelationshipIden::GameId).eq(game_id))
+                .add(Expr::col(GameRelationshipIden::RelatedGameId).eq(other_game_id)),
+        )
+        .add(
+            Cond::all()
+                .add(Expr::col(GameRelationshipIden::GameId).eq(other_game_id))
+                .add(Expr::col(GameRelationshipIden::RelatedGameId).eq(game_id)),
+        )
+}
+
+fn join_game_relationship(select: &mut SelectStatement, game_column: GameRelationshipIden) {
+    select.left_join(
+        GameRelationshipIden::Table,
+        Expr::col((GameIden::Table, GameIden::UserId))
+            .equals((GameRelationshipIden::Table, GameRelationshipIden::UserId))
+            .and(
+                Expr::col((GameIden::Table, GameIden::Id))
+                    .equals((GameRelationshipIden::Table, game_column)),
+            ),
+    );
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
+    select.from(GameRelationshipIden::Table).and_where(
+        Expr::col((GameRelationshipIden::Table, GameRelationshipIden::UserId)).eq(user_id),
+    );
+}
+
+fn add_fields(select: &mut SelectStatement) {
+    select
+        .expr_as(
+            Expr::col((GameRelationshipIden::Table, GameRelationshipIden::Type)),
+            Alias::new(RELATIONSHIP_TYPE_ALIAS),
+        )
+        .order_by((GameIden::Table, GameIden::Name), Order::Asc);
+}
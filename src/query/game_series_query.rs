@@ -0,0 +1,162 @@
+use sea_query::{Alias, Expr, Order, Query, QueryStatementWriter, SelectStatement};
+
+use crate::entities::{GameIden, GameSeriesIden, SeriesIden};
+
+use super::{game_query, series_query};
+
+pub fn select_all_games_by_series_id_order_by_position(
+    user_id: &str,
+    series_id: &str,
+) -> impl QueryStatementWriter {
+    let mut select = game_query::select_all(user_id);
+
+    join_game_series_by_series_id(&mut select, series_id);
+    select
+        .column((GameSeriesIden::Table, GameSeriesIden::Position))
+        .order_by(
+            (GameSeriesIden::Table, GameSeriesIden::Position),
+            Order::Asc,
+        )
+        .order_by((GameIden::Table, GameIden::Name), Order::Asc);
+
+    select
+}
+
+pub fn select_all_series_by_game_id(user_id: &str, game_id: &str) -> impl QueryStatementWriter {
+    let mut select = series_query::select_all(user_id);
+
+    join_game_series_by_game_id(&mut select, game_id);
+
+    select
+}
+
+pub fn insert(
+    user_id: &str,
+    game_id: &str,
+    series_id: &str,
+    position: i32,
+) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(GameSeriesIden::Table)
+        .columns([
+            GameSeriesIden::UserId,
+            GameSeriesIden::GameId,
+            GameSeriesIden::SeriesId,
+            GameSeriesIden::Position,
+        ])
+        .values_panic([
+            user_id.into(),
+            game_id.into(),
+            series_id.into(),
+            position.into(),
+        ]);
+
+    insert
+}
+
+pub fn update_position_by_id(
+    user_id: &str,
+    game_id: &str,
+    series_id: &str,
+    position: i32,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    update
+        .table(GameSeriesIden::Table)
+        .value(GameSeriesIden::Position, position)
+        .and_where(Expr::col(GameSeriesIden::UserId).eq(user_id))
+        .and_where(Expr::col(GameSeriesIden::GameId).eq(game_id))
+        .and_where(Expr::col(GameSeriesIden::SeriesId).eq(series_id));
+
+    update
+}
+
+pub fn update_game_id_by_game_id(
+    user_id: &str,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let existing_alias = Alias::new("ExistingGameSeries");
+    let mut existing_select = Query::select();
+    existing_select
+        .expr(Expr::val(1))
+        .from_as(GameSeriesIden::Table, existing_alias.clone())
+        .and_where(Expr::col((existing_alias.clone(), GameSeriesIden::UserId)).eq(user_id))
+        .and_where(Expr::col((existing_alias.clone(), GameSeriesIden::GameId)).eq(new_game_id))
+        .and_where(
+            Expr::col((existing_alias, GameSeriesIden::SeriesId))
+                .equals((GameSeriesIden::Table, GameSeriesIden::SeriesId)),
+        );
+
+    update
+        .table(GameSeriesIden::Table)
+        .value(GameSeriesIden::GameId, new_game_id)
+        .and_where(Expr::col((GameSeriesIden::Table, GameSeriesIden::UserId)).eq(user_id))
+        .and_where(Expr::col((GameSeriesIden::Table, GameSeriesIden::GameId)).eq(game_id))
+        .and_where(Expr::exists(existing_select).not());
+
+    update
+}
+
+pub fn delete_by_id(user_id: &str, game_id: &str, series_id: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(GameSeriesIden::Table)
+        .and_where(Expr::col(GameSeriesIden::UserId).eq(user_id))
+        .and_where(Expr::col(GameSeriesIden::GameId).eq(game_id))
+        .and_where(Expr::col(GameSeriesIden::SeriesId).eq(series_id));
+
+    delete
+}
+
+pub fn exists_by_id(user_id: &str, game_id: &str, series_id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .column((GameSeriesIden::Table, GameSeriesIden::UserId))
+        .and_where(Expr::col(GameSeriesIden::GameId).eq(game_id))
+        .and_where(Expr::col(GameSeriesIden::SeriesId).eq(series_id));
+
+    select
+}
+
+fn join_game_series_by_series_id(select: &mut SelectStatement, series_id: &str) {
+    select
+        .left_join(
+            GameSeriesIden::Table,
+            Expr::col((GameIden::Table, GameIden::UserId))
+                .equals((GameSeriesIden::Table, GameSeriesIden::UserId))
+                .and(
+                    Expr::col((GameIden::Table, GameIden::Id))
+                        .equals((GameSeriesIden::Table, GameSeriesIden::GameId)),
+                ),
+        )
+        .and_where(Expr::col((GameSeriesIden::Table, GameSeriesIden::SeriesId)).eq(series_id));
+}
+
+fn join_game_series_by_game_id(select: &mut SelectStatement, game_id: &str) {
+    select
+        .left_join(
+            GameSeriesIden::Table,
+            Expr::col((SeriesIden::Table, SeriesIden::UserId))
+                .equals((GameSeriesIden::Table, GameSeriesIden::UserId))
+                .and(
+                    Expr::col((SeriesIden::Table, SeriesIden::Id))
+                        .equals((GameSeriesIden::Table, GameSeriesIden::SeriesId)),
+                ),
+        )
+        .and_where(Expr::col((GameSeriesIden::Table, GameSeriesIden::GameId)).eq(game_id));
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
+    select
+        .from(GameSeriesIden::Table)
+        .and_where(Expr::col((GameSeriesIden::Table, GameSeriesIden::UserId)).eq(user_id));
+}
@@ -0,0 +1,242 @@
+use chrono::NaiveDateTime;
+use sea_query::{Alias, Expr, Order, Query, QueryStatementWriter, SelectStatement};
+
+use crate::entities::{GameIden, GameStatusHistoryIden, GameUserInfoIden};
+
+pub fn select_all_by_game_id_order_by_datetime(
+    user_id: &str,
+    game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .and_where(
+            Expr::col((GameStatusHistoryIden::Table, GameStatusHistoryIden::GameId)).eq(game_id),
+        )
+        .column((GameStatusHistoryIden::Table, GameStatusHistoryIden::GameId));
+    add_fields(&mut select);
+    order_by_datetime_asc(&mut select);
+
+    select
+}
+
+pub fn select_all_games_by_datetime_gte_and_datetime_lte_order_by_datetime(
+    user_id: &str,
+    start_datetime: NaiveDateTime,
+    end_datetime: NaiveDateTime,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    join_game(&mut select);
+    select
+        .and_where(
+            Expr::col((
+                GameStatusHistoryIden::Table,
+                GameStatusHistoryIden::DateTime,
+            ))
+            .gte(start_datetime),
+        )
+        .and_where(
+            Expr::col((
+                GameStatusHistoryIden::Table,
+                GameStatusHistoryIden::DateTime,
+            ))
+            .lte(end_datetime),
+        )
+        .column((GameIden::Table, GameIden::Id))
+        .column((GameIden::Table, GameIden::Name))
+        .column((GameIden::Table, GameIden::Edition))
+        .column((GameIden::Table, GameIden::CoverFilename))
+        .column((GameIden::Table, GameIden::AddedDateTime));
+    add_fields(&mut select);
+    order_by_datetime_asc(&mut select);
+
+    select
+}
+
+/// First time each game was set to the status, leaving out the backfilled changes as their
+/// date is only the one of the game creation
+pub fn select_all_first_by_status_and_game_id_in(
+    user_id: &str,
+    status: i16,
+    game_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .and_where(
+            Expr::col((GameStatusHistoryIden::Table, GameStatusHistoryIden::GameId))
+                .is_in(game_ids),
+        )
+        .and_where(
+            Expr::col((GameStatusHistoryIden::Table, GameStatusHistoryIden::Status)).eq(status),
+        )
+        .and_where(
+            Expr::col((
+                GameStatusHistoryIden::Table,
+                GameStatusHistoryIden::Backfilled,
+            ))
+            .eq(false),
+        )
+        .column((GameStatusHistoryIden::Table, GameStatusHistoryIden::GameId))
+        .column((GameStatusHistoryIden::Table, GameStatusHistoryIden::Status))
+        .expr_as(
+            Expr::col((
+                GameStatusHistoryIden::Table,
+                GameStatusHistoryIden::PreviousStatus,
+            ))
+            .max(),
+            GameStatusHistoryIden::PreviousStatus,
+        )
+        .expr_as(
+            Expr::col((
+                GameStatusHistoryIden::Table,
+                GameStatusHistoryIden::DateTime,
+            ))
+            .min(),
+            GameStatusHistoryIden::DateTime,
+        )
+        .group_by_col((GameStatusHistoryIden::Table, GameStatusHistoryIden::UserId))
+        .group_by_col((GameStatusHistoryIden::Table, GameStatusHistoryIden::GameId))
+        .group_by_col((GameStatusHistoryIden::Table, GameStatusHistoryIden::Status));
+
+    select
+}
+
+pub fn insert(user_id: &str, game_id: &str, status: i16) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(GameStatusHistoryIden::Table)
+        .columns([
+            GameStatusHistoryIden::UserId,
+            GameStatusHistoryIden::GameId,
+            GameStatusHistoryIden::Status,
+            GameStatusHistoryIden::DateTime,
+        ])
+        .values_panic([
+            user_id.into(),
+            game_id.into(),
+            status.into(),
+            crate::date_utils::now().into(),
+        ]);
+
+    insert
+}
+
+/// Records the status change, must run before the status of the game is updated
+pub fn insert_if_status_changed(
+    user_id: &str,
+    game_id: &str,
+    status: i16,
+) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    select
+        .column((GameUserInfoIden::Table, GameUserInfoIden::UserId))
+        .column((GameUserInfoIden::Table, GameUserInfoIden::GameId))
+        .expr(Expr::val(status))
+        .column((GameUserInfoIden::Table, GameUserInfoIden::Status))
+        .expr(Expr::val(crate::date_utils::now()))
+        .from(GameUserInfoIden::Table)
+        .and_where(Expr::col((GameUserInfoIden::Table, GameUserInfoIden::UserId)).eq(user_id))
+        .and_where(Expr::col((GameUserInfoIden::Table, GameUserInfoIden::GameId)).eq(game_id))
+        .and_where(Expr::col((GameUserInfoIden::Table, GameUserInfoIden::Status)).ne(status));
+
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(GameStatusHistoryIden::Table)
+        .columns([
+            GameStatusHistoryIden::UserId,
+            GameStatusHistoryIden::GameId,
+            GameStatusHistoryIden::Status,
+            GameStatusHistoryIden::PreviousStatus,
+            GameStatusHistoryIden::DateTime,
+        ])
+        .select_from(select)
+        .expect("Selected values should match the inserted columns");
+
+    insert
+}
+
+pub fn update_game_id_by_game_id(
+    user_id: &str,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let existing_alias = Alias::new("ExistingGameStatusHistory");
+    let mut existing_select = Query::select();
+    existing_select
+        .expr(Expr::val(1))
+        .from_as(GameStatusHistoryIden::Table, existing_alias.clone())
+        .and_where(Expr::col((existing_alias.clone(), GameStatusHistoryIden::UserId)).eq(user_id))
+        .and_where(
+            Expr::col((existing_alias.clone(), GameStatusHistoryIden::GameId)).eq(new_game_id),
+        )
+        .and_where(
+            Expr::col((existing_alias, GameStatusHistoryIden::DateTime)).equals((
+                GameStatusHistoryIden::Table,
+                GameStatusHistoryIden::DateTime,
+            )),
+        );
+
+    update
+        .table(GameStatusHistoryIden::Table)
+        .value(GameStatusHistoryIden::GameId, new_game_id)
+        .and_where(
+            Expr::col((GameStatusHistoryIden::Table, GameStatusHistoryIden::UserId)).eq(user_id),
+        )
+        .and_where(
+            Expr::col((GameStatusHistoryIden::Table, GameStatusHistoryIden::GameId)).eq(game_id),
+        )
+        .and_where(Expr::exists(existing_select).not());
+
+    update
+}
+
+fn join_game(select: &mut SelectStatement) {
+    select.inner_join(
+        GameIden::Table,
+        Expr::col((GameStatusHistoryIden::Table, GameStatusHistoryIden::UserId))
+            .equals((GameIden::Table, GameIden::UserId))
+            .and(
+                Expr::col((GameStatusHistoryIden::Table, GameStatusHistoryIden::GameId))
+                    .equals((GameIden::Table, GameIden::Id)),
+            ),
+    );
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
+    select.from(GameStatusHistoryIden::Table).and_where(
+        Expr::col((GameStatusHistoryIden::Table, GameStatusHistoryIden::UserId)).eq(user_id),
+    );
+}
+
+fn order_by_datetime_asc(select: &mut SelectStatement) {
+    select.order_by(
+        (
+            GameStatusHistoryIden::Table,
+            GameStatusHistoryIden::DateTime,
+        ),
+        Order::Asc,
+    );
+}
+
+fn add_fields(select: &mut SelectStatement) {
+    select
+        .column((GameStatusHistoryIden::Table, GameStatusHistoryIden::Status))
+        .column((
+            GameStatusHistoryIden::Table,
+            GameStatusHistoryIden::PreviousStatus,
+        ))
+        .column((
+            GameStatusHistoryIden::Table,
+            GameStatusHistoryIden::DateTime,
+        ));
+}
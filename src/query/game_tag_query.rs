@@ -1,4 +1,4 @@
-use sea_query::{Expr, Query, QueryStatementWriter, SelectStatement};
+use sea_query::{Alias, Expr, Query, QueryStatementWriter, SelectStatement};
 
 use crate::entities::{GameIden, GameTagIden, TagIden};
 
@@ -20,6 +20,20 @@ pub fn select_all_tags_by_game_id(user_id: &str, game_id: &str) -> impl QuerySta
     select
 }
 
+pub fn select_all_tags_by_game_id_in(
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let mut select = tag_query::select_all(user_id);
+
+    join_game_tag(&mut select);
+    select
+        .and_where(Expr::col((GameTagIden::Table, GameTagIden::GameId)).is_in(game_ids))
+        .column((GameTagIden::Table, GameTagIden::GameId));
+
+    select
+}
+
 pub fn insert(user_id: &str, game_id: &str, tag_id: &str) -> impl QueryStatementWriter {
     let mut insert = Query::insert();
 
@@ -31,6 +45,35 @@ pub fn insert(user_id: &str, game_id: &str, tag_id: &str) -> impl QueryStatement
     insert
 }
 
+pub fn update_game_id_by_game_id(
+    user_id: &str,
+    game_id: &str,
+    new_game_id: &str,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let existing_alias = Alias::new("ExistingGameTag");
+    let mut existing_select = Query::select();
+    existing_select
+        .expr(Expr::val(1))
+        .from_as(GameTagIden::Table, existing_alias.clone())
+        .and_where(Expr::col((existing_alias.clone(), GameTagIden::UserId)).eq(user_id))
+        .and_where(Expr::col((existing_alias.clone(), GameTagIden::GameId)).eq(new_game_id))
+        .and_where(
+            Expr::col((existing_alias, GameTagIden::TagId))
+                .equals((GameTagIden::Table, GameTagIden::TagId)),
+        );
+
+    update
+        .table(GameTagIden::Table)
+        .value(GameTagIden::GameId, new_game_id)
+        .and_where(Expr::col((GameTagIden::Table, GameTagIden::UserId)).eq(user_id))
+        .and_where(Expr::col((GameTagIden::Table, GameTagIden::GameId)).eq(game_id))
+        .and_where(Expr::exists(existing_select).not());
+
+    update
+}
+
 pub fn delete_by_id(user_id: &str, game_id: &str, tag_id: &str) -> impl QueryStatementWriter {
     let mut delete = Query::delete();
 
@@ -70,17 +113,20 @@ fn join_game_tag_by_tag_id(select: &mut SelectStatement, tag_id: &str) {
 }
 
 fn join_game_tag_by_game_id(select: &mut SelectStatement, game_id: &str) {
-    select
-        .left_join(
-            GameTagIden::Table,
-            Expr::col((TagIden::Table, TagIden::UserId))
-                .equals((GameTagIden::Table, GameTagIden::UserId))
-                .and(
-                    Expr::col((TagIden::Table, TagIden::Id))
-                        .equals((GameTagIden::Table, GameTagIden::TagId)),
-                ),
-        )
-        .and_where(Expr::col((GameTagIden::Table, GameTagIden::GameId)).eq(game_id));
+    join_game_tag(select);
+    select.and_where(Expr::col((GameTagIden::Table, GameTagIden::GameId)).eq(game_id));
+}
+
+fn join_game_tag(select: &mut SelectStatement) {
+    select.left_join(
+        GameTagIden::Table,
+        Expr::col((TagIden::Table, TagIden::UserId))
+            .equals((GameTagIden::Table, GameTagIden::UserId))
+            .and(
+                Expr::col((TagIden::Table, TagIden::Id))
+                    .equals((GameTagIden::Table, GameTagIden::TagId)),
+            ),
+    );
 }
 
 fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
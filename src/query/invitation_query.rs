@@ -0,0 +1,124 @@
+use sea_query::{Expr, Order, Query, QueryStatementWriter, SelectStatement};
+
+use crate::entities::InvitationIden;
+
+pub fn select_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id(&mut select, id);
+    add_fields(&mut select);
+
+    select
+}
+
+pub fn select_all(user_id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    add_fields(&mut select);
+    select.order_by(
+        (InvitationIden::Table, InvitationIden::AddedDateTime),
+        Order::Desc,
+    );
+
+    select
+}
+
+pub fn insert(
+    user_id: &str,
+    id: &str,
+    code: &str,
+    expiry_datetime: chrono::NaiveDateTime,
+) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(InvitationIden::Table)
+        .columns([
+            InvitationIden::UserId,
+            InvitationIden::Id,
+            InvitationIden::Code,
+            InvitationIden::ExpiryDateTime,
+            InvitationIden::AddedDateTime,
+            InvitationIden::UpdatedDateTime,
+        ])
+        .values_panic([
+            user_id.into(),
+            id.into(),
+            code.into(),
+            expiry_datetime.into(),
+            crate::date_utils::now().into(),
+            crate::date_utils::now().into(),
+        ]);
+
+    insert
+}
+
+/// Marks the invitation as used only if it was never used and not expired.
+/// Used datetime is checked as the user gets cleared when the invited user is deleted.
+pub fn update_used_by_code(code: &str, used_by_user_id: &str) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    let now = crate::date_utils::now();
+    update
+        .table(InvitationIden::Table)
+        .values([
+            (InvitationIden::UsedByUserId, used_by_user_id.into()),
+            (InvitationIden::UsedDateTime, now.into()),
+            (InvitationIden::UpdatedDateTime, now.into()),
+        ])
+        .and_where(Expr::col(InvitationIden::Code).eq(code))
+        .and_where(Expr::col(InvitationIden::UsedDateTime).is_null())
+        .and_where(Expr::col(InvitationIden::ExpiryDateTime).gt(now))
+        .returning_col(InvitationIden::Id);
+
+    update
+}
+
+pub fn delete_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(InvitationIden::Table)
+        .and_where(Expr::col(InvitationIden::UserId).eq(user_id))
+        .and_where(Expr::col(InvitationIden::Id).eq(id));
+
+    delete
+}
+
+pub fn exists_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id(&mut select, id);
+    add_id_field(&mut select);
+
+    select
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
+    select
+        .from(InvitationIden::Table)
+        .and_where(Expr::col((InvitationIden::Table, InvitationIden::UserId)).eq(user_id));
+}
+
+fn where_id(select: &mut SelectStatement, id: &str) {
+    select.and_where(Expr::col((InvitationIden::Table, InvitationIden::Id)).eq(id));
+}
+
+fn add_id_field(select: &mut SelectStatement) {
+    select.column((InvitationIden::Table, InvitationIden::Id));
+}
+
+fn add_fields(select: &mut SelectStatement) {
+    add_id_field(select);
+    select
+        .column((InvitationIden::Table, InvitationIden::UserId))
+        .column((InvitationIden::Table, InvitationIden::Code))
+        .column((InvitationIden::Table, InvitationIden::ExpiryDateTime))
+        .column((InvitationIden::Table, InvitationIden::UsedByUserId))
+        .column((InvitationIden::Table, InvitationIden::UsedDateTime))
+        .column((InvitationIden::Table, InvitationIden::AddedDateTime))
+        .column((InvitationIden::Table, InvitationIden::UpdatedDateTime));
+}
@@ -0,0 +1,110 @@
+use chrono::NaiveDateTime;
+use sea_query::{Expr, OnConflict, Query, QueryStatementWriter, SelectStatement};
+
+use crate::entities::LoginAttemptIden;
+
+pub fn select_by_source(source_type: i16, source: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from(&mut select);
+    where_source(&mut select, source_type, source);
+    add_fields(&mut select);
+
+    select
+}
+
+/// Increments the failed count in a single statement, restarting it when the last failure
+/// is older than the window start. Returns the new failed count.
+pub fn upsert_increment_failed_count(
+    source_type: i16,
+    source: &str,
+    now: NaiveDateTime,
+    window_start: NaiveDateTime,
+) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(LoginAttemptIden::Table)
+        .columns([
+            LoginAttemptIden::Source,
+            LoginAttemptIden::SourceType,
+            LoginAttemptIden::FailedCount,
+            LoginAttemptIden::LastFailedDateTime,
+        ])
+        .values_panic([source.into(), source_type.into(), 1.into(), now.into()])
+        .on_conflict(
+            OnConflict::columns([LoginAttemptIden::SourceType, LoginAttemptIden::Source])
+                .value(
+                    LoginAttemptIden::FailedCount,
+                    Expr::cust_with_values(
+                        r#"CASE WHEN "LoginAttempt"."last_failed_datetime" < ? THEN 1 ELSE "LoginAttempt"."failed_count" + 1 END"#,
+                        [window_start],
+                    ),
+                )
+                .value(LoginAttemptIden::LastFailedDateTime, now)
+                .to_owned(),
+        )
+        .returning_col(LoginAttemptIden::FailedCount);
+
+    insert
+}
+
+/// Never shortens a lock set by a concurrent failed attempt
+pub fn update_locked_until_by_source(
+    source_type: i16,
+    source: &str,
+    locked_until_datetime: NaiveDateTime,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    update
+        .table(LoginAttemptIden::Table)
+        .value(LoginAttemptIden::LockedUntilDateTime, locked_until_datetime)
+        .and_where(Expr::col(LoginAttemptIden::SourceType).eq(source_type))
+        .and_where(Expr::col(LoginAttemptIden::Source).eq(source))
+        .and_where(
+            Expr::col(LoginAttemptIden::LockedUntilDateTime)
+                .is_null()
+                .or(Expr::col(LoginAttemptIden::LockedUntilDateTime).lt(locked_until_datetime)),
+        );
+
+    update
+}
+
+pub fn delete_by_source(source_type: i16, source: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(LoginAttemptIden::Table)
+        .and_where(Expr::col(LoginAttemptIden::SourceType).eq(source_type))
+        .and_where(Expr::col(LoginAttemptIden::Source).eq(source));
+
+    delete
+}
+
+fn from(select: &mut SelectStatement) {
+    select.from(LoginAttemptIden::Table);
+}
+
+fn where_source(select: &mut SelectStatement, source_type: i16, source: &str) {
+    select
+        .and_where(
+            Expr::col((LoginAttemptIden::Table, LoginAttemptIden::SourceType)).eq(source_type),
+        )
+        .and_where(Expr::col((LoginAttemptIden::Table, LoginAttemptIden::Source)).eq(source));
+}
+
+fn add_fields(select: &mut SelectStatement) {
+    select
+        .column((LoginAttemptIden::Table, LoginAttemptIden::Source))
+        .column((LoginAttemptIden::Table, LoginAttemptIden::SourceType))
+        .column((LoginAttemptIden::Table, LoginAttemptIden::FailedCount))
+        .column((
+            LoginAttemptIden::Table,
+            LoginAttemptIden::LastFailedDateTime,
+        ))
+        .column((
+            LoginAttemptIden::Table,
+            LoginAttemptIden::LockedUntilDateTime,
+        ));
+}
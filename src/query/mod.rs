@@ -1,12 +1,23 @@
+pub mod custom_field_query;
 pub mod dlc_available_query;
 pub mod dlc_finish_query;
 pub mod dlc_query;
+pub mod dlc_tag_query;
 pub mod game_available_query;
+pub mod game_custom_value_query;
 pub mod game_finish_query;
 pub mod game_log_query;
 pub mod game_query;
+pub mod game_relationship_query;
+pub mod game_series_query;
+pub mod game_status_history_query;
 pub mod game_tag_query;
+pub mod invitation_query;
+pub mod login_attempt_query;
 pub mod platform_query;
+pub mod saved_search_query;
 pub mod search;
+pub mod series_query;
 pub mod tag_query;
 pub mod user_query;
+pub mod user_totp_query;
@@ -0,0 +1,168 @@
+use sea_query::{Expr, Query, QueryStatementWriter, SelectStatement, SimpleExpr};
+
+use crate::entities::{SavedSearch, SavedSearchIden, SavedSearchSearch, SearchQuery};
+use crate::errors::SearchErrors;
+
+use super::search::apply_search;
+
+pub fn select_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id(&mut select, id);
+    add_fields(&mut select);
+
+    select
+}
+
+pub fn select_all_with_search(
+    user_id: &str,
+    search: SavedSearchSearch,
+) -> Result<SearchQuery, SearchErrors> {
+    let select = select_all(user_id);
+
+    apply_search(select, search)
+}
+
+fn select_all(user_id: &str) -> SelectStatement {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    add_fields(&mut select);
+
+    select
+}
+
+pub fn insert(user_id: &str, id: &str, saved_search: &SavedSearch) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(SavedSearchIden::Table)
+        .columns([
+            SavedSearchIden::UserId,
+            SavedSearchIden::Id,
+            SavedSearchIden::Name,
+            SavedSearchIden::Type,
+            SavedSearchIden::Search,
+            SavedSearchIden::AddedDateTime,
+            SavedSearchIden::UpdatedDateTime,
+        ])
+        .values_panic([
+            user_id.into(),
+            id.into(),
+            saved_search.name.clone().into(),
+            saved_search.stype.into(),
+            saved_search.search.clone().into(),
+            crate::date_utils::now().into(),
+            crate::date_utils::now().into(),
+        ]);
+
+    insert
+}
+
+pub fn update_by_id(
+    user_id: &str,
+    id: &str,
+    saved_search: &SavedSearch,
+) -> impl QueryStatementWriter {
+    update_values_by_id(
+        user_id,
+        id,
+        vec![
+            (SavedSearchIden::Name, saved_search.name.clone().into()),
+            (SavedSearchIden::Type, saved_search.stype.into()),
+            (SavedSearchIden::Search, saved_search.search.clone().into()),
+        ],
+    )
+}
+
+fn update_values_by_id(
+    user_id: &str,
+    id: &str,
+    mut values: Vec<(SavedSearchIden, SimpleExpr)>,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    values.push((
+        SavedSearchIden::UpdatedDateTime,
+        crate::date_utils::now().into(),
+    ));
+    update
+        .table(SavedSearchIden::Table)
+        .values(values)
+        .and_where(Expr::col(SavedSearchIden::UserId).eq(user_id))
+        .and_where(Expr::col(SavedSearchIden::Id).eq(id));
+
+    update
+}
+
+pub fn delete_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(SavedSearchIden::Table)
+        .and_where(Expr::col(SavedSearchIden::UserId).eq(user_id))
+        .and_where(Expr::col(SavedSearchIden::Id).eq(id));
+
+    delete
+}
+
+pub fn exists_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id(&mut select, id);
+    add_id_field(&mut select);
+
+    select
+}
+
+pub fn exists_by_name_and_type(user_id: &str, name: &str, stype: i16) -> SelectStatement {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    add_id_field(&mut select);
+    select
+        .and_where(Expr::col(SavedSearchIden::Name).eq(name))
+        .and_where(Expr::col(SavedSearchIden::Type).eq(stype));
+
+    select
+}
+
+pub fn exists_by_name_and_type_and_id_not(
+    user_id: &str,
+    name: &str,
+    stype: i16,
+    id: &str,
+) -> impl QueryStatementWriter {
+    let mut select = exists_by_name_and_type(user_id, name, stype);
+
+    select.and_where(Expr::col(SavedSearchIden::Id).ne(id));
+
+    select
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
+    select
+        .from(SavedSearchIden::Table)
+        .and_where(Expr::col((SavedSearchIden::Table, SavedSearchIden::UserId)).eq(user_id));
+}
+
+fn where_id(select: &mut SelectStatement, id: &str) {
+    select.and_where(Expr::col((SavedSearchIden::Table, SavedSearchIden::Id)).eq(id));
+}
+
+fn add_id_field(select: &mut SelectStatement) {
+    select.column((SavedSearchIden::Table, SavedSearchIden::Id));
+}
+
+fn add_fields(select: &mut SelectStatement) {
+    add_id_field(select);
+    select
+        .column((SavedSearchIden::Table, SavedSearchIden::UserId))
+        .column((SavedSearchIden::Table, SavedSearchIden::Name))
+        .column((SavedSearchIden::Table, SavedSearchIden::Type))
+        .column((SavedSearchIden::Table, SavedSearchIden::Search))
+        .column((SavedSearchIden::Table, SavedSearchIden::AddedDateTime))
+        .column((SavedSearchIden::Table, SavedSearchIden::UpdatedDateTime));
+}
@@ -1,98 +1,82 @@
-use sea_query::{BinOper, Cond, Expr, Func, LikeExpr, SelectStatement, Value};
+use base64::Engine;
+use chrono::{NaiveDate, NaiveDateTime};
+use sea_query::{
+    Alias, BinOper, Cond, Expr, Func, JoinType, LikeExpr, Order, Query, SelectStatement,
+    SimpleExpr, SubQueryStatement, Value,
+};
 
 use crate::entities::{
-    FieldSearchValue, FieldValue, FilterOperator, Search, SearchQuery, TableIden,
+    Facet, FacetQuery, FieldSearchValue, FieldType, FieldValue, Filter, FilterGroup, FilterNode,
+    FilterOperator, GroupOperator, RelationField, Search, SearchField, SearchQuery, Sort,
+    TableIden,
 };
 use crate::errors::{MappingError, SearchErrors};
 
 const DEFAULT_PAGE_SIZE: u64 = 500;
 const INITIAL_PAGE: u64 = 0;
 const LIKE_SYMBOL: &str = "%";
+const UNACCENT_FUNCTION: &str = "immutable_unaccent";
+const WORD_SIMILAR_OPERATOR: &str = "<%";
+const COUNT_SUBQUERY_ALIAS: &str = "search";
+const FACET_VALUE_COLUMN: &str = "value";
+const FACET_COUNT_COLUMN: &str = "count";
+pub const CURSOR_COLUMN: &str = "search_cursor";
+const CURSOR_ENGINE: base64::engine::GeneralPurpose =
+    base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
+/// Search field not stored in a joined table but computed from rows related to the searched one
+pub enum FieldRelation {
+    /// Correlated select of the related rows and their value, matching when any of them does.
+    /// Sorted by the lowest value among them.
+    Any(SelectStatement, SimpleExpr),
+    /// Single value computed from the related rows
+    Value(SimpleExpr),
+}
+
+/// Obtains the relation of a search field, if supported by the searched resource
+pub type RelationResolver = fn(&RelationField) -> Option<FieldRelation>;
+
+/// Search field ready to be queried, either a plain column or a resolved relation
+enum ResolvedField {
+    Column(Expr),
+    Relation(FieldRelation),
+}
 
 pub fn apply_search<I: 'static + TableIden + Clone + Copy>(
     select: SelectStatement,
     search: Search<I>,
 ) -> Result<SearchQuery, SearchErrors> {
-    apply_search_internal(select, search).map_err(SearchErrors::Mapping)
+    apply_search_with_relations(select, search, |_| None)
+}
+
+pub fn apply_search_with_relations<I: 'static + TableIden + Clone + Copy>(
+    select: SelectStatement,
+    search: Search<I>,
+    relations: RelationResolver,
+) -> Result<SearchQuery, SearchErrors> {
+    apply_search_internal(select, search, relations).map_err(SearchErrors::Mapping)
 }
 
 fn apply_search_internal<I: 'static + TableIden + Clone + Copy>(
     mut select: SelectStatement,
     search: Search<I>,
+    relations: RelationResolver,
 ) -> Result<SearchQuery, MappingError> {
-    if let Some(sorts) = search.sort {
-        for sort in sorts {
-            let table = sort.table;
-            let field = sort.field;
-            let order = sort.order;
-            select.order_by((table, field), order);
-        }
-    }
-
+    let mut similarity_ranks = vec![];
     if let Some(filters) = search.filter {
         if !filters.is_empty() {
             let mut ands = Cond::all();
             let mut ors = Cond::any();
 
             for filter in filters {
-                let table = filter.table;
-                let field = filter.field;
-                let col = Expr::col((table, field));
-                let expr = match filter.value {
-                    FieldValue::Value(value) => match filter.operator {
-                        FilterOperator::Equal => col.eq(Value::try_from(value)?),
-                        FilterOperator::NotEqual => col.ne(Value::try_from(value)?),
-                        FilterOperator::GreaterThan => col.gt(Value::try_from(value)?),
-                        FilterOperator::GreaterThanOrEqual => col.gte(Value::try_from(value)?),
-                        FilterOperator::SmallerThan => col.lt(Value::try_from(value)?),
-                        FilterOperator::SmallerThanOrEqual => col.lte(Value::try_from(value)?),
-                        FilterOperator::StartsWith => {
-                            to_lower(col).like(LikeExpr::new(format_like_starts_with(value)))
-                        }
-                        FilterOperator::NotStartsWith => {
-                            to_lower(col).not_like(LikeExpr::new(format_like_starts_with(value)))
-                        }
-                        FilterOperator::EndsWith => {
-                            to_lower(col).like(LikeExpr::new(format_like_ends_with(value)))
-                        }
-                        FilterOperator::NotEndsWith => {
-                            to_lower(col).not_like(LikeExpr::new(format_like_ends_with(value)))
-                        }
-                        FilterOperator::Contains => {
-                            to_lower(col).like(LikeExpr::new(format_like_contains(value)))
-                        }
-                        FilterOperator::NotContains => {
-                            to_lower(col).not_like(LikeExpr::new(format_like_contains(value)))
-                        }
-                        _ => Err(MappingError(String::from(
-                            "Operator not supported with single value.",
-                        )))?,
-                    },
-                    FieldValue::Values(value) => {
-                        let _type = value._type;
-                        let in_values = value
-                            .values
-                            .into_iter()
-                            .map(|v| {
-                                let field_search_value = crate::entities::FieldSearchValue {
-                                    _type: _type.clone(),
-                                    value: v,
-                                };
-                                Value::try_from(field_search_value)
-                            })
-                            .collect::<Result<Vec<Value>, MappingError>>()?;
-
-                        match filter.operator {
-                            FilterOperator::In => col.is_in(in_values),
-                            FilterOperator::NotIn => col.is_not_in(in_values),
-                            _ => Err(MappingError(String::from(
-                                "Operator not supported with multiple values.",
-                            )))?,
-                        }
-                    }
-                };
+                if let Some(rank) = similarity_rank(&filter, relations)? {
+                    similarity_ranks.push(rank);
+                }
 
-                match filter.chain_operator {
+                let chain_operator = filter.chain_operator.clone();
+                let expr = filter_to_expr(filter, relations)?;
+
+                match chain_operator {
                     BinOper::And => ands = ands.add(expr),
                     BinOper::Or => ors = ors.add(expr),
                     _ => unreachable!(),
@@ -108,19 +92,480 @@ fn apply_search_internal<I: 'static + TableIden + Clone + Copy>(
         }
     }
 
+    if let Some(filter_group) = search.filter_group {
+        let cond = filter_group_to_cond(filter_group, relations)?;
+        if !cond.is_empty() {
+            select.cond_where(cond);
+        }
+    }
+
+    // Built before sorting and paging as they are not needed to count
+    let count_query = if search.count {
+        Some(count_select(&select))
+    } else {
+        None
+    };
+    let facet_queries = search
+        .facets
+        .into_iter()
+        .map(|facet| facet_select(&select, facet, relations))
+        .collect::<Result<Vec<FacetQuery>, MappingError>>()?;
+
+    let sorts = search.sort.unwrap_or_default();
+    let cursor = search.cursor.is_some();
+    if let Some(encoded_cursor) = search.cursor {
+        let cursor_values = decode_cursor(&encoded_cursor)?;
+        // Empty cursor is the first page
+        if !cursor_values.is_empty() {
+            select.cond_where(cursor_cond(&sorts, cursor_values, relations)?);
+        }
+        select.expr_as(cursor_expr(&sorts, relations)?, Alias::new(CURSOR_COLUMN));
+    }
+
+    // Best matches first when searching by similarity without explicit order
+    if sorts.is_empty() && !similarity_ranks.is_empty() {
+        select.order_by_expr(
+            Func::cust(Alias::new("GREATEST"))
+                .args(similarity_ranks)
+                .into(),
+            Order::Desc,
+        );
+    }
+
+    for sort in sorts {
+        let order = sort.order.clone();
+        select.order_by_expr(sort_expr(&sort, relations)?, order);
+    }
+
     let size = search.size.unwrap_or(DEFAULT_PAGE_SIZE);
     select.limit(size);
 
-    let page = search.page.unwrap_or(INITIAL_PAGE);
+    // Cursor already points to where the page starts
+    let page = if cursor {
+        INITIAL_PAGE
+    } else {
+        search.page.unwrap_or(INITIAL_PAGE)
+    };
     select.offset(page * size);
 
     Ok(SearchQuery {
         query: select,
+        count_query,
+        facet_queries,
+        cursor,
         page,
         size,
     })
 }
 
+/// Counts the rows of the filtered select, wrapped so grouped or distinct selects are counted right
+fn count_select(select: &SelectStatement) -> SelectStatement {
+    let mut count_select = Query::select();
+    count_select
+        .expr(Expr::cust("COUNT(*)"))
+        .from_subquery(select.clone(), Alias::new(COUNT_SUBQUERY_ALIAS));
+    count_select
+}
+
+/// Counts the rows of the filtered select by each value of the facet field.
+/// Filtered rows are aliased as the searched table, so relations can still be correlated to them.
+fn facet_select<I: 'static + TableIden + Clone + Copy>(
+    select: &SelectStatement,
+    facet: Facet<I>,
+    relations: RelationResolver,
+) -> Result<FacetQuery, MappingError> {
+    let mut facet_select = match &facet.field.field {
+        // Filtered rows keep the column names, now under the searched table alias
+        SearchField::Column(_, column) => {
+            facet_value_select::<I>(select, Expr::col((I::TABLE, column.clone())).into())
+        }
+        SearchField::Relation(relation) => match resolve_relation(relation, relations)? {
+            FieldRelation::Any(mut related, value) => {
+                related
+                    .expr_as(facet_value_expr(value), Alias::new(FACET_VALUE_COLUMN))
+                    .join_subquery(
+                        JoinType::InnerJoin,
+                        select.clone(),
+                        I::TABLE,
+                        Expr::cust("TRUE"),
+                    );
+                related
+            }
+            FieldRelation::Value(value) => facet_value_select::<I>(select, value),
+        },
+    };
+
+    facet_select
+        .expr_as(Expr::cust("COUNT(*)"), Alias::new(FACET_COUNT_COLUMN))
+        .group_by_col(Alias::new(FACET_VALUE_COLUMN))
+        .order_by(Alias::new(FACET_COUNT_COLUMN), Order::Desc);
+
+    Ok(FacetQuery {
+        name: facet.name,
+        _type: facet.field._type,
+        query: facet_select,
+    })
+}
+
+fn facet_value_select<I: 'static + TableIden + Clone + Copy>(
+    select: &SelectStatement,
+    value: SimpleExpr,
+) -> SelectStatement {
+    let mut facet_select = Query::select();
+    facet_select
+        .expr_as(facet_value_expr(value), Alias::new(FACET_VALUE_COLUMN))
+        .from_subquery(select.clone(), I::TABLE);
+    facet_select
+}
+
+fn facet_value_expr(value: SimpleExpr) -> SimpleExpr {
+    Func::cast_as(value, Alias::new("text")).into()
+}
+
+pub fn encode_cursor(cursor_values: &str) -> String {
+    CURSOR_ENGINE.encode(cursor_values)
+}
+
+fn decode_cursor(encoded_cursor: &str) -> Result<Vec<serde_json::Value>, MappingError> {
+    if crate::string_utils::string_is_blank(encoded_cursor) {
+        return Ok(vec![]);
+    }
+
+    let decoded_cursor = CURSOR_ENGINE
+        .decode(encoded_cursor)
+        .map_err(|_| invalid_cursor_error())?;
+    serde_json::from_slice(&decoded_cursor).map_err(|_| invalid_cursor_error())
+}
+
+/// Value a sort orders by, the column itself unless it comes from a relation
+fn sort_expr<I: TableIden>(
+    sort: &Sort<I>,
+    relations: RelationResolver,
+) -> Result<SimpleExpr, MappingError> {
+    let expr = match resolve_field(&sort.field, relations)? {
+        ResolvedField::Relation(FieldRelation::Any(mut related, value)) => {
+            related.expr(Expr::expr(value).min());
+            scalar_subquery(related)
+        }
+        ResolvedField::Relation(FieldRelation::Value(value)) => value,
+        ResolvedField::Column(col) => col.into(),
+    };
+    Ok(expr)
+}
+
+pub fn scalar_subquery(select: SelectStatement) -> SimpleExpr {
+    SimpleExpr::SubQuery(None, Box::new(SubQueryStatement::SelectStatement(select)))
+}
+
+/// Fields computed from related rows must be resolved by the searched resource, so a search
+/// applied without them fails instead of querying a missing table
+fn resolve_field(
+    field: &SearchField,
+    relations: RelationResolver,
+) -> Result<ResolvedField, MappingError> {
+    match field {
+        SearchField::Column(table, column) => Ok(ResolvedField::Column(Expr::col((
+            table.clone(),
+            column.clone(),
+        )))),
+        SearchField::Relation(relation) => {
+            resolve_relation(relation, relations).map(ResolvedField::Relation)
+        }
+    }
+}
+
+fn resolve_relation(
+    relation: &RelationField,
+    relations: RelationResolver,
+) -> Result<FieldRelation, MappingError> {
+    relations(relation)
+        .ok_or_else(|| MappingError(String::from("Field not supported in this search.")))
+}
+
+/// Sort values of each row as a JSON array, so the last one can be used as next cursor
+fn cursor_expr<I: TableIden>(
+    sorts: &[Sort<I>],
+    relations: RelationResolver,
+) -> Result<SimpleExpr, MappingError> {
+    let sort_cols = sorts
+        .iter()
+        .map(|sort| sort_expr(sort, relations))
+        .collect::<Result<Vec<SimpleExpr>, MappingError>>()?;
+    let json_array = Func::cust(Alias::new("json_build_array")).args(sort_cols);
+    Ok(Func::cast_as(json_array, Alias::new("text")).into())
+}
+
+/// Rows after the cursor in sort order: (a > x) OR (a = x AND b > y) OR ...
+/// Nulls are taken into account as they go last when ascending and first when descending
+fn cursor_cond<I: TableIden>(
+    sorts: &[Sort<I>],
+    cursor_values: Vec<serde_json::Value>,
+    relations: RelationResolver,
+) -> Result<Cond, MappingError> {
+    if sorts.len() != cursor_values.len() {
+        return Err(invalid_cursor_error());
+    }
+
+    let mut after_cursor = Cond::any();
+    let mut equal_to_cursor = Cond::all();
+    for (sort, cursor_value) in sorts.iter().zip(cursor_values) {
+        let col = Expr::expr(sort_expr(sort, relations)?);
+        let value = cursor_value_to_value(&sort._type, cursor_value)?;
+        let descending = matches!(sort.order, Order::Desc);
+
+        let after_expr = match &value {
+            Some(value) if descending => Some(col.clone().lt(value.clone())),
+            Some(value) => Some(col.clone().gt(value.clone()).or(col.clone().is_null())),
+            None if descending => Some(col.clone().is_not_null()),
+            None => None,
+        };
+        if let Some(after_expr) = after_expr {
+            after_cursor = after_cursor.add(equal_to_cursor.clone().add(after_expr));
+        }
+
+        let equal_expr = match value {
+            Some(value) => col.eq(value),
+            None => col.is_null(),
+        };
+        equal_to_cursor = equal_to_cursor.add(equal_expr);
+    }
+
+    Ok(after_cursor)
+}
+
+fn cursor_value_to_value(
+    _type: &FieldType,
+    cursor_value: serde_json::Value,
+) -> Result<Option<Value>, MappingError> {
+    if cursor_value.is_null() {
+        return Ok(None);
+    }
+
+    let value: Option<Value> = match _type {
+        FieldType::Integer => cursor_value
+            .as_i64()
+            .and_then(|value| i32::try_from(value).ok())
+            .map(Value::from),
+        FieldType::Number => cursor_value.as_f64().map(Value::from),
+        FieldType::String => cursor_value.as_str().map(Value::from),
+        FieldType::Boolean => cursor_value.as_bool().map(Value::from),
+        FieldType::Date => cursor_value
+            .as_str()
+            .and_then(|value| value.parse::<NaiveDate>().ok())
+            .map(Value::from),
+        FieldType::DateTime => cursor_value
+            .as_str()
+            .and_then(|value| value.parse::<NaiveDateTime>().ok())
+            .map(Value::from),
+        FieldType::Duration => cursor_value.as_i64().map(Value::from),
+        FieldType::GameStatus | FieldType::PlatformType | FieldType::SavedSearchType => {
+            cursor_value
+                .as_i64()
+                .and_then(|value| i16::try_from(value).ok())
+                .map(Value::from)
+        }
+    };
+    value.map(Some).ok_or_else(invalid_cursor_error)
+}
+
+fn invalid_cursor_error() -> MappingError {
+    MappingError(String::from("Invalid cursor."))
+}
+
+fn filter_group_to_cond<I: TableIden>(
+    filter_group: FilterGroup<I>,
+    relations: RelationResolver,
+) -> Result<Cond, MappingError> {
+    let mut cond = match filter_group.operator {
+        GroupOperator::All => Cond::all(),
+        GroupOperator::Any => Cond::any(),
+        GroupOperator::Not => Cond::all().not(),
+    };
+
+    for node in filter_group.nodes {
+        cond = match node {
+            FilterNode::Filter(filter) => cond.add(filter_to_expr(filter, relations)?),
+            FilterNode::Group(group) => cond.add(filter_group_to_cond(group, relations)?),
+        };
+    }
+
+    Ok(cond)
+}
+
+fn filter_to_expr<I: TableIden>(
+    filter: Filter<I>,
+    relations: RelationResolver,
+) -> Result<SimpleExpr, MappingError> {
+    match resolve_field(&filter.field, relations)? {
+        ResolvedField::Relation(FieldRelation::Any(related, value)) => {
+            related_filter_expr(related, Expr::expr(value), filter.value, filter.operator)
+        }
+        ResolvedField::Relation(FieldRelation::Value(value)) => {
+            operator_expr(Expr::expr(value), filter.value, filter.operator)
+        }
+        ResolvedField::Column(col) => operator_expr(col, filter.value, filter.operator),
+    }
+}
+
+/// Matches when any related row does, negated operators match when none of them does the opposite
+fn related_filter_expr(
+    mut related: SelectStatement,
+    col: Expr,
+    value: FieldValue,
+    operator: FilterOperator,
+) -> Result<SimpleExpr, MappingError> {
+    let (operator, negated) = match operator {
+        FilterOperator::NotEqual => (FilterOperator::Equal, true),
+        FilterOperator::NotIn => (FilterOperator::In, true),
+        FilterOperator::NotStartsWith => (FilterOperator::StartsWith, true),
+        FilterOperator::NotEndsWith => (FilterOperator::EndsWith, true),
+        FilterOperator::NotContains => (FilterOperator::Contains, true),
+        // Null when there is no related row with a value
+        FilterOperator::IsNull => (FilterOperator::IsNotNull, true),
+        operator => (operator, false),
+    };
+
+    related
+        .expr(Expr::val(1))
+        .and_where(operator_expr(col, value, operator)?);
+    let exists = Expr::exists(related);
+
+    if negated {
+        Ok(exists.not())
+    } else {
+        Ok(exists)
+    }
+}
+
+fn operator_expr(
+    col: Expr,
+    value: FieldValue,
+    operator: FilterOperator,
+) -> Result<SimpleExpr, MappingError> {
+    let expr = match value {
+        FieldValue::Empty => match operator {
+            FilterOperator::IsNull => col.is_null(),
+            FilterOperator::IsNotNull => col.is_not_null(),
+            _ => Err(MappingError(String::from("Operator needs a value.")))?,
+        },
+        FieldValue::Value(value) => match operator {
+            FilterOperator::Equal => col.eq(Value::try_from(value)?),
+            FilterOperator::NotEqual => col.ne(Value::try_from(value)?),
+            FilterOperator::GreaterThan => col.gt(Value::try_from(value)?),
+            FilterOperator::GreaterThanOrEqual => col.gte(Value::try_from(value)?),
+            FilterOperator::SmallerThan => col.lt(Value::try_from(value)?),
+            FilterOperator::SmallerThanOrEqual => col.lte(Value::try_from(value)?),
+            FilterOperator::StartsWith => {
+                to_lower(col).like(LikeExpr::new(format_like_starts_with(value)))
+            }
+            FilterOperator::NotStartsWith => {
+                to_lower(col).not_like(LikeExpr::new(format_like_starts_with(value)))
+            }
+            FilterOperator::EndsWith => {
+                to_lower(col).like(LikeExpr::new(format_like_ends_with(value)))
+            }
+            FilterOperator::NotEndsWith => {
+                to_lower(col).not_like(LikeExpr::new(format_like_ends_with(value)))
+            }
+            FilterOperator::Contains => {
+                to_lower(col).like(LikeExpr::new(format_like_contains(value)))
+            }
+            FilterOperator::NotContains => {
+                to_lower(col).not_like(LikeExpr::new(format_like_contains(value)))
+            }
+            FilterOperator::Similar => similar_expr(col, value)?,
+            _ => Err(MappingError(String::from(
+                "Operator not supported with single value.",
+            )))?,
+        },
+        FieldValue::Values(value) => {
+            let _type = value._type;
+            let in_values = value
+                .values
+                .into_iter()
+                .map(|v| {
+                    let field_search_value = crate::entities::FieldSearchValue {
+                        _type: _type.clone(),
+                        value: v,
+                    };
+                    Value::try_from(field_search_value)
+                })
+                .collect::<Result<Vec<Value>, MappingError>>()?;
+
+            match operator {
+                FilterOperator::In => col.is_in(in_values),
+                FilterOperator::NotIn => col.is_not_in(in_values),
+                _ => Err(MappingError(String::from(
+                    "Operator not supported with multiple values.",
+                )))?,
+            }
+        }
+    };
+
+    Ok(expr)
+}
+
+/// Accent insensitive match containing the value or close enough to it to allow typos.
+/// Both operators work on the same expression as the trigram indexes so they can be used
+fn similar_expr(col: Expr, search: FieldSearchValue) -> Result<SimpleExpr, MappingError> {
+    let pattern = format!("{LIKE_SYMBOL}{}{LIKE_SYMBOL}", search.value);
+    let (col, value) = similar_operands(col, search)?;
+    let pattern = unaccent_lower(Expr::val(pattern));
+    let contains = Expr::expr(col.clone()).binary(BinOper::Like, pattern);
+    let similar = Expr::expr(value).binary(BinOper::Custom(WORD_SIMILAR_OPERATOR), col);
+
+    Ok(contains.or(similar))
+}
+
+/// Similarity of a single value filter, to rank by how close each row is to it
+fn similarity_rank<I: TableIden>(
+    filter: &Filter<I>,
+    relations: RelationResolver,
+) -> Result<Option<SimpleExpr>, MappingError> {
+    let FieldValue::Value(value) = &filter.value else {
+        return Ok(None);
+    };
+    if !matches!(filter.operator, FilterOperator::Similar) {
+        return Ok(None);
+    }
+
+    let col = match resolve_field(&filter.field, relations)? {
+        ResolvedField::Relation(FieldRelation::Any(_, _)) => return Ok(None),
+        ResolvedField::Relation(FieldRelation::Value(value)) => Expr::expr(value),
+        ResolvedField::Column(col) => col,
+    };
+    let search = FieldSearchValue {
+        _type: value._type.clone(),
+        value: value.value.clone(),
+    };
+    let (col, value) = similar_operands(col, search)?;
+
+    Ok(Some(
+        Func::cust(Alias::new("word_similarity"))
+            .args([value, col])
+            .into(),
+    ))
+}
+
+fn similar_operands(
+    col: Expr,
+    search: FieldSearchValue,
+) -> Result<(SimpleExpr, SimpleExpr), MappingError> {
+    if !matches!(search._type, FieldType::String) {
+        return Err(MappingError(String::from(
+            "Similar operator only supported with text fields.",
+        )));
+    }
+
+    Ok((unaccent_lower(col), unaccent_lower(Expr::val(search.value))))
+}
+
+fn unaccent_lower(expr: Expr) -> SimpleExpr {
+    Func::cust(Alias::new(UNACCENT_FUNCTION))
+        .arg(Func::lower(expr))
+        .into()
+}
+
 fn to_lower(col: Expr) -> Expr {
     Expr::expr(Func::lower(col))
 }
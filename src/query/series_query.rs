@@ -0,0 +1,169 @@
+use sea_query::{Expr, Query, QueryStatementWriter, SelectStatement, SimpleExpr};
+
+use crate::entities::{SearchQuery, Series, SeriesIden, SeriesSearch};
+use crate::errors::SearchErrors;
+
+use super::search::apply_search;
+
+pub fn select_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id(&mut select, id);
+    add_fields(&mut select);
+
+    select
+}
+
+pub fn select_all_with_query(
+    user_id: &str,
+    search: SeriesSearch,
+) -> Result<SearchQuery, SearchErrors> {
+    let select = select_all(user_id);
+
+    apply_search(select, search)
+}
+
+pub(super) fn select_all(user_id: &str) -> SelectStatement {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    add_fields(&mut select);
+
+    select
+}
+
+pub fn insert(user_id: &str, id: &str, series: &Series) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(SeriesIden::Table)
+        .columns([
+            SeriesIden::UserId,
+            SeriesIden::Id,
+            SeriesIden::Name,
+            SeriesIden::Description,
+            SeriesIden::ImageFilename,
+            SeriesIden::AddedDateTime,
+            SeriesIden::UpdatedDateTime,
+        ])
+        .values_panic([
+            user_id.into(),
+            id.into(),
+            series.name.clone().into(),
+            series.description.clone().into(),
+            series.image_filename.clone().into(),
+            crate::date_utils::now().into(),
+            crate::date_utils::now().into(),
+        ]);
+
+    insert
+}
+
+pub fn update_by_id(user_id: &str, id: &str, series: &Series) -> impl QueryStatementWriter {
+    update_values_by_id(
+        user_id,
+        id,
+        vec![
+            (SeriesIden::Name, series.name.clone().into()),
+            (SeriesIden::Description, series.description.clone().into()),
+            (
+                SeriesIden::ImageFilename,
+                series.image_filename.clone().into(),
+            ),
+        ],
+    )
+}
+
+pub fn update_image_filename_by_id(
+    user_id: &str,
+    id: &str,
+    image_filename: Option<String>,
+) -> impl QueryStatementWriter {
+    update_values_by_id(
+        user_id,
+        id,
+        vec![(SeriesIden::ImageFilename, image_filename.into())],
+    )
+}
+
+fn update_values_by_id(
+    user_id: &str,
+    id: &str,
+    mut values: Vec<(SeriesIden, SimpleExpr)>,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    values.push((SeriesIden::UpdatedDateTime, crate::date_utils::now().into()));
+    update
+        .table(SeriesIden::Table)
+        .values(values)
+        .and_where(Expr::col(SeriesIden::UserId).eq(user_id))
+        .and_where(Expr::col(SeriesIden::Id).eq(id));
+
+    update
+}
+
+pub fn delete_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(SeriesIden::Table)
+        .and_where(Expr::col(SeriesIden::UserId).eq(user_id))
+        .and_where(Expr::col(SeriesIden::Id).eq(id));
+
+    delete
+}
+
+pub fn exists_by_id(user_id: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    where_id(&mut select, id);
+    add_id_field(&mut select);
+
+    select
+}
+
+pub fn exists_by_name(user_id: &str, name: &str) -> SelectStatement {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    add_id_field(&mut select);
+    select.and_where(Expr::col(SeriesIden::Name).eq(name));
+
+    select
+}
+
+pub fn exists_by_name_and_id_not(user_id: &str, name: &str, id: &str) -> impl QueryStatementWriter {
+    let mut select = exists_by_name(user_id, name);
+
+    select.and_where(Expr::col(SeriesIden::Id).ne(id));
+
+    select
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
+    select
+        .from(SeriesIden::Table)
+        .and_where(Expr::col((SeriesIden::Table, SeriesIden::UserId)).eq(user_id));
+}
+
+fn where_id(select: &mut SelectStatement, id: &str) {
+    select.and_where(Expr::col((SeriesIden::Table, SeriesIden::Id)).eq(id));
+}
+
+fn add_id_field(select: &mut SelectStatement) {
+    select.column((SeriesIden::Table, SeriesIden::Id));
+}
+
+fn add_fields(select: &mut SelectStatement) {
+    add_id_field(select);
+    select
+        .column((SeriesIden::Table, SeriesIden::UserId))
+        .column((SeriesIden::Table, SeriesIden::Name))
+        .column((SeriesIden::Table, SeriesIden::Description))
+        .column((SeriesIden::Table, SeriesIden::ImageFilename))
+        .column((SeriesIden::Table, SeriesIden::AddedDateTime))
+        .column((SeriesIden::Table, SeriesIden::UpdatedDateTime));
+}
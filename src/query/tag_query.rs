@@ -1,6 +1,12 @@
-use sea_query::{Expr, Query, QueryStatementWriter, SelectStatement, SimpleExpr};
+use sea_query::{
+    Alias, CommonTableExpression, Expr, Order, Query, QueryStatementWriter, SelectStatement,
+    SimpleExpr, UnionType, WithClause,
+};
 
 use crate::entities::{SearchQuery, Tag, TagIden, TagSearch};
+
+const DESCENDANT_ALIAS: &str = "TagDescendant";
+const ROOT_ID_ALIAS: &str = "root_id";
 use crate::errors::SearchErrors;
 
 use super::search::apply_search;
@@ -24,6 +30,67 @@ pub fn select_all_with_query(
     apply_search(select, search)
 }
 
+pub fn select_all_order_by_name(user_id: &str) -> impl QueryStatementWriter {
+    let mut select = select_all(user_id);
+
+    select.order_by((TagIden::Table, TagIden::Name), Order::Asc);
+
+    select
+}
+
+/// Every tag under the given ones along with the one it was found from, including themselves.
+/// Walks down the parents with a recursive CTE, where UNION discards repeated rows so it ends on cycles.
+pub fn select_all_descendants_by_id_in(
+    user_id: &str,
+    ids: Vec<String>,
+) -> impl QueryStatementWriter {
+    let descendant_alias = Alias::new(DESCENDANT_ALIAS);
+
+    let mut roots_select = Query::select();
+    roots_select
+        .expr_as(
+            Expr::col((TagIden::Table, TagIden::Id)),
+            Alias::new(ROOT_ID_ALIAS),
+        )
+        .column((TagIden::Table, TagIden::Id))
+        .from(TagIden::Table)
+        .and_where(Expr::col((TagIden::Table, TagIden::UserId)).eq(user_id))
+        .and_where(Expr::col((TagIden::Table, TagIden::Id)).is_in(ids));
+
+    let mut children_select = Query::select();
+    children_select
+        .column((descendant_alias.clone(), Alias::new(ROOT_ID_ALIAS)))
+        .column((TagIden::Table, TagIden::Id))
+        .from(TagIden::Table)
+        .inner_join(
+            descendant_alias.clone(),
+            Expr::col((TagIden::Table, TagIden::ParentId))
+                .equals((descendant_alias.clone(), TagIden::Id)),
+        )
+        .and_where(Expr::col((TagIden::Table, TagIden::UserId)).eq(user_id));
+
+    roots_select.union(UnionType::Distinct, children_select);
+    let common_table = CommonTableExpression::new()
+        .query(roots_select)
+        .column(Alias::new(ROOT_ID_ALIAS))
+        .column(TagIden::Id)
+        .table_name(descendant_alias.clone())
+        .to_owned();
+
+    let mut select = Query::select();
+    select
+        .column(Alias::new(ROOT_ID_ALIAS))
+        .column(TagIden::Id)
+        .from(descendant_alias);
+
+    select.with(
+        WithClause::new()
+            .recursive(true)
+            .cte(common_table)
+            .to_owned(),
+    )
+}
+
 pub(super) fn select_all(user_id: &str) -> SelectStatement {
     let mut select = Query::select();
 
@@ -42,6 +109,9 @@ pub fn insert(user_id: &str, id: &str, tag: &Tag) -> impl QueryStatementWriter {
             TagIden::UserId,
             TagIden::Id,
             TagIden::Name,
+            TagIden::ParentId,
+            TagIden::Color,
+            TagIden::Icon,
             TagIden::AddedDateTime,
             TagIden::UpdatedDateTime,
         ])
@@ -49,6 +119,9 @@ pub fn insert(user_id: &str, id: &str, tag: &Tag) -> impl QueryStatementWriter {
             user_id.into(),
             id.into(),
             tag.name.clone().into(),
+            tag.parent_id.map(|id| id.to_string()).into(),
+            tag.color.clone().into(),
+            tag.icon.clone().into(),
             crate::date_utils::now().into(),
             crate::date_utils::now().into(),
         ]);
@@ -57,7 +130,19 @@ pub fn insert(user_id: &str, id: &str, tag: &Tag) -> impl QueryStatementWriter {
 }
 
 pub fn update_by_id(user_id: &str, id: &str, tag: &Tag) -> impl QueryStatementWriter {
-    update_values_by_id(user_id, id, vec![(TagIden::Name, tag.name.clone().into())])
+    update_values_by_id(
+        user_id,
+        id,
+        vec![
+            (TagIden::Name, tag.name.clone().into()),
+            (
+                TagIden::ParentId,
+                tag.parent_id.map(|id| id.to_string()).into(),
+            ),
+            (TagIden::Color, tag.color.clone().into()),
+            (TagIden::Icon, tag.icon.clone().into()),
+        ],
+    )
 }
 
 fn update_values_by_id(
@@ -135,6 +220,9 @@ fn add_fields(select: &mut SelectStatement) {
     select
         .column((TagIden::Table, TagIden::UserId))
         .column((TagIden::Table, TagIden::Name))
+        .column((TagIden::Table, TagIden::ParentId))
+        .column((TagIden::Table, TagIden::Color))
+        .column((TagIden::Table, TagIden::Icon))
         .column((TagIden::Table, TagIden::AddedDateTime))
         .column((TagIden::Table, TagIden::UpdatedDateTime));
 }
@@ -40,7 +40,12 @@ pub(super) fn select_all() -> SelectStatement {
     select
 }
 
-pub fn insert(id: &str, password: &str, user: &User) -> impl QueryStatementWriter {
+pub fn insert(
+    id: &str,
+    password: &str,
+    must_change_password: bool,
+    user: &User,
+) -> impl QueryStatementWriter {
     let mut insert = Query::insert();
 
     insert
@@ -49,6 +54,7 @@ pub fn insert(id: &str, password: &str, user: &User) -> impl QueryStatementWrite
             UserIden::Id,
             UserIden::Password,
             UserIden::Username,
+            UserIden::MustChangePassword,
             UserIden::AddedDateTime,
             UserIden::UpdatedDateTime,
         ])
@@ -56,6 +62,7 @@ pub fn insert(id: &str, password: &str, user: &User) -> impl QueryStatementWrite
             id.into(),
             password.into(),
             user.username.clone().into(),
+            must_change_password.into(),
             crate::date_utils::now().into(),
             crate::date_utils::now().into(),
         ]);
@@ -67,7 +74,21 @@ pub fn update_by_id(id: &str, user: &User) -> impl QueryStatementWriter {
     update_values_by_id(id, vec![(UserIden::Username, user.username.clone().into())])
 }
 
-pub fn update_password_by_id(id: &str, password: &str) -> impl QueryStatementWriter {
+pub fn update_password_by_id(
+    id: &str,
+    password: &str,
+    must_change_password: bool,
+) -> impl QueryStatementWriter {
+    update_values_by_id(
+        id,
+        vec![
+            (UserIden::Password, password.into()),
+            (UserIden::MustChangePassword, must_change_password.into()),
+        ],
+    )
+}
+
+pub fn update_password_hash_by_id(id: &str, password: &str) -> impl QueryStatementWriter {
     update_values_by_id(id, vec![(UserIden::Password, password.into())])
 }
 
@@ -183,6 +204,7 @@ fn add_fields(select: &mut SelectStatement) {
         .column((UserIden::Table, UserIden::Username))
         .column((UserIden::Table, UserIden::Password))
         .column((UserIden::Table, UserIden::Admin))
+        .column((UserIden::Table, UserIden::MustChangePassword))
         .column((UserIden::Table, UserIden::AddedDateTime))
         .column((UserIden::Table, UserIden::UpdatedDateTime));
 }
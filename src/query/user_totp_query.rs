@@ -0,0 +1,167 @@
+use sea_query::{Cond, Expr, LockType, Query, QueryStatementWriter, SelectStatement, SimpleExpr};
+
+use crate::entities::{UserRecoveryCodeIden, UserTotpIden};
+
+pub fn select_by_user_id(user_id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    add_fields(&mut select);
+
+    select
+}
+
+pub fn select_enabled_by_user_id_for_update(user_id: &str) -> impl QueryStatementWriter {
+    let mut select = Query::select();
+
+    from_and_where_user_id(&mut select, user_id);
+    select
+        .column((UserTotpIden::Table, UserTotpIden::Enabled))
+        .lock(LockType::Update);
+
+    select
+}
+
+pub fn insert(user_id: &str, secret: &str) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(UserTotpIden::Table)
+        .columns([
+            UserTotpIden::UserId,
+            UserTotpIden::Secret,
+            UserTotpIden::Enabled,
+            UserTotpIden::AddedDateTime,
+            UserTotpIden::UpdatedDateTime,
+        ])
+        .values_panic([
+            user_id.into(),
+            secret.into(),
+            false.into(),
+            crate::date_utils::now().into(),
+            crate::date_utils::now().into(),
+        ]);
+
+    insert
+}
+
+pub fn update_enabled_by_user_id(
+    user_id: &str,
+    enabled: bool,
+    last_used_step: i64,
+) -> impl QueryStatementWriter {
+    update_values_by_user_id(
+        user_id,
+        vec![
+            (UserTotpIden::Enabled, enabled.into()),
+            (UserTotpIden::LastUsedStep, last_used_step.into()),
+        ],
+    )
+}
+
+/// Returns the row only if the step is newer than the last used one, so a code is accepted once
+pub fn update_last_used_step_by_user_id(
+    user_id: &str,
+    last_used_step: i64,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    update
+        .table(UserTotpIden::Table)
+        .values([
+            (UserTotpIden::LastUsedStep, last_used_step.into()),
+            (
+                UserTotpIden::UpdatedDateTime,
+                crate::date_utils::now().into(),
+            ),
+        ])
+        .and_where(Expr::col(UserTotpIden::UserId).eq(user_id))
+        .cond_where(
+            Cond::any()
+                .add(Expr::col(UserTotpIden::LastUsedStep).is_null())
+                .add(Expr::col(UserTotpIden::LastUsedStep).lt(last_used_step)),
+        )
+        .returning_col(UserTotpIden::UserId);
+
+    update
+}
+
+fn update_values_by_user_id(
+    user_id: &str,
+    mut values: Vec<(UserTotpIden, SimpleExpr)>,
+) -> impl QueryStatementWriter {
+    let mut update = Query::update();
+
+    values.push((
+        UserTotpIden::UpdatedDateTime,
+        crate::date_utils::now().into(),
+    ));
+    update
+        .table(UserTotpIden::Table)
+        .values(values)
+        .and_where(Expr::col(UserTotpIden::UserId).eq(user_id));
+
+    update
+}
+
+pub fn delete_by_user_id(user_id: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(UserTotpIden::Table)
+        .and_where(Expr::col(UserTotpIden::UserId).eq(user_id));
+
+    delete
+}
+
+pub fn insert_recovery_codes(user_id: &str, code_hashes: &[String]) -> impl QueryStatementWriter {
+    let mut insert = Query::insert();
+
+    insert
+        .into_table(UserRecoveryCodeIden::Table)
+        .columns([UserRecoveryCodeIden::UserId, UserRecoveryCodeIden::CodeHash]);
+    for code_hash in code_hashes {
+        insert.values_panic([user_id.into(), code_hash.clone().into()]);
+    }
+
+    insert
+}
+
+/// Returns the row only if the code existed, so it can be used just once
+pub fn delete_recovery_code_by_id(user_id: &str, code_hash: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(UserRecoveryCodeIden::Table)
+        .and_where(Expr::col(UserRecoveryCodeIden::UserId).eq(user_id))
+        .and_where(Expr::col(UserRecoveryCodeIden::CodeHash).eq(code_hash))
+        .returning_col(UserRecoveryCodeIden::UserId);
+
+    delete
+}
+
+pub fn delete_recovery_codes_by_user_id(user_id: &str) -> impl QueryStatementWriter {
+    let mut delete = Query::delete();
+
+    delete
+        .from_table(UserRecoveryCodeIden::Table)
+        .and_where(Expr::col(UserRecoveryCodeIden::UserId).eq(user_id));
+
+    delete
+}
+
+fn from_and_where_user_id(select: &mut SelectStatement, user_id: &str) {
+    select
+        .from(UserTotpIden::Table)
+        .and_where(Expr::col((UserTotpIden::Table, UserTotpIden::UserId)).eq(user_id));
+}
+
+fn add_fields(select: &mut SelectStatement) {
+    select
+        .column((UserTotpIden::Table, UserTotpIden::UserId))
+        .column((UserTotpIden::Table, UserTotpIden::Secret))
+        .column((UserTotpIden::Table, UserTotpIden::Enabled))
+        .column((UserTotpIden::Table, UserTotpIden::LastUsedStep))
+        .column((UserTotpIden::Table, UserTotpIden::AddedDateTime))
+        .column((UserTotpIden::Table, UserTotpIden::UpdatedDateTime));
+}
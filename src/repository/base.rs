@@ -1,9 +1,11 @@
 use sea_query::{PostgresQueryBuilder, QueryStatementWriter};
-use sqlx::{PgPool, Postgres, Transaction};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Postgres, Row, Transaction};
 use uuid::Uuid;
 
-use crate::entities::{PageResult, SearchQuery};
+use crate::entities::{FacetResult, FacetValue, PageResult, SearchQuery};
 use crate::errors::{RepositoryError, SearchErrors};
+use crate::query::search::{encode_cursor, CURSOR_COLUMN};
 
 pub(super) async fn begin_transaction(
     pool: &PgPool,
@@ -138,22 +140,102 @@ where
         .map(|list: Vec<(T,)>| list.into_iter().map(|tuple| tuple.0).collect())
 }
 
-pub(super) async fn fetch_all_search<'c, X, T>(
-    executor: X,
+pub(super) async fn fetch_all_search<T>(
+    pool: &PgPool,
     search_query: SearchQuery,
 ) -> Result<PageResult<T>, SearchErrors>
 where
-    X: sqlx::Executor<'c, Database = Postgres>,
     T: for<'r> sqlx::FromRow<'r, sqlx::postgres::PgRow> + Send + Unpin,
 {
-    fetch_all(executor, search_query.query)
-        .await
-        .map(|list| PageResult {
-            data: list,
-            page: search_query.page,
-            size: search_query.size,
-        })
-        .map_err(SearchErrors::Repository)
+    let size = search_query.size;
+    let cursor = search_query.cursor;
+    let (data, next_cursor) = if cursor {
+        fetch_all_with_cursor(pool, search_query.query, size)
+            .await
+            .map_err(SearchErrors::Repository)?
+    } else {
+        let data = fetch_all(pool, search_query.query)
+            .await
+            .map_err(SearchErrors::Repository)?;
+        (data, None)
+    };
+
+    // Counting is only done when requested as it can be expensive on big tables
+    let total_count = match search_query.count_query {
+        Some(count_query) => {
+            let count: Option<i64> = fetch_optional_single(pool, count_query)
+                .await
+                .map_err(SearchErrors::Repository)?;
+            Some(count.unwrap_or_default().max(0) as u64)
+        }
+        None => None,
+    };
+
+    let facets = if search_query.facet_queries.is_empty() {
+        None
+    } else {
+        let mut facets = vec![];
+        for facet_query in search_query.facet_queries {
+            let values: Vec<FacetValue> = fetch_all(pool, facet_query.query)
+                .await
+                .map_err(SearchErrors::Repository)?;
+            facets.push(FacetResult {
+                name: facet_query.name,
+                _type: facet_query._type,
+                values,
+            });
+        }
+        Some(facets)
+    };
+
+    Ok(PageResult {
+        data,
+        page: search_query.page,
+        size,
+        total_count,
+        cursor,
+        next_cursor,
+        facets,
+    })
+}
+
+async fn fetch_all_with_cursor<T>(
+    pool: &PgPool,
+    query: impl QueryStatementWriter,
+    size: u64,
+) -> Result<(Vec<T>, Option<String>), RepositoryError>
+where
+    T: for<'r> sqlx::FromRow<'r, sqlx::postgres::PgRow> + Send + Unpin,
+{
+    let sql = build_sql(query);
+    log::info!("{}", sql);
+    let rows: Vec<PgRow> = sqlx::query(&sql).fetch_all(pool).await.map_err(|err| {
+        log::error!("Error executing query. - {}", err.to_string());
+        RepositoryError()
+    })?;
+
+    // A page smaller than requested is the last one
+    let next_cursor = match rows.last() {
+        Some(last_row) if rows.len() as u64 == size => {
+            let cursor_values: String = last_row.try_get(CURSOR_COLUMN).map_err(|err| {
+                log::error!("Error getting cursor. - {}", err.to_string());
+                RepositoryError()
+            })?;
+            Some(encode_cursor(&cursor_values))
+        }
+        _ => None,
+    };
+
+    let data = rows
+        .iter()
+        .map(T::from_row)
+        .collect::<Result<Vec<T>, sqlx::Error>>()
+        .map_err(|err| {
+            log::error!("Error mapping rows. - {}", err.to_string());
+            RepositoryError()
+        })?;
+
+    Ok((data, next_cursor))
 }
 
 pub(super) async fn exists_id<'c, X>(
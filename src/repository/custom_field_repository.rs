@@ -0,0 +1,81 @@
+use sqlx::PgPool;
+
+use crate::entities::{CustomField, CustomFieldSearch, PageResult};
+use crate::errors::{RepositoryError, SearchErrors};
+use crate::query::custom_field_query;
+
+use super::base::{execute, exists_id, fetch_all, fetch_all_search, fetch_optional};
+
+pub async fn find_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    id: &str,
+) -> Result<Option<CustomField>, RepositoryError> {
+    let query = custom_field_query::select_by_id(user_id, id);
+    fetch_optional(pool, query).await
+}
+
+pub async fn find_all(pool: &PgPool, user_id: &str) -> Result<Vec<CustomField>, RepositoryError> {
+    let query = custom_field_query::select_all(user_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn search_all(
+    pool: &PgPool,
+    user_id: &str,
+    search: CustomFieldSearch,
+) -> Result<PageResult<CustomField>, SearchErrors> {
+    let search_query = custom_field_query::select_all_with_search(user_id, search)?;
+    fetch_all_search(pool, search_query).await
+}
+
+pub async fn create(
+    pool: &PgPool,
+    user_id: &str,
+    custom_field: &CustomField,
+) -> Result<String, RepositoryError> {
+    let id = crate::uuid_utils::new_model_uuid();
+
+    let query = custom_field_query::insert(user_id, &id, custom_field);
+    execute(pool, query).await.map(|_| id)
+}
+
+pub async fn update_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    id: &str,
+    custom_field: &CustomField,
+) -> Result<(), RepositoryError> {
+    let query = custom_field_query::update_by_id(user_id, id, custom_field);
+    execute(pool, query).await
+}
+
+pub async fn delete_by_id(pool: &PgPool, user_id: &str, id: &str) -> Result<(), RepositoryError> {
+    let query = custom_field_query::delete_by_id(user_id, id);
+    execute(pool, query).await
+}
+
+pub async fn exists_by_id(pool: &PgPool, user_id: &str, id: &str) -> Result<bool, RepositoryError> {
+    let query = custom_field_query::exists_by_id(user_id, id);
+    exists_id(pool, query).await
+}
+
+pub async fn exists_with_unique(
+    pool: &PgPool,
+    user_id: &str,
+    custom_field: &CustomField,
+) -> Result<bool, RepositoryError> {
+    let query = custom_field_query::exists_by_name(user_id, &custom_field.name);
+    exists_id(pool, query).await
+}
+
+pub async fn exists_with_unique_except_id(
+    pool: &PgPool,
+    user_id: &str,
+    custom_field: &CustomField,
+    excluded_id: &str,
+) -> Result<bool, RepositoryError> {
+    let query =
+        custom_field_query::exists_by_name_and_id_not(user_id, &custom_field.name, excluded_id);
+    exists_id(pool, query).await
+}
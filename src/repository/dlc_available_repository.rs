@@ -1,7 +1,7 @@
 use chrono::NaiveDate;
 use sqlx::PgPool;
 
-use crate::entities::{DLCWithDate, PlatformWithDate};
+use crate::entities::{Availability, DLCPurchase, DLCWithDate, PlatformWithDate};
 use crate::errors::RepositoryError;
 use crate::query::dlc_available_query;
 
@@ -29,14 +29,38 @@ pub async fn find_all_platforms_with_dlc(
     fetch_all(pool, query).await
 }
 
+pub async fn find_all_purchases_between(
+    pool: &PgPool,
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<DLCPurchase>, RepositoryError> {
+    let query =
+        dlc_available_query::select_all_purchases_by_added_date_gte_and_added_date_lte_order_by_added_date(
+            user_id, start_date, end_date,
+        );
+    fetch_all(pool, query).await
+}
+
 pub async fn create(
     pool: &PgPool,
     user_id: &str,
     dlc_id: &str,
     platform_id: &str,
-    added_date: NaiveDate,
+    availability: &Availability,
+) -> Result<(), RepositoryError> {
+    let query = dlc_available_query::insert(user_id, dlc_id, platform_id, availability);
+    execute(pool, query).await
+}
+
+pub async fn update_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+    platform_id: &str,
+    availability: &Availability,
 ) -> Result<(), RepositoryError> {
-    let query = dlc_available_query::insert(user_id, dlc_id, platform_id, added_date);
+    let query = dlc_available_query::update_by_id(user_id, dlc_id, platform_id, availability);
     execute(pool, query).await
 }
 
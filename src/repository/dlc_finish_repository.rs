@@ -1,10 +1,11 @@
 use chrono::NaiveDate;
 use sqlx::PgPool;
 
+use crate::entities::DLCFinishDate;
 use crate::errors::RepositoryError;
 use crate::query::dlc_finish_query;
 
-use super::base::{execute, execute_return_single, exists_id, fetch_all_single};
+use super::base::{execute, execute_return_single, exists_id, fetch_all, fetch_all_single};
 
 pub async fn find_first_by_dlc_id(
     pool: &PgPool,
@@ -24,6 +25,19 @@ pub async fn find_all_by_dlc_id(
     fetch_all_single(pool, query).await
 }
 
+pub async fn find_all_first_by_user_id_and_dlc_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_ids: Vec<String>,
+) -> Result<Vec<DLCFinishDate>, RepositoryError> {
+    if dlc_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = dlc_finish_query::select_all_first_by_user_id_and_dlc_id_in(user_id, dlc_ids);
+    fetch_all(pool, query).await
+}
+
 pub async fn create(
     pool: &PgPool,
     user_id: &str,
@@ -1,10 +1,14 @@
+use chrono::NaiveDateTime;
 use sqlx::PgPool;
 
 use crate::entities::{DLCSearch, PageResult, DLC};
 use crate::errors::{RepositoryError, SearchErrors};
 use crate::query::dlc_query;
 
-use super::base::{execute, exists_id, fetch_all, fetch_all_search, fetch_optional};
+use super::base::{
+    begin_transaction, commit_transaction, execute, exists_id, fetch_all, fetch_all_search,
+    fetch_optional,
+};
 
 pub async fn find_by_id(
     pool: &PgPool,
@@ -24,6 +28,39 @@ pub async fn find_all_by_base_game_id(
     fetch_all(pool, query).await
 }
 
+pub async fn find_all_by_base_game_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    base_game_ids: Vec<String>,
+) -> Result<Vec<DLC>, RepositoryError> {
+    if base_game_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = dlc_query::select_all_by_base_game_id_in(user_id, base_game_ids);
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_by_base_game_id_in_with_activity_between(
+    pool: &PgPool,
+    user_id: &str,
+    base_game_ids: Vec<String>,
+    start_datetime: NaiveDateTime,
+    end_datetime: NaiveDateTime,
+) -> Result<Vec<DLC>, RepositoryError> {
+    if base_game_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = dlc_query::select_all_by_base_game_id_in_with_activity_between(
+        user_id,
+        base_game_ids,
+        start_datetime,
+        end_datetime,
+    );
+    fetch_all(pool, query).await
+}
+
 pub async fn search_all(
     pool: &PgPool,
     user_id: &str,
@@ -36,8 +73,17 @@ pub async fn search_all(
 pub async fn create(pool: &PgPool, user_id: &str, dlc: &DLC) -> Result<String, RepositoryError> {
     let id = crate::uuid_utils::new_model_uuid();
 
+    let mut transaction = begin_transaction(pool).await?;
+
     let query = dlc_query::insert(user_id, &id, dlc);
-    execute(pool, query).await.map(|_| id)
+    execute(&mut *transaction, query).await?;
+
+    let user_info_query = dlc_query::insert_user_info(user_id, &id, dlc);
+    execute(&mut *transaction, user_info_query).await?;
+
+    commit_transaction(transaction).await?;
+
+    Ok(id)
 }
 
 pub async fn update_by_id(
@@ -46,8 +92,17 @@ pub async fn update_by_id(
     id: &str,
     dlc: &DLC,
 ) -> Result<(), RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
     let query = dlc_query::update_by_id(user_id, id, dlc);
-    execute(pool, query).await
+    execute(&mut *transaction, query).await?;
+
+    let user_info_query = dlc_query::update_user_info_by_id(user_id, id, dlc);
+    execute(&mut *transaction, user_info_query).await?;
+
+    commit_transaction(transaction).await?;
+
+    Ok(())
 }
 
 pub async fn update_base_game_id(
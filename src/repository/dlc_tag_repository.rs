@@ -0,0 +1,68 @@
+use sqlx::PgPool;
+
+use crate::entities::{Tag, TagWithDLCId, DLC};
+use crate::errors::RepositoryError;
+use crate::query::dlc_tag_query;
+
+use super::base::{execute, exists_id, fetch_all};
+
+pub async fn find_all_dlcs_with_tag(
+    pool: &PgPool,
+    user_id: &str,
+    tag_id: &str,
+) -> Result<Vec<DLC>, RepositoryError> {
+    let query = dlc_tag_query::select_all_dlcs_by_tag_id(user_id, tag_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_tags_with_dlc(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+) -> Result<Vec<Tag>, RepositoryError> {
+    let query = dlc_tag_query::select_all_tags_by_dlc_id(user_id, dlc_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_tags_with_dlc_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_ids: Vec<String>,
+) -> Result<Vec<TagWithDLCId>, RepositoryError> {
+    if dlc_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = dlc_tag_query::select_all_tags_by_dlc_id_in(user_id, dlc_ids);
+    fetch_all(pool, query).await
+}
+
+pub async fn create(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+    tag_id: &str,
+) -> Result<(), RepositoryError> {
+    let query = dlc_tag_query::insert(user_id, dlc_id, tag_id);
+    execute(pool, query).await
+}
+
+pub async fn delete_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+    tag_id: &str,
+) -> Result<(), RepositoryError> {
+    let query = dlc_tag_query::delete_by_id(user_id, dlc_id, tag_id);
+    execute(pool, query).await
+}
+
+pub async fn exists_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+    tag_id: &str,
+) -> Result<bool, RepositoryError> {
+    let query = dlc_tag_query::exists_by_id(user_id, dlc_id, tag_id);
+    exists_id(pool, query).await
+}
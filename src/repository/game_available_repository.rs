@@ -1,7 +1,10 @@
 use chrono::NaiveDate;
 use sqlx::PgPool;
 
-use crate::entities::{GameWithDate, PlatformWithDate};
+use crate::entities::{
+    Availability, GamePurchase, GameWithAvailabilityEnd, GameWithDate, PlatformWithDate,
+    PlatformWithGameIdAndDate,
+};
 use crate::errors::RepositoryError;
 use crate::query::game_available_query;
 
@@ -29,14 +32,66 @@ pub async fn find_all_platforms_with_game(
     fetch_all(pool, query).await
 }
 
+pub async fn find_all_platforms_with_game_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<PlatformWithGameIdAndDate>, RepositoryError> {
+    if game_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = game_available_query::select_all_platforms_by_game_id_in_order_by_added_date(
+        user_id, game_ids,
+    );
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_purchases_between(
+    pool: &PgPool,
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<GamePurchase>, RepositoryError> {
+    let query =
+        game_available_query::select_all_purchases_by_added_date_gte_and_added_date_lte_order_by_added_date(
+            user_id, start_date, end_date,
+        );
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_games_with_end_date_between(
+    pool: &PgPool,
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<GameWithAvailabilityEnd>, RepositoryError> {
+    let query =
+        game_available_query::select_all_games_by_end_date_gte_and_end_date_lte_order_by_end_date(
+            user_id, start_date, end_date,
+        );
+    fetch_all(pool, query).await
+}
+
 pub async fn create(
     pool: &PgPool,
     user_id: &str,
     game_id: &str,
     platform_id: &str,
-    added_date: NaiveDate,
+    availability: &Availability,
+) -> Result<(), RepositoryError> {
+    let query = game_available_query::insert(user_id, game_id, platform_id, availability);
+    execute(pool, query).await
+}
+
+pub async fn update_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    platform_id: &str,
+    availability: &Availability,
 ) -> Result<(), RepositoryError> {
-    let query = game_available_query::insert(user_id, game_id, platform_id, added_date);
+    let query = game_available_query::update_by_id(user_id, game_id, platform_id, availability);
     execute(pool, query).await
 }
 
@@ -0,0 +1,305 @@
+use std::collections::{HashMap, HashSet};
+
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::entities::{
+    Availability, Game, GameBulkOutcome, GameCustomValue, PlatformWithGameIdAndDate, TagWithGameId,
+};
+use crate::errors::RepositoryError;
+use crate::query::{game_available_query, game_query, game_status_history_query, game_tag_query};
+
+use super::base::{begin_transaction, commit_transaction, execute, fetch_all, fetch_all_single};
+use super::game_custom_value_repository;
+
+/// Applies the changes to every found game, skipping the ones that would break a constraint.
+/// Only the written games get the custom values.
+pub async fn update_multiple(
+    pool: &PgPool,
+    user_id: &str,
+    ids: &[String],
+    merge: impl Fn(Game) -> Game,
+    to_wishlist: bool,
+    custom_values: &[GameCustomValue],
+) -> Result<HashMap<Uuid, GameBulkOutcome>, RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let games = lock_games(&mut transaction, user_id, ids).await?;
+    let found_ids: Vec<String> = games.iter().map(|game| game.id.to_string()).collect();
+
+    let available_ids: HashSet<Uuid> = if to_wishlist && !found_ids.is_empty() {
+        let query = game_available_query::select_all_game_ids_by_game_id_in(user_id, found_ids);
+        fetch_all_single(&mut *transaction, query)
+            .await?
+            .into_iter()
+            .collect()
+    } else {
+        HashSet::new()
+    };
+
+    let mut outcomes = HashMap::new();
+    let mut merged_games = vec![];
+    for game in games {
+        if available_ids.contains(&game.id) {
+            outcomes.insert(game.id, GameBulkOutcome::Available);
+        } else {
+            // The merged game loses its id, so it is kept apart
+            merged_games.push((game.id, merge(game)));
+        }
+    }
+
+    // Games outside the batch, or the ones left unchanged, keep their name and edition
+    let name_editions: Vec<(String, String)> = merged_games
+        .iter()
+        .map(|(_, game)| (game.name.clone(), game.edition.clone()))
+        .collect();
+    let mut used_name_editions: HashSet<(String, String)> = if merged_games.is_empty() {
+        HashSet::new()
+    } else {
+        let merged_ids = merged_games.iter().map(|(id, _)| id.to_string()).collect();
+        let query = game_query::select_all_name_and_edition_by_name_and_edition_in_and_id_not_in(
+            user_id,
+            &name_editions,
+            merged_ids,
+        );
+        fetch_all(&mut *transaction, query)
+            .await?
+            .into_iter()
+            .collect()
+    };
+
+    let mut written_ids = vec![];
+    for ((game_id, game), name_edition) in merged_games.into_iter().zip(name_editions) {
+        // Games of the same batch cannot end up with the same name and edition either
+        if !used_name_editions.insert(name_edition) {
+            outcomes.insert(game_id, GameBulkOutcome::AlreadyExists);
+            continue;
+        }
+
+        let id = game_id.to_string();
+        let query = game_query::update_by_id(user_id, &id, &game);
+        execute(&mut *transaction, query).await?;
+
+        let status_history_query =
+            game_status_history_query::insert_if_status_changed(user_id, &id, game.status);
+        execute(&mut *transaction, status_history_query).await?;
+
+        let user_info_query = game_query::update_user_info_by_id(user_id, &id, &game);
+        execute(&mut *transaction, user_info_query).await?;
+
+        outcomes.insert(game_id, GameBulkOutcome::Written);
+        written_ids.push(id);
+    }
+
+    for game_id in &written_ids {
+        game_custom_value_repository::set_all(&mut transaction, user_id, game_id, custom_values)
+            .await?;
+    }
+
+    commit_transaction(transaction).await?;
+
+    Ok(outcomes)
+}
+
+/// Deletes every found game, returning them
+pub async fn delete_multiple(
+    pool: &PgPool,
+    user_id: &str,
+    ids: &[String],
+) -> Result<Vec<Game>, RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let games = lock_games(&mut transaction, user_id, ids).await?;
+    for game in &games {
+        let id = game.id.to_string();
+        let query = game_query::delete_by_id(user_id, &id);
+        execute(&mut *transaction, query).await?;
+
+        let user_info_query = game_query::delete_user_info_by_id(user_id, &id);
+        execute(&mut *transaction, user_info_query).await?;
+    }
+
+    commit_transaction(transaction).await?;
+
+    Ok(games)
+}
+
+/// Links the tags to every found game, returning their ids. Already linked tags are left as they are.
+pub async fn create_tags_multiple(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: &[String],
+    tag_ids: &[String],
+) -> Result<Vec<Uuid>, RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let found_ids = lock_game_ids(&mut transaction, user_id, game_ids).await?;
+    let found_game_ids: Vec<String> = found_ids.iter().map(Uuid::to_string).collect();
+
+    let existing_game_tags: HashSet<(Uuid, Uuid)> = if found_game_ids.is_empty() {
+        HashSet::new()
+    } else {
+        let query = game_tag_query::select_all_tags_by_game_id_in(user_id, found_game_ids);
+        fetch_all::<_, TagWithGameId>(&mut *transaction, query)
+            .await?
+            .into_iter()
+            .map(|tag| (tag.game_id, tag.id))
+            .collect()
+    };
+
+    for game_id in &found_ids {
+        for tag_id in tag_ids {
+            let already_linked = Uuid::parse_str(tag_id)
+                .is_ok_and(|tag_id| existing_game_tags.contains(&(*game_id, tag_id)));
+            if !already_linked {
+                let query = game_tag_query::insert(user_id, &game_id.to_string(), tag_id);
+                execute(&mut *transaction, query).await?;
+            }
+        }
+    }
+
+    commit_transaction(transaction).await?;
+
+    Ok(found_ids)
+}
+
+/// Unlinks the tags from every found game, returning their ids
+pub async fn delete_tags_multiple(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: &[String],
+    tag_ids: &[String],
+) -> Result<Vec<Uuid>, RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let found_ids = lock_game_ids(&mut transaction, user_id, game_ids).await?;
+    for game_id in &found_ids {
+        for tag_id in tag_ids {
+            let query = game_tag_query::delete_by_id(user_id, &game_id.to_string(), tag_id);
+            execute(&mut *transaction, query).await?;
+        }
+    }
+
+    commit_transaction(transaction).await?;
+
+    Ok(found_ids)
+}
+
+/// Makes the platforms available for every found game, returning their ids.
+/// Already available platforms are left as they are, and the games changed by `make_available`
+/// are updated too.
+pub async fn create_platforms_multiple(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: &[String],
+    platform_ids: &[String],
+    availability: &Availability,
+    make_available: impl Fn(Game) -> Option<Game>,
+) -> Result<Vec<Uuid>, RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let games = lock_games(&mut transaction, user_id, game_ids).await?;
+    let found_ids: Vec<Uuid> = games.iter().map(|game| game.id).collect();
+    let found_game_ids: Vec<String> = found_ids.iter().map(Uuid::to_string).collect();
+
+    let existing_game_platforms: HashSet<(Uuid, Uuid)> = if found_game_ids.is_empty() {
+        HashSet::new()
+    } else {
+        let query = game_available_query::select_all_platforms_by_game_id_in_order_by_added_date(
+            user_id,
+            found_game_ids,
+        );
+        fetch_all::<_, PlatformWithGameIdAndDate>(&mut *transaction, query)
+            .await?
+            .into_iter()
+            .map(|platform| (platform.game_id, platform.id))
+            .collect()
+    };
+
+    // The changed games are matched by their original id
+    let available_games = games.into_iter().filter_map(|game| {
+        let id = game.id.to_string();
+        make_available(game).map(|game| (id, game))
+    });
+    for (id, game) in available_games {
+        let status_history_query =
+            game_status_history_query::insert_if_status_changed(user_id, &id, game.status);
+        execute(&mut *transaction, status_history_query).await?;
+
+        let user_info_query = game_query::update_user_info_by_id(user_id, &id, &game);
+        execute(&mut *transaction, user_info_query).await?;
+    }
+
+    for game_id in &found_ids {
+        for platform_id in platform_ids {
+            let already_available = Uuid::parse_str(platform_id).is_ok_and(|platform_id| {
+                existing_game_platforms.contains(&(*game_id, platform_id))
+            });
+            if !already_available {
+                let query = game_available_query::insert(
+                    user_id,
+                    &game_id.to_string(),
+                    platform_id,
+                    availability,
+                );
+                execute(&mut *transaction, query).await?;
+            }
+        }
+    }
+
+    commit_transaction(transaction).await?;
+
+    Ok(found_ids)
+}
+
+/// Removes the platforms from every found game, returning their ids
+pub async fn delete_platforms_multiple(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: &[String],
+    platform_ids: &[String],
+) -> Result<Vec<Uuid>, RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let found_ids = lock_game_ids(&mut transaction, user_id, game_ids).await?;
+    for game_id in &found_ids {
+        for platform_id in platform_ids {
+            let query =
+                game_available_query::delete_by_id(user_id, &game_id.to_string(), platform_id);
+            execute(&mut *transaction, query).await?;
+        }
+    }
+
+    commit_transaction(transaction).await?;
+
+    Ok(found_ids)
+}
+
+/// Existing games from the ids, which cannot change until the transaction ends
+async fn lock_game_ids(
+    transaction: &mut Transaction<'_, Postgres>,
+    user_id: &str,
+    ids: &[String],
+) -> Result<Vec<Uuid>, RepositoryError> {
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = game_query::select_all_ids_by_id_in_for_update(user_id, ids.to_vec());
+    fetch_all_single(&mut **transaction, query).await
+}
+
+async fn lock_games(
+    transaction: &mut Transaction<'_, Postgres>,
+    user_id: &str,
+    ids: &[String],
+) -> Result<Vec<Game>, RepositoryError> {
+    let found_ids = lock_game_ids(transaction, user_id, ids).await?;
+    if found_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query =
+        game_query::select_all_by_id_in(user_id, found_ids.iter().map(Uuid::to_string).collect());
+    fetch_all(&mut **transaction, query).await
+}
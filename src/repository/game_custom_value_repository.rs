@@ -0,0 +1,42 @@
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::entities::{GameCustomValue, GameCustomValueWithField};
+use crate::errors::RepositoryError;
+use crate::query::game_custom_value_query;
+
+use super::base::{execute, fetch_all};
+
+pub async fn find_all_with_game_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameCustomValueWithField>, RepositoryError> {
+    let query = game_custom_value_query::select_all_by_game_id_in(user_id, game_ids);
+    fetch_all(pool, query).await
+}
+
+/// Replaces the values of the fields in the game within the transaction of its write,
+/// removing the empty ones
+pub(super) async fn set_all(
+    transaction: &mut Transaction<'_, Postgres>,
+    user_id: &str,
+    game_id: &str,
+    custom_values: &[GameCustomValue],
+) -> Result<(), RepositoryError> {
+    for custom_value in custom_values {
+        let delete_query =
+            game_custom_value_query::delete_by_id(user_id, game_id, &custom_value.field_id);
+        execute(&mut **transaction, delete_query).await?;
+
+        if custom_value.text_value.is_some()
+            || custom_value.number_value.is_some()
+            || custom_value.date_value.is_some()
+            || custom_value.bool_value.is_some()
+        {
+            let insert_query = game_custom_value_query::insert(user_id, game_id, custom_value);
+            execute(&mut **transaction, insert_query).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -25,6 +25,19 @@ pub async fn find_all_by_game_id(
     fetch_all_single(pool, query).await
 }
 
+pub async fn find_all_by_user_id_and_game_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameFinish>, RepositoryError> {
+    if game_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = game_finish_query::select_all_by_user_id_and_game_id_in(user_id, game_ids);
+    fetch_all(pool, query).await
+}
+
 pub async fn find_all_first_by_user_id_and_game_id_in(
     pool: &PgPool,
     user_id: &str,
@@ -1,7 +1,7 @@
 use chrono::NaiveDateTime;
 use sqlx::{postgres::types::PgInterval, PgPool};
 
-use crate::entities::{GameLog, GameLogWithTime};
+use crate::entities::{GameLog, GameLogTotalTime, GameLogWithTime};
 use crate::errors::RepositoryError;
 use crate::query::game_log_query;
 
@@ -18,6 +18,19 @@ pub async fn find_sum_time_by_game_id(
     execute_return_single(pool, query).await
 }
 
+pub async fn find_sum_time_by_game_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameLogTotalTime>, RepositoryError> {
+    if game_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = game_log_query::select_sum_time_by_user_id_and_game_id_in(user_id, game_ids);
+    fetch_all(pool, query).await
+}
+
 pub async fn find_all_by_game_id(
     pool: &PgPool,
     user_id: &str,
@@ -40,6 +53,19 @@ pub async fn find_all_first_by_user_id_and_game_id_in(
     fetch_all(pool, query).await
 }
 
+pub async fn find_all_first_sessions_by_game_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameLogWithTime>, RepositoryError> {
+    if game_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = game_log_query::select_all_first_sessions_by_game_id_in(user_id, game_ids);
+    fetch_all(pool, query).await
+}
+
 pub async fn create_multiple(
     pool: &PgPool,
     user_id: &str,
@@ -0,0 +1,81 @@
+use sqlx::PgPool;
+
+use crate::entities::{GameRelationshipIds, GameWithRelationship};
+use crate::errors::RepositoryError;
+use crate::query::game_relationship_query;
+
+use super::base::{execute, exists_id, fetch_all};
+
+pub async fn find_all_related_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+) -> Result<Vec<GameWithRelationship>, RepositoryError> {
+    let query = game_relationship_query::select_all_related_games_by_game_id(user_id, game_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_relating_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+) -> Result<Vec<GameWithRelationship>, RepositoryError> {
+    let query = game_relationship_query::select_all_relating_games_by_game_id(user_id, game_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_with_game_id_in_and_type(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+    rtype: i16,
+) -> Result<Vec<GameRelationshipIds>, RepositoryError> {
+    if game_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query =
+        game_relationship_query::select_all_by_game_id_in_and_type(user_id, game_ids, rtype);
+    fetch_all(pool, query).await
+}
+
+pub async fn create(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    related_game_id: &str,
+    rtype: i16,
+) -> Result<(), RepositoryError> {
+    let query = game_relationship_query::insert(user_id, game_id, related_game_id, rtype);
+    execute(pool, query).await
+}
+
+pub async fn delete_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    related_game_id: &str,
+) -> Result<(), RepositoryError> {
+    let query = game_relationship_query::delete_by_id(user_id, game_id, related_game_id);
+    execute(pool, query).await
+}
+
+pub async fn exists_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    related_game_id: &str,
+) -> Result<bool, RepositoryError> {
+    let query = game_relationship_query::exists_by_id(user_id, game_id, related_game_id);
+    exists_id(pool, query).await
+}
+
+pub async fn exists_between_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    other_game_id: &str,
+) -> Result<bool, RepositoryError> {
+    let query = game_relationship_query::exists_between_games(user_id, game_id, other_game_id);
+    exists_id(pool, query).await
+}
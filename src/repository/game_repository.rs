@@ -1,12 +1,19 @@
 use sqlx::PgPool;
+use uuid::Uuid;
 
-use crate::entities::{Game, GameSearch, PageResult};
+use crate::entities::{Game, GameCustomValue, GameSearch, PageResult};
 use crate::errors::{RepositoryError, SearchErrors};
-use crate::query::game_query;
+use crate::query::{
+    dlc_query, game_available_query, game_custom_value_query, game_finish_query, game_log_query,
+    game_query, game_relationship_query, game_series_query, game_status_history_query,
+    game_tag_query,
+};
 
 use super::base::{
-    begin_transaction, commit_transaction, execute, exists_id, fetch_all_search, fetch_optional,
+    begin_transaction, commit_transaction, execute, exists_id, fetch_all, fetch_all_search,
+    fetch_all_single, fetch_optional,
 };
+use super::game_custom_value_repository;
 
 pub async fn find_by_id(
     pool: &PgPool,
@@ -17,6 +24,19 @@ pub async fn find_by_id(
     fetch_optional(pool, query).await
 }
 
+pub async fn find_all_with_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    ids: Vec<String>,
+) -> Result<Vec<Game>, RepositoryError> {
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = game_query::select_all_by_id_in(user_id, ids);
+    fetch_all(pool, query).await
+}
+
 pub async fn search_all(
     pool: &PgPool,
     user_id: &str,
@@ -26,7 +46,23 @@ pub async fn search_all(
     fetch_all_search(pool, search_query).await
 }
 
-pub async fn create(pool: &PgPool, user_id: &str, game: &Game) -> Result<String, RepositoryError> {
+pub async fn find_all_ids_with_search(
+    pool: &PgPool,
+    user_id: &str,
+    search: GameSearch,
+) -> Result<Vec<Uuid>, SearchErrors> {
+    let query = game_query::select_all_ids_with_search(user_id, search)?;
+    fetch_all_single(pool, query)
+        .await
+        .map_err(SearchErrors::Repository)
+}
+
+pub async fn create(
+    pool: &PgPool,
+    user_id: &str,
+    game: &Game,
+    custom_values: &[GameCustomValue],
+) -> Result<String, RepositoryError> {
     let id = crate::uuid_utils::new_model_uuid();
 
     let mut transaction = begin_transaction(pool).await?;
@@ -37,6 +73,11 @@ pub async fn create(pool: &PgPool, user_id: &str, game: &Game) -> Result<String,
     let user_info_query = game_query::insert_user_info(user_id, &id, game);
     execute(&mut *transaction, user_info_query).await?;
 
+    let status_history_query = game_status_history_query::insert(user_id, &id, game.status);
+    execute(&mut *transaction, status_history_query).await?;
+
+    game_custom_value_repository::set_all(&mut transaction, user_id, &id, custom_values).await?;
+
     commit_transaction(transaction).await?;
 
     Ok(id)
@@ -47,15 +88,98 @@ pub async fn update_by_id(
     user_id: &str,
     id: &str,
     game: &Game,
+    custom_values: &[GameCustomValue],
+) -> Result<(), RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let query = game_query::update_by_id(user_id, id, game);
+    execute(&mut *transaction, query).await?;
+
+    let status_history_query =
+        game_status_history_query::insert_if_status_changed(user_id, id, game.status);
+    execute(&mut *transaction, status_history_query).await?;
+
+    let user_info_query = game_query::update_user_info_by_id(user_id, id, game);
+    execute(&mut *transaction, user_info_query).await?;
+
+    game_custom_value_repository::set_all(&mut transaction, user_id, id, custom_values).await?;
+
+    commit_transaction(transaction).await?;
+
+    Ok(())
+}
+
+/// Moves everything related to the other game into the game, then deletes the other game.
+/// Related rows colliding with existing ones of the game are dropped with the other game,
+/// except for logs where the longer session is kept.
+pub async fn merge_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    id: &str,
+    other_id: &str,
+    game: &Game,
 ) -> Result<(), RepositoryError> {
     let mut transaction = begin_transaction(pool).await?;
 
+    let longer_logs_query =
+        game_log_query::update_end_datetime_by_game_id_with_longer(user_id, other_id, id);
+    execute(&mut *transaction, longer_logs_query).await?;
+
+    let logs_query = game_log_query::update_game_id_by_game_id(user_id, other_id, id);
+    execute(&mut *transaction, logs_query).await?;
+
+    let finishes_query = game_finish_query::update_game_id_by_game_id(user_id, other_id, id);
+    execute(&mut *transaction, finishes_query).await?;
+
+    let tags_query = game_tag_query::update_game_id_by_game_id(user_id, other_id, id);
+    execute(&mut *transaction, tags_query).await?;
+
+    let platforms_query = game_available_query::update_game_id_by_game_id(user_id, other_id, id);
+    execute(&mut *transaction, platforms_query).await?;
+
+    // Relationships between both games would end up pointing to the same one
+    let relationships_between_query =
+        game_relationship_query::delete_between_games(user_id, id, other_id);
+    execute(&mut *transaction, relationships_between_query).await?;
+
+    let relationships_query =
+        game_relationship_query::update_game_id_by_game_id(user_id, other_id, id);
+    execute(&mut *transaction, relationships_query).await?;
+
+    let related_relationships_query =
+        game_relationship_query::update_related_game_id_by_related_game_id(user_id, other_id, id);
+    execute(&mut *transaction, related_relationships_query).await?;
+
+    let series_links_query = game_series_query::update_game_id_by_game_id(user_id, other_id, id);
+    execute(&mut *transaction, series_links_query).await?;
+
+    let custom_values_query =
+        game_custom_value_query::update_game_id_by_game_id(user_id, other_id, id);
+    execute(&mut *transaction, custom_values_query).await?;
+
+    let status_history_move_query =
+        game_status_history_query::update_game_id_by_game_id(user_id, other_id, id);
+    execute(&mut *transaction, status_history_move_query).await?;
+
+    let dlcs_query = dlc_query::update_base_game_id_by_base_game_id(user_id, other_id, id);
+    execute(&mut *transaction, dlcs_query).await?;
+
     let query = game_query::update_by_id(user_id, id, game);
     execute(&mut *transaction, query).await?;
 
+    let status_history_query =
+        game_status_history_query::insert_if_status_changed(user_id, id, game.status);
+    execute(&mut *transaction, status_history_query).await?;
+
     let user_info_query = game_query::update_user_info_by_id(user_id, id, game);
     execute(&mut *transaction, user_info_query).await?;
 
+    let delete_query = game_query::delete_by_id(user_id, other_id);
+    execute(&mut *transaction, delete_query).await?;
+
+    let delete_user_info_query = game_query::delete_user_info_by_id(user_id, other_id);
+    execute(&mut *transaction, delete_user_info_query).await?;
+
     commit_transaction(transaction).await?;
 
     Ok(())
@@ -0,0 +1,68 @@
+use sqlx::PgPool;
+
+use crate::entities::{GameWithPosition, Series};
+use crate::errors::RepositoryError;
+use crate::query::game_series_query;
+
+use super::base::{execute, exists_id, fetch_all};
+
+pub async fn find_all_games_with_series(
+    pool: &PgPool,
+    user_id: &str,
+    series_id: &str,
+) -> Result<Vec<GameWithPosition>, RepositoryError> {
+    let query =
+        game_series_query::select_all_games_by_series_id_order_by_position(user_id, series_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_series_with_game(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+) -> Result<Vec<Series>, RepositoryError> {
+    let query = game_series_query::select_all_series_by_game_id(user_id, game_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn create(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    series_id: &str,
+    position: i32,
+) -> Result<(), RepositoryError> {
+    let query = game_series_query::insert(user_id, game_id, series_id, position);
+    execute(pool, query).await
+}
+
+pub async fn update_position_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    series_id: &str,
+    position: i32,
+) -> Result<(), RepositoryError> {
+    let query = game_series_query::update_position_by_id(user_id, game_id, series_id, position);
+    execute(pool, query).await
+}
+
+pub async fn delete_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    series_id: &str,
+) -> Result<(), RepositoryError> {
+    let query = game_series_query::delete_by_id(user_id, game_id, series_id);
+    execute(pool, query).await
+}
+
+pub async fn exists_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    series_id: &str,
+) -> Result<bool, RepositoryError> {
+    let query = game_series_query::exists_by_id(user_id, game_id, series_id);
+    exists_id(pool, query).await
+}
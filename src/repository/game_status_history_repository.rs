@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+use crate::entities::{GameStatusChange, GameWithStatusChange};
+use crate::errors::RepositoryError;
+use crate::query::game_status_history_query;
+
+use super::base::fetch_all;
+
+pub async fn find_all_by_game_id(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+) -> Result<Vec<GameStatusChange>, RepositoryError> {
+    let query =
+        game_status_history_query::select_all_by_game_id_order_by_datetime(user_id, game_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_games_by_datetime_between(
+    pool: &PgPool,
+    user_id: &str,
+    start_datetime: NaiveDateTime,
+    end_datetime: NaiveDateTime,
+) -> Result<Vec<GameWithStatusChange>, RepositoryError> {
+    let query =
+        game_status_history_query::select_all_games_by_datetime_gte_and_datetime_lte_order_by_datetime(
+            user_id,
+            start_datetime,
+            end_datetime,
+        );
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_first_by_status_and_game_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    status: i16,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameStatusChange>, RepositoryError> {
+    if game_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = game_status_history_query::select_all_first_by_status_and_game_id_in(
+        user_id, status, game_ids,
+    );
+    fetch_all(pool, query).await
+}
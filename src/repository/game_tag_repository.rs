@@ -1,6 +1,6 @@
 use sqlx::PgPool;
 
-use crate::entities::{Game, Tag};
+use crate::entities::{Game, Tag, TagWithGameId};
 use crate::errors::RepositoryError;
 use crate::query::game_tag_query;
 
@@ -24,6 +24,19 @@ pub async fn find_all_tags_with_game(
     fetch_all(pool, query).await
 }
 
+pub async fn find_all_tags_with_game_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<TagWithGameId>, RepositoryError> {
+    if game_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = game_tag_query::select_all_tags_by_game_id_in(user_id, game_ids);
+    fetch_all(pool, query).await
+}
+
 pub async fn create(
     pool: &PgPool,
     user_id: &str,
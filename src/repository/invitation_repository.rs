@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+use crate::entities::Invitation;
+use crate::errors::RepositoryError;
+use crate::query::invitation_query;
+
+use super::base::{execute, exists_id, fetch_all, fetch_optional};
+
+pub async fn find_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    id: &str,
+) -> Result<Option<Invitation>, RepositoryError> {
+    let query = invitation_query::select_by_id(user_id, id);
+    fetch_optional(pool, query).await
+}
+
+pub async fn find_all(pool: &PgPool, user_id: &str) -> Result<Vec<Invitation>, RepositoryError> {
+    let query = invitation_query::select_all(user_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn create(
+    pool: &PgPool,
+    user_id: &str,
+    code: &str,
+    expiry_datetime: NaiveDateTime,
+) -> Result<String, RepositoryError> {
+    let id = crate::uuid_utils::new_model_uuid();
+
+    let query = invitation_query::insert(user_id, &id, code, expiry_datetime);
+    execute(pool, query).await.map(|_| id)
+}
+
+pub async fn delete_by_id(pool: &PgPool, user_id: &str, id: &str) -> Result<(), RepositoryError> {
+    let query = invitation_query::delete_by_id(user_id, id);
+    execute(pool, query).await
+}
+
+pub async fn exists_by_id(pool: &PgPool, user_id: &str, id: &str) -> Result<bool, RepositoryError> {
+    let query = invitation_query::exists_by_id(user_id, id);
+    exists_id(pool, query).await
+}
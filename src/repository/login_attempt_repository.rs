@@ -0,0 +1,52 @@
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+use crate::entities::LoginAttempt;
+use crate::errors::RepositoryError;
+use crate::query::login_attempt_query;
+
+use super::base::{execute, execute_return_single, fetch_optional};
+
+pub async fn find_by_source(
+    pool: &PgPool,
+    source_type: i16,
+    source: &str,
+) -> Result<Option<LoginAttempt>, RepositoryError> {
+    let query = login_attempt_query::select_by_source(source_type, source);
+    fetch_optional(pool, query).await
+}
+
+pub async fn increment_failed_count(
+    pool: &PgPool,
+    source_type: i16,
+    source: &str,
+    now: NaiveDateTime,
+    window_start: NaiveDateTime,
+) -> Result<i32, RepositoryError> {
+    let query =
+        login_attempt_query::upsert_increment_failed_count(source_type, source, now, window_start);
+    execute_return_single(pool, query).await
+}
+
+pub async fn update_locked_until_by_source(
+    pool: &PgPool,
+    source_type: i16,
+    source: &str,
+    locked_until_datetime: NaiveDateTime,
+) -> Result<(), RepositoryError> {
+    let query = login_attempt_query::update_locked_until_by_source(
+        source_type,
+        source,
+        locked_until_datetime,
+    );
+    execute(pool, query).await
+}
+
+pub async fn delete_by_source(
+    pool: &PgPool,
+    source_type: i16,
+    source: &str,
+) -> Result<(), RepositoryError> {
+    let query = login_attempt_query::delete_by_source(source_type, source);
+    execute(pool, query).await
+}
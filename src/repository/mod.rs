@@ -1,15 +1,27 @@
 mod base;
+pub mod custom_field_repository;
 pub mod dlc_available_repository;
 pub mod dlc_finish_repository;
 pub mod dlc_repository;
+pub mod dlc_tag_repository;
 pub mod dlc_with_finish_repository;
 pub mod game_available_repository;
+pub mod game_bulk_repository;
+pub mod game_custom_value_repository;
 pub mod game_finish_repository;
 pub mod game_log_repository;
+pub mod game_relationship_repository;
 pub mod game_repository;
+pub mod game_series_repository;
+pub mod game_status_history_repository;
 pub mod game_tag_repository;
 pub mod game_with_finish_repository;
 pub mod game_with_log_repository;
+pub mod invitation_repository;
+pub mod login_attempt_repository;
 pub mod platform_repository;
+pub mod saved_search_repository;
+pub mod series_repository;
 pub mod tag_repository;
 pub mod user_repository;
+pub mod user_totp_repository;
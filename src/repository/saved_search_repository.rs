@@ -0,0 +1,84 @@
+use sqlx::PgPool;
+
+use crate::entities::{PageResult, SavedSearch, SavedSearchSearch};
+use crate::errors::{RepositoryError, SearchErrors};
+use crate::query::saved_search_query;
+
+use super::base::{execute, exists_id, fetch_all_search, fetch_optional};
+
+pub async fn find_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    id: &str,
+) -> Result<Option<SavedSearch>, RepositoryError> {
+    let query = saved_search_query::select_by_id(user_id, id);
+    fetch_optional(pool, query).await
+}
+
+pub async fn search_all(
+    pool: &PgPool,
+    user_id: &str,
+    search: SavedSearchSearch,
+) -> Result<PageResult<SavedSearch>, SearchErrors> {
+    let search_query = saved_search_query::select_all_with_search(user_id, search)?;
+    fetch_all_search(pool, search_query).await
+}
+
+pub async fn create(
+    pool: &PgPool,
+    user_id: &str,
+    saved_search: &SavedSearch,
+) -> Result<String, RepositoryError> {
+    let id = crate::uuid_utils::new_model_uuid();
+
+    let query = saved_search_query::insert(user_id, &id, saved_search);
+    execute(pool, query).await.map(|_| id)
+}
+
+pub async fn update_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    id: &str,
+    saved_search: &SavedSearch,
+) -> Result<(), RepositoryError> {
+    let query = saved_search_query::update_by_id(user_id, id, saved_search);
+    execute(pool, query).await
+}
+
+pub async fn delete_by_id(pool: &PgPool, user_id: &str, id: &str) -> Result<(), RepositoryError> {
+    let query = saved_search_query::delete_by_id(user_id, id);
+    execute(pool, query).await
+}
+
+pub async fn exists_by_id(pool: &PgPool, user_id: &str, id: &str) -> Result<bool, RepositoryError> {
+    let query = saved_search_query::exists_by_id(user_id, id);
+    exists_id(pool, query).await
+}
+
+pub async fn exists_with_unique(
+    pool: &PgPool,
+    user_id: &str,
+    saved_search: &SavedSearch,
+) -> Result<bool, RepositoryError> {
+    let query = saved_search_query::exists_by_name_and_type(
+        user_id,
+        &saved_search.name,
+        saved_search.stype,
+    );
+    exists_id(pool, query).await
+}
+
+pub async fn exists_with_unique_except_id(
+    pool: &PgPool,
+    user_id: &str,
+    saved_search: &SavedSearch,
+    excluded_id: &str,
+) -> Result<bool, RepositoryError> {
+    let query = saved_search_query::exists_by_name_and_type_and_id_not(
+        user_id,
+        &saved_search.name,
+        saved_search.stype,
+        excluded_id,
+    );
+    exists_id(pool, query).await
+}
@@ -0,0 +1,85 @@
+use sqlx::PgPool;
+
+use crate::entities::{PageResult, Series, SeriesSearch};
+use crate::errors::{RepositoryError, SearchErrors};
+use crate::query::series_query;
+
+use super::base::{execute, exists_id, fetch_all_search, fetch_optional};
+
+pub async fn find_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    id: &str,
+) -> Result<Option<Series>, RepositoryError> {
+    let query = series_query::select_by_id(user_id, id);
+    fetch_optional(pool, query).await
+}
+
+pub async fn search_all(
+    pool: &PgPool,
+    user_id: &str,
+    search: SeriesSearch,
+) -> Result<PageResult<Series>, SearchErrors> {
+    let search_query = series_query::select_all_with_query(user_id, search)?;
+    fetch_all_search(pool, search_query).await
+}
+
+pub async fn create(
+    pool: &PgPool,
+    user_id: &str,
+    series: &Series,
+) -> Result<String, RepositoryError> {
+    let id = crate::uuid_utils::new_model_uuid();
+
+    let query = series_query::insert(user_id, &id, series);
+    execute(pool, query).await.map(|_| id)
+}
+
+pub async fn update_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    id: &str,
+    series: &Series,
+) -> Result<(), RepositoryError> {
+    let query = series_query::update_by_id(user_id, id, series);
+    execute(pool, query).await
+}
+
+pub async fn update_image_filename_by_id(
+    pool: &PgPool,
+    user_id: &str,
+    id: &str,
+    image_filename: Option<String>,
+) -> Result<(), RepositoryError> {
+    let query = series_query::update_image_filename_by_id(user_id, id, image_filename);
+    execute(pool, query).await
+}
+
+pub async fn delete_by_id(pool: &PgPool, user_id: &str, id: &str) -> Result<(), RepositoryError> {
+    let query = series_query::delete_by_id(user_id, id);
+    execute(pool, query).await
+}
+
+pub async fn exists_by_id(pool: &PgPool, user_id: &str, id: &str) -> Result<bool, RepositoryError> {
+    let query = series_query::exists_by_id(user_id, id);
+    exists_id(pool, query).await
+}
+
+pub async fn exists_with_unique(
+    pool: &PgPool,
+    user_id: &str,
+    series: &Series,
+) -> Result<bool, RepositoryError> {
+    let query = series_query::exists_by_name(user_id, &series.name);
+    exists_id(pool, query).await
+}
+
+pub async fn exists_with_unique_except_id(
+    pool: &PgPool,
+    user_id: &str,
+    series: &Series,
+    excluded_id: &str,
+) -> Result<bool, RepositoryError> {
+    let query = series_query::exists_by_name_and_id_not(user_id, &series.name, excluded_id);
+    exists_id(pool, query).await
+}
@@ -1,10 +1,10 @@
 use sqlx::PgPool;
 
-use crate::entities::{PageResult, Tag, TagSearch};
+use crate::entities::{PageResult, Tag, TagDescendant, TagSearch};
 use crate::errors::{RepositoryError, SearchErrors};
 use crate::query::tag_query;
 
-use super::base::{execute, exists_id, fetch_all_search, fetch_optional};
+use super::base::{execute, exists_id, fetch_all, fetch_all_search, fetch_optional};
 
 pub async fn find_by_id(
     pool: &PgPool,
@@ -24,6 +24,24 @@ pub async fn search_all(
     fetch_all_search(pool, search_query).await
 }
 
+pub async fn find_all(pool: &PgPool, user_id: &str) -> Result<Vec<Tag>, RepositoryError> {
+    let query = tag_query::select_all_order_by_name(user_id);
+    fetch_all(pool, query).await
+}
+
+pub async fn find_all_descendants_with_id_in(
+    pool: &PgPool,
+    user_id: &str,
+    ids: Vec<String>,
+) -> Result<Vec<TagDescendant>, RepositoryError> {
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let query = tag_query::select_all_descendants_by_id_in(user_id, ids);
+    fetch_all(pool, query).await
+}
+
 pub async fn create(pool: &PgPool, user_id: &str, tag: &Tag) -> Result<String, RepositoryError> {
     let id = crate::uuid_utils::new_model_uuid();
 
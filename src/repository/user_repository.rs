@@ -1,10 +1,14 @@
 use sqlx::PgPool;
+use uuid::Uuid;
 
 use crate::entities::{PageResult, User, UserSearch};
 use crate::errors::{RepositoryError, SearchErrors};
-use crate::query::user_query;
+use crate::query::{invitation_query, user_query};
 
-use super::base::{execute, exists_id, fetch_all_search, fetch_optional};
+use super::base::{
+    begin_transaction, commit_transaction, execute, exists_id, fetch_all_search, fetch_optional,
+    fetch_optional_single,
+};
 
 pub async fn find_by_id(pool: &PgPool, id: &str) -> Result<Option<User>, RepositoryError> {
     let query = user_query::select_by_id(id);
@@ -27,13 +31,46 @@ pub async fn search_all(
     fetch_all_search(pool, search_query).await
 }
 
-pub async fn create(pool: &PgPool, password: &str, user: &User) -> Result<String, RepositoryError> {
+pub async fn create(
+    pool: &PgPool,
+    password: &str,
+    must_change_password: bool,
+    user: &User,
+) -> Result<String, RepositoryError> {
     let id = crate::uuid_utils::new_model_uuid();
 
-    let query = user_query::insert(&id, password, user);
+    let query = user_query::insert(&id, password, must_change_password, user);
     execute(pool, query).await.map(|_| id)
 }
 
+/// Creates the user redeeming the invitation code.
+/// Returns None (and nothing is created) if the code is not valid anymore.
+pub async fn create_with_invitation(
+    pool: &PgPool,
+    password: &str,
+    user: &User,
+    invitation_code: &str,
+) -> Result<Option<String>, RepositoryError> {
+    let id = crate::uuid_utils::new_model_uuid();
+
+    let mut transaction = begin_transaction(pool).await?;
+
+    let query = user_query::insert(&id, password, false, user);
+    execute(&mut *transaction, query).await?;
+
+    let invitation_query = invitation_query::update_used_by_code(invitation_code, &id);
+    let invitation_id: Option<Uuid> =
+        fetch_optional_single(&mut *transaction, invitation_query).await?;
+    if invitation_id.is_none() {
+        // Dropping the transaction rolls back the user creation
+        return Ok(None);
+    }
+
+    commit_transaction(transaction).await?;
+
+    Ok(Some(id))
+}
+
 pub async fn update_by_id(pool: &PgPool, id: &str, user: &User) -> Result<(), RepositoryError> {
     let query = user_query::update_by_id(id, user);
     execute(pool, query).await
@@ -43,8 +80,18 @@ pub async fn update_password(
     pool: &PgPool,
     id: &str,
     password: &str,
+    must_change_password: bool,
+) -> Result<(), RepositoryError> {
+    let query = user_query::update_password_by_id(id, password, must_change_password);
+    execute(pool, query).await
+}
+
+pub async fn update_password_hash(
+    pool: &PgPool,
+    id: &str,
+    password: &str,
 ) -> Result<(), RepositoryError> {
-    let query = user_query::update_password_by_id(id, password);
+    let query = user_query::update_password_hash_by_id(id, password);
     execute(pool, query).await
 }
 
@@ -0,0 +1,126 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::entities::UserTotp;
+use crate::errors::RepositoryError;
+use crate::query::user_totp_query;
+
+use super::base::{
+    begin_transaction, commit_transaction, execute, fetch_optional, fetch_optional_single,
+};
+
+pub async fn find_by_user_id(
+    pool: &PgPool,
+    user_id: &str,
+) -> Result<Option<UserTotp>, RepositoryError> {
+    let query = user_totp_query::select_by_user_id(user_id);
+    fetch_optional(pool, query).await
+}
+
+/// Returns whether it was created, which does not happen if it is already enabled
+pub async fn create(pool: &PgPool, user_id: &str, secret: &str) -> Result<bool, RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let enabled_query = user_totp_query::select_enabled_by_user_id_for_update(user_id);
+    let enabled: Option<bool> = fetch_optional_single(&mut *transaction, enabled_query).await?;
+    if enabled == Some(true) {
+        return Ok(false);
+    }
+
+    // Replace any previous pending enrollment
+    let delete_query = user_totp_query::delete_by_user_id(user_id);
+    execute(&mut *transaction, delete_query).await?;
+
+    let query = user_totp_query::insert(user_id, secret);
+    execute(&mut *transaction, query).await?;
+
+    commit_transaction(transaction).await?;
+
+    Ok(true)
+}
+
+pub async fn update_enabled(
+    pool: &PgPool,
+    user_id: &str,
+    last_used_step: i64,
+    recovery_code_hashes: &[String],
+) -> Result<(), RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let query = user_totp_query::update_enabled_by_user_id(user_id, true, last_used_step);
+    execute(&mut *transaction, query).await?;
+
+    let delete_codes_query = user_totp_query::delete_recovery_codes_by_user_id(user_id);
+    execute(&mut *transaction, delete_codes_query).await?;
+
+    let codes_query = user_totp_query::insert_recovery_codes(user_id, recovery_code_hashes);
+    execute(&mut *transaction, codes_query).await?;
+
+    commit_transaction(transaction).await?;
+
+    Ok(())
+}
+
+/// Returns whether the step was newer than the last used one
+pub async fn update_last_used_step(
+    pool: &PgPool,
+    user_id: &str,
+    last_used_step: i64,
+) -> Result<bool, RepositoryError> {
+    let query = user_totp_query::update_last_used_step_by_user_id(user_id, last_used_step);
+    let updated_user_id: Option<Uuid> = fetch_optional_single(pool, query).await?;
+    Ok(updated_user_id.is_some())
+}
+
+/// Returns whether the codes were replaced, which only happens if the step was newer than the
+/// last used one
+pub async fn replace_recovery_codes(
+    pool: &PgPool,
+    user_id: &str,
+    last_used_step: i64,
+    recovery_code_hashes: &[String],
+) -> Result<bool, RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let query = user_totp_query::update_last_used_step_by_user_id(user_id, last_used_step);
+    let updated_user_id: Option<Uuid> = fetch_optional_single(&mut *transaction, query).await?;
+    if updated_user_id.is_none() {
+        // Dropping the transaction rolls back
+        return Ok(false);
+    }
+
+    let delete_codes_query = user_totp_query::delete_recovery_codes_by_user_id(user_id);
+    execute(&mut *transaction, delete_codes_query).await?;
+
+    let codes_query = user_totp_query::insert_recovery_codes(user_id, recovery_code_hashes);
+    execute(&mut *transaction, codes_query).await?;
+
+    commit_transaction(transaction).await?;
+
+    Ok(true)
+}
+
+pub async fn delete_by_user_id(pool: &PgPool, user_id: &str) -> Result<(), RepositoryError> {
+    let mut transaction = begin_transaction(pool).await?;
+
+    let query = user_totp_query::delete_by_user_id(user_id);
+    execute(&mut *transaction, query).await?;
+
+    let codes_query = user_totp_query::delete_recovery_codes_by_user_id(user_id);
+    execute(&mut *transaction, codes_query).await?;
+
+    commit_transaction(transaction).await?;
+
+    Ok(())
+}
+
+/// Returns whether the code existed and got deleted
+pub async fn delete_recovery_code(
+    pool: &PgPool,
+    user_id: &str,
+    code_hash: &str,
+) -> Result<bool, RepositoryError> {
+    let query = user_totp_query::delete_recovery_code_by_id(user_id, code_hash);
+    let deleted_user_id: Option<Uuid> = fetch_optional_single(pool, query).await?;
+    Ok(deleted_user_id.is_some())
+}
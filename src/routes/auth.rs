@@ -1,11 +1,14 @@
-use actix_web::{post, web, Responder};
+use actix_web::{get, post, web, HttpRequest, Responder};
 use jsonwebtoken::{DecodingKey, EncodingKey};
 use sqlx::PgPool;
 
-use crate::models::TokenRequest;
-use crate::services::auth_service;
+use crate::models::{
+    ClientIpConfig, PasswordPolicy, RegistrationConfig, RegistrationDTO, RegistrationInfoDTO,
+    TokenRequest,
+};
+use crate::services::{auth_service, users_service};
 
-use super::base::handle_get_result;
+use super::base::{handle_create_result, handle_get_result};
 
 #[utoipa::path(
     post,
@@ -16,16 +19,78 @@ use super::base::handle_get_result;
         (status = 200, description = "Pair of access and refresh token in JWT format", body = TokenResponse, content_type = "application/json"),
         (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
         (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 429, description = "Too many failed attempts", body = ErrorMessage, content_type = "application/json"),
         (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
     ),
 )]
 #[post("/token")]
 pub async fn token(
+    req: HttpRequest,
     pool: web::Data<PgPool>,
     encoding_key: web::Data<EncodingKey>,
     decoding_key: web::Data<DecodingKey>,
+    client_ip_config: web::Data<ClientIpConfig>,
     form: web::Form<TokenRequest>,
 ) -> impl Responder {
-    let get_result = auth_service::get_token(&pool, &encoding_key, &decoding_key, form.0).await;
+    let ip = get_client_ip(&req, &client_ip_config);
+    let get_result =
+        auth_service::get_token(&pool, &encoding_key, &decoding_key, form.0, ip.as_deref()).await;
     handle_get_result(get_result)
 }
+
+#[utoipa::path(
+    get,
+    path = "/auth/registration",
+    tag = "Auth",
+    responses(
+        (status = 200, description = "Registration mode obtained", body = RegistrationInfoDTO, content_type = "application/json"),
+    ),
+)]
+#[get("/registration")]
+pub async fn get_registration_info(
+    registration_config: web::Data<RegistrationConfig>,
+) -> impl Responder {
+    web::Json(RegistrationInfoDTO {
+        mode: registration_config.mode,
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/register",
+    tag = "Auth",
+    request_body(content = RegistrationDTO, description = "User to be registered (invitation_code required in invitation mode)", content_type = "application/json"),
+    responses(
+        (status = 201, description = "User registered", body = UserDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+        (status = 501, description = "Registration disabled", body = ErrorMessage, content_type = "application/json"),
+    ),
+)]
+#[post("/register")]
+pub async fn register(
+    pool: web::Data<PgPool>,
+    registration_config: web::Data<RegistrationConfig>,
+    password_policy: web::Data<PasswordPolicy>,
+    body: web::Json<RegistrationDTO>,
+) -> impl Responder {
+    let create_result =
+        users_service::register_user(&pool, &registration_config, &password_policy, body.0).await;
+    handle_create_result(create_result)
+}
+
+/// Behind a reverse proxy all clients share the proxy address, so the IP is read from the
+/// trusted header when configured. The last value is taken, as it is the one appended by the proxy.
+fn get_client_ip(req: &HttpRequest, client_ip_config: &ClientIpConfig) -> Option<String> {
+    match &client_ip_config.trusted_header {
+        Some(header) => req
+            .headers()
+            .get(header)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.rsplit(',').next())
+            .map(|ip| ip.trim())
+            .filter(|ip| !ip.is_empty())
+            .map(String::from),
+        None => req.peer_addr().map(|addr| addr.ip().to_string()),
+    }
+}
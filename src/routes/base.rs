@@ -1,9 +1,10 @@
 use actix_web::HttpResponse;
 use serde::Serialize;
+use serde_json::Value;
 use sqlx::PgPool;
 
-use crate::errors::{forbidden_error, ToError};
-use crate::models::{FileTempPath, ModelInfo, PageResultDTO};
+use crate::errors::{error_message_builder, forbidden_error, ApiErrors, ToError};
+use crate::models::{CountQuery, FieldsInfo, FileTempPath, ModelInfo, PageResultDTO, SearchDTO};
 use crate::services::users_service;
 
 pub(super) fn handle_get_result(
@@ -15,6 +16,63 @@ pub(super) fn handle_get_result(
     }
 }
 
+/// Keeps only the requested fields of the item, plus the id and the embedded relations
+pub(super) fn handle_get_fields_result<T: ModelInfo + FieldsInfo + Serialize>(
+    service_result: Result<T, impl ToError>,
+    fields: Option<String>,
+    include: Option<String>,
+) -> HttpResponse {
+    match service_result {
+        Ok(data) => match fields {
+            Some(fields) => {
+                if let Err(error) = check_fields::<T>(&fields) {
+                    return error.to_error();
+                }
+
+                match serde_json::to_value(data) {
+                    Ok(mut value) => {
+                        retain_fields(&mut value, &fields, include.as_deref());
+                        HttpResponse::Ok().json(value)
+                    }
+                    Err(error) => serialize_error::<T>(error).to_error(),
+                }
+            }
+            None => HttpResponse::Ok().json(data),
+        },
+        Err(error) => error.to_error(),
+    }
+}
+
+/// Keeps only the requested fields of every item in the page, plus the id and the embedded relations
+pub(super) fn handle_get_page_fields_result<T: ModelInfo + FieldsInfo + Serialize>(
+    service_result: Result<PageResultDTO<T>, impl ToError>,
+    fields: Option<String>,
+    include: Option<String>,
+) -> HttpResponse {
+    match service_result {
+        Ok(page) => match fields {
+            Some(fields) => {
+                if let Err(error) = check_fields::<T>(&fields) {
+                    return error.to_error();
+                }
+
+                let mut value = match serde_json::to_value(page) {
+                    Ok(value) => value,
+                    Err(error) => return serialize_error::<T>(error).to_error(),
+                };
+                if let Some(Value::Array(items)) = value.get_mut("data") {
+                    for item in items {
+                        retain_fields(item, &fields, include.as_deref());
+                    }
+                }
+                HttpResponse::Ok().json(value)
+            }
+            None => HttpResponse::Ok().json(page),
+        },
+        Err(error) => error.to_error(),
+    }
+}
+
 pub(super) fn handle_create_result(
     service_result: Result<impl Serialize, impl ToError>,
 ) -> HttpResponse {
@@ -48,6 +106,12 @@ pub(super) fn handle_multipart_result(
     multipart_result.map_err(|err| err.to_error())
 }
 
+/// Count query parameter takes precedence over the count field of the body
+pub(super) fn apply_count_query(mut search: SearchDTO, count_query: CountQuery) -> SearchDTO {
+    search.count = count_query.count.or(search.count);
+    search
+}
+
 pub(super) async fn require_admin(pool: &PgPool, user_id: &str) -> Result<(), HttpResponse> {
     let admin_result = users_service::is_user_admin(pool, user_id).await;
     match admin_result {
@@ -78,3 +142,38 @@ pub(super) fn populate_get_page_result<T: ModelInfo>(
         populate_function(&mut page.data);
     }
 }
+
+/// Fails on the first requested field the model does not have
+fn check_fields<T: ModelInfo + FieldsInfo>(fields: &str) -> Result<(), ApiErrors> {
+    fields
+        .split(',')
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .try_for_each(|field| {
+            if T::FIELDS.contains(&field) {
+                Ok(())
+            } else {
+                Err(ApiErrors::InvalidParameter(
+                    error_message_builder::field_not_found(T::MODEL_NAME, field),
+                ))
+            }
+        })
+}
+
+fn serialize_error<T: ModelInfo>(error: serde_json::Error) -> ApiErrors {
+    ApiErrors::UnknownError(error_message_builder::inner_error(
+        &format!("Error serializing {}", T::MODEL_NAME),
+        &error.to_string(),
+    ))
+}
+
+fn retain_fields(value: &mut Value, fields: &str, include: Option<&str>) {
+    if let Value::Object(object) = value {
+        let requested: Vec<&str> = fields
+            .split(',')
+            .chain(include.unwrap_or_default().split(','))
+            .map(str::trim)
+            .collect();
+        object.retain(|key, _| key == "id" || requested.contains(&key.as_str()));
+    }
+}
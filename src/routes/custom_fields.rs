@@ -0,0 +1,169 @@
+use actix_web::{delete, get, post, put, web, Responder};
+use sqlx::PgPool;
+
+use crate::models::{
+    CountQuery, ItemId, LoggedUser, NewCustomFieldDTO, QuicksearchQuery, SearchDTO,
+};
+use crate::services::custom_fields_service;
+
+use super::base::{
+    apply_count_query, handle_create_result, handle_delete_result, handle_get_result,
+    handle_update_result,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/custom-fields/{id}",
+    tag = "Custom fields",
+    params(
+        ("id" = String, Path, description = "Custom field id"),
+    ),
+    responses(
+        (status = 200, description = "Custom field obtained", body = CustomFieldDTO, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Custom field not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/custom-fields/{id}")]
+pub async fn get_custom_field(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let get_result = custom_fields_service::get_custom_field(&pool, &logged_user.id, &id).await;
+    handle_get_result(get_result)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/custom-fields/list",
+    tag = "Custom fields",
+    params(
+        QuicksearchQuery,
+        CountQuery,
+    ),
+    request_body(content = SearchDTO, description = "Query", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Custom fields obtained", body = CustomFieldPageResult, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/custom-fields/list")]
+pub async fn get_custom_fields(
+    pool: web::Data<PgPool>,
+    query: web::Query<QuicksearchQuery>,
+    count_query: web::Query<CountQuery>,
+    body: web::Json<SearchDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let search_result = custom_fields_service::search_custom_fields(
+        &pool,
+        &logged_user.id,
+        apply_count_query(body.0, count_query.0),
+        query.0.q,
+    )
+    .await;
+    handle_get_result(search_result)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/custom-fields",
+    tag = "Custom fields",
+    request_body(content = NewCustomFieldDTO, description = "Custom field to be created", content_type = "application/json"),
+    responses(
+        (status = 201, description = "Custom field created", body = CustomFieldDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Custom field not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/custom-fields")]
+pub async fn post_custom_field(
+    pool: web::Data<PgPool>,
+    body: web::Json<NewCustomFieldDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let create_result =
+        custom_fields_service::create_custom_field(&pool, &logged_user.id, body.0).await;
+    handle_create_result(create_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/custom-fields/{id}",
+    tag = "Custom fields",
+    params(
+        ("id" = String, Path, description = "Custom field id"),
+    ),
+    request_body(content = NewCustomFieldDTO, description = "Custom field to be updated", content_type = "application/json"),
+    responses(
+        (status = 204, description = "Custom field updated"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Custom field not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/custom-fields/{id}")]
+pub async fn put_custom_field(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    body: web::Json<NewCustomFieldDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let update_result =
+        custom_fields_service::update_custom_field(&pool, &logged_user.id, &id, body.0).await;
+    handle_update_result(update_result)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/custom-fields/{id}",
+    tag = "Custom fields",
+    params(
+        ("id" = String, Path, description = "Custom field id"),
+    ),
+    responses(
+        (status = 204, description = "Custom field deleted"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Custom field not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/custom-fields/{id}")]
+pub async fn delete_custom_field(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let delete_result =
+        custom_fields_service::delete_custom_field(&pool, &logged_user.id, &id).await;
+    handle_delete_result(delete_result)
+}
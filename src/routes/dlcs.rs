@@ -2,15 +2,19 @@ use actix_web::{delete, get, post, put, web, Responder};
 use sqlx::PgPool;
 
 use crate::models::{
-    DateDTO, FileTempPath, ItemId, ItemIdAndRelatedId, LoggedUser, NewDLCDTO, QuicksearchQuery,
-    SearchDTO,
+    AvailabilityDTO, CountQuery, DLCIncludeQuery, FileTempPath, ItemId, ItemIdAndRelatedId,
+    LoggedUser, NewDLCDTO, QuicksearchQuery, SavedSearchQuery, SavedSearchType, SearchDTO,
 };
 use crate::providers::ImageClientProvider;
-use crate::services::{dlc_available_service, dlc_image_service, dlcs_service, game_image_service};
+use crate::services::{
+    dlc_available_service, dlc_image_service, dlc_include_service, dlc_tags_service, dlcs_service,
+    game_image_service, saved_searches_service,
+};
 
 use super::base::{
-    handle_action_result, handle_create_result, handle_delete_result, handle_get_result,
-    handle_multipart_result, handle_update_result, populate_get_page_result, populate_get_result,
+    apply_count_query, handle_action_result, handle_create_result, handle_delete_result,
+    handle_get_result, handle_multipart_result, handle_update_result, populate_get_page_result,
+    populate_get_result,
 };
 
 #[utoipa::path(
@@ -19,9 +23,11 @@ use super::base::{
     tag = "DLCs",
     params(
         ("id" = String, Path, description = "DLC id"),
+        DLCIncludeQuery,
     ),
     responses(
         (status = 200, description = "DLC obtained", body = DLCDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
         (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
         (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
         (status = 404, description = "DLC not found", body = ErrorMessage, content_type = "application/json"),
@@ -36,10 +42,17 @@ pub async fn get_dlc(
     pool: web::Data<PgPool>,
     image_client_provider: web::Data<ImageClientProvider>,
     path: web::Path<ItemId>,
+    include_query: web::Query<DLCIncludeQuery>,
     logged_user: LoggedUser,
 ) -> impl Responder {
     let ItemId(id) = path.into_inner();
-    let mut get_result = dlcs_service::get_dlc(&pool, &logged_user.id, &id).await;
+    let mut get_result = match dlcs_service::get_dlc(&pool, &logged_user.id, &id).await {
+        Ok(dlc) => {
+            dlc_include_service::include_dlc(&pool, &logged_user.id, dlc, include_query.0.include)
+                .await
+        }
+        Err(error) => Err(error),
+    };
     populate_get_result(&mut get_result, |dlc| {
         dlc_image_service::populate_dlc_cover(&image_client_provider, dlc)
     });
@@ -112,6 +125,39 @@ pub async fn get_game_dlcs(
     handle_get_result(get_result)
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/tags/{id}/dlcs",
+    tag = "DLCs",
+    params(
+        ("id" = String, Path, description = "Tag id"),
+    ),
+    responses(
+        (status = 200, description = "DLCs obtained", body = [DLCDTO], content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Tag not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/tags/{id}/dlcs")]
+pub async fn get_tag_dlcs(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let mut get_result = dlc_tags_service::get_tag_dlcs(&pool, &logged_user.id, &id).await;
+    populate_get_result(&mut get_result, |dlcs| {
+        dlc_image_service::populate_dlcs_cover(&image_client_provider, dlcs)
+    });
+    handle_get_result(get_result)
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/platforms/{id}/dlcs",
@@ -152,10 +198,14 @@ pub async fn get_platform_dlcs(
     tag = "DLCs",
     params(
         QuicksearchQuery,
+        CountQuery,
+        SavedSearchQuery,
+        DLCIncludeQuery,
     ),
     request_body(content = SearchDTO, description = "Query", content_type = "application/json"),
     responses(
         (status = 200, description = "DLCs obtained", body = DLCPageResult, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
         (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
         (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
         (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
@@ -169,11 +219,36 @@ pub async fn get_dlcs(
     pool: web::Data<PgPool>,
     image_client_provider: web::Data<ImageClientProvider>,
     query: web::Query<QuicksearchQuery>,
+    count_query: web::Query<CountQuery>,
+    saved_search_query: web::Query<SavedSearchQuery>,
+    include_query: web::Query<DLCIncludeQuery>,
     body: web::Json<SearchDTO>,
     logged_user: LoggedUser,
 ) -> impl Responder {
-    let mut search_result =
-        dlcs_service::search_dlcs(&pool, &logged_user.id, body.0, query.0.q).await;
+    let search_result = match saved_searches_service::apply_saved_search(
+        &pool,
+        &logged_user.id,
+        SavedSearchType::DLC,
+        saved_search_query.0.saved_search,
+        apply_count_query(body.0, count_query.0),
+    )
+    .await
+    {
+        Ok(search) => dlcs_service::search_dlcs(&pool, &logged_user.id, search, query.0.q).await,
+        Err(error) => Err(error),
+    };
+    let mut search_result = match search_result {
+        Ok(page) => {
+            dlc_include_service::include_dlcs_page(
+                &pool,
+                &logged_user.id,
+                page,
+                include_query.0.include,
+            )
+            .await
+        }
+        Err(error) => Err(error),
+    };
     populate_get_page_result(&mut search_result, |dlcs| {
         dlc_image_service::populate_dlcs_cover(&image_client_provider, dlcs)
     });
@@ -363,6 +438,38 @@ pub async fn link_dlc_game(
     handle_action_result(update_result)
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/v1/dlcs/{id}/tags/{other_id}",
+    tag = "DLCs",
+    params(
+        ("id" = String, Path, description = "DLC id"),
+        ("other_id" = String, Path, description = "Tag id"),
+    ),
+    responses(
+        (status = 204, description = "DLC and Tag linked"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "DLC or Tag not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/dlcs/{id}/tags/{other_id}")]
+pub async fn link_dlc_tag(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemIdAndRelatedId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemIdAndRelatedId(id, tag_id) = path.into_inner();
+    let create_result =
+        dlc_tags_service::create_dlc_tag(&pool, &logged_user.id, &id, &tag_id).await;
+    handle_action_result(create_result)
+}
+
 #[utoipa::path(
     put,
     path = "/api/v1/dlcs/{id}/platforms/{other_id}",
@@ -371,7 +478,7 @@ pub async fn link_dlc_game(
         ("id" = String, Path, description = "DLC id"),
         ("other_id" = String, Path, description = "Platform id"),
     ),
-    request_body(content = DateDTO, description = "Available date", content_type = "application/json"),
+    request_body(content = AvailabilityDTO, description = "Available date and purchase", content_type = "application/json"),
     responses(
         (status = 204, description = "DLC and Platform linked"),
         (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
@@ -388,7 +495,7 @@ pub async fn link_dlc_game(
 pub async fn link_dlc_platform(
     pool: web::Data<PgPool>,
     path: web::Path<ItemIdAndRelatedId>,
-    body: web::Json<DateDTO>,
+    body: web::Json<AvailabilityDTO>,
     logged_user: LoggedUser,
 ) -> impl Responder {
     let ItemIdAndRelatedId(id, platform_id) = path.into_inner();
@@ -397,12 +504,52 @@ pub async fn link_dlc_platform(
         &logged_user.id,
         &id,
         &platform_id,
-        body.date,
+        body.0,
     )
     .await;
     handle_action_result(create_result)
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/v1/dlcs/{id}/platforms/{other_id}/availability",
+    tag = "DLCs",
+    params(
+        ("id" = String, Path, description = "DLC id"),
+        ("other_id" = String, Path, description = "Platform id"),
+    ),
+    request_body(content = AvailabilityDTO, description = "Available date and purchase", content_type = "application/json"),
+    responses(
+        (status = 204, description = "DLC availability on Platform updated"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "DLC and Platform link not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/dlcs/{id}/platforms/{other_id}/availability")]
+pub async fn put_dlc_platform_availability(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemIdAndRelatedId>,
+    body: web::Json<AvailabilityDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemIdAndRelatedId(id, platform_id) = path.into_inner();
+    let update_result = dlc_available_service::update_dlc_available(
+        &pool,
+        &logged_user.id,
+        &id,
+        &platform_id,
+        body.0,
+    )
+    .await;
+    handle_update_result(update_result)
+}
+
 #[utoipa::path(
     delete,
     path = "/api/v1/dlcs/{id}",
@@ -528,3 +675,35 @@ pub async fn unlink_dlc_platform(
             .await;
     handle_action_result(delete_result)
 }
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/dlcs/{id}/tags/{other_id}",
+    tag = "DLCs",
+    params(
+        ("id" = String, Path, description = "DLC id"),
+        ("other_id" = String, Path, description = "Tag id"),
+    ),
+    responses(
+        (status = 204, description = "DLC and Tag unlinked"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "DLC and Tag relation not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/dlcs/{id}/tags/{other_id}")]
+pub async fn unlink_dlc_tag(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemIdAndRelatedId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemIdAndRelatedId(id, tag_id) = path.into_inner();
+    let delete_result =
+        dlc_tags_service::delete_dlc_tag(&pool, &logged_user.id, &id, &tag_id).await;
+    handle_action_result(delete_result)
+}
@@ -8,7 +8,8 @@ use crate::models::{
 use crate::providers::ImageClientProvider;
 use crate::routes::base::populate_get_result;
 use crate::services::{
-    game_finishes_service, game_image_service, game_review_service, game_with_finish_service,
+    dlc_image_service, game_finishes_service, game_image_service, game_review_service,
+    game_with_finish_service,
 };
 
 use super::base::{
@@ -109,7 +110,11 @@ pub async fn get_finished_games_review(
         game_image_service::populate_games_finished_review_cover(
             &image_client_provider,
             &mut review.games,
-        )
+        );
+        dlc_image_service::populate_dlcs_finished_review_cover(
+            &image_client_provider,
+            &mut review.dlcs,
+        );
     });
     handle_get_result(get_result)
 }
@@ -7,7 +7,8 @@ use crate::models::{
 };
 use crate::providers::ImageClientProvider;
 use crate::services::{
-    game_image_service, game_logs_service, game_review_service, game_with_logs_service,
+    dlc_image_service, game_image_service, game_logs_service, game_review_service,
+    game_with_logs_service,
 };
 
 use super::base::{
@@ -108,7 +109,8 @@ pub async fn get_played_games_review(
         game_image_service::populate_games_played_review_cover(
             &image_client_provider,
             &mut review.games,
-        )
+        );
+        dlc_image_service::populate_dlcs_cover(&image_client_provider, &mut review.dlcs);
     });
     handle_get_result(get_result)
 }
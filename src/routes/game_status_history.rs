@@ -0,0 +1,78 @@
+use actix_web::{get, post, web, Responder};
+use sqlx::PgPool;
+
+use crate::models::{ItemId, LoggedUser, StartEndDateQuery};
+use crate::providers::ImageClientProvider;
+use crate::services::{game_image_service, game_review_service, game_status_history_service};
+
+use super::base::{handle_get_result, populate_get_result};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/games/{id}/status-history",
+    tag = "GameStatusHistory",
+    params(
+        ("id" = String, Path, description = "Game id"),
+    ),
+    responses(
+        (status = 200, description = "Status history obtained", body = [GameStatusChangeDTO], content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Game not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/games/{id}/status-history")]
+pub async fn get_game_status_history(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let get_result =
+        game_status_history_service::get_game_status_history(&pool, &logged_user.id, &id).await;
+    handle_get_result(get_result)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/games/status/review",
+    tag = "GameStatusHistory",
+    params(
+        StartEndDateQuery,
+    ),
+    responses(
+        (status = 200, description = "Status games review obtained", body = GamesStatusReviewDTO, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/games/status/review")]
+pub async fn get_status_games_review(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    query: web::Query<StartEndDateQuery>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let mut get_result = game_review_service::get_status_games_review(
+        &pool,
+        &logged_user.id,
+        query.start_date,
+        query.end_date,
+    )
+    .await;
+    populate_get_result(&mut get_result, |review| {
+        game_image_service::populate_games_status_review_cover(
+            &image_client_provider,
+            &mut review.games,
+        );
+    });
+    handle_get_result(get_result)
+}
@@ -2,16 +2,20 @@ use actix_web::{delete, get, post, put, web, Responder};
 use sqlx::PgPool;
 
 use crate::models::{
-    DateDTO, FileTempPath, ItemId, ItemIdAndRelatedId, LoggedUser, NewGameDTO, QuicksearchQuery,
-    SearchDTO,
+    AvailabilityDTO, CountQuery, ExpiringQuery, FieldsQuery, FileTempPath, GameIncludeQuery,
+    GameMergeDTO, GameRelationshipQuery, GameSeriesPositionDTO, ItemId, ItemIdAndRelatedId,
+    LoggedUser, NewGameDTO, NewGameRelationshipDTO, QuicksearchQuery, SavedSearchQuery,
+    SavedSearchType, SearchDTO,
 };
 use crate::providers::ImageClientProvider;
 use crate::services::{
-    game_available_service, game_image_service, game_tags_service, games_service,
+    game_available_service, game_image_service, game_include_service, game_relationships_service,
+    game_series_service, game_tags_service, games_service, saved_searches_service,
 };
 
 use super::base::{
-    handle_action_result, handle_create_result, handle_delete_result, handle_get_result,
+    apply_count_query, handle_action_result, handle_create_result, handle_delete_result,
+    handle_get_fields_result, handle_get_page_fields_result, handle_get_result,
     handle_multipart_result, handle_update_result, populate_get_page_result, populate_get_result,
 };
 
@@ -21,9 +25,12 @@ use super::base::{
     tag = "Games",
     params(
         ("id" = String, Path, description = "Game id"),
+        FieldsQuery,
+        GameIncludeQuery,
     ),
     responses(
         (status = 200, description = "Game obtained", body = GameDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
         (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
         (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
         (status = 404, description = "Game not found", body = ErrorMessage, content_type = "application/json"),
@@ -38,14 +45,23 @@ pub async fn get_game(
     pool: web::Data<PgPool>,
     image_client_provider: web::Data<ImageClientProvider>,
     path: web::Path<ItemId>,
+    fields_query: web::Query<FieldsQuery>,
+    include_query: web::Query<GameIncludeQuery>,
     logged_user: LoggedUser,
 ) -> impl Responder {
     let ItemId(id) = path.into_inner();
-    let mut get_result = games_service::get_game(&pool, &logged_user.id, &id).await;
+    let include = game_include_service::with_single_game_includes(include_query.0.include);
+    let mut get_result = match games_service::get_game(&pool, &logged_user.id, &id).await {
+        Ok(game) => {
+            game_include_service::include_game(&pool, &logged_user.id, game, include.clone()).await
+        }
+        Err(error) => Err(error),
+    };
     populate_get_result(&mut get_result, |game| {
-        game_image_service::populate_game_cover(&image_client_provider, game)
+        game_image_service::populate_game_cover(&image_client_provider, game);
+        game_image_service::populate_game_includes_images(&image_client_provider, game);
     });
-    handle_get_result(get_result)
+    handle_get_fields_result(get_result, fields_query.0.fields, include)
 }
 
 #[utoipa::path(
@@ -115,16 +131,94 @@ pub async fn get_platform_games(
     handle_get_result(get_result)
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/games/available/expiring",
+    tag = "Games",
+    params(
+        ExpiringQuery,
+    ),
+    responses(
+        (status = 200, description = "Games with expiring availability obtained", body = [GameExpiringDTO], content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/games/available/expiring")]
+pub async fn get_expiring_games(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    query: web::Query<ExpiringQuery>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let mut get_result =
+        game_available_service::get_expiring_games(&pool, &logged_user.id, query.days).await;
+    populate_get_result(&mut get_result, |games| {
+        game_image_service::populate_games_expiring_cover(&image_client_provider, games)
+    });
+    handle_get_result(get_result)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/games/{id}/relationships",
+    tag = "Games",
+    params(
+        ("id" = String, Path, description = "Game id"),
+        GameRelationshipQuery,
+    ),
+    responses(
+        (status = 200, description = "Related games obtained", body = [RelatedGameDTO], content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Game not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/games/{id}/relationships")]
+pub async fn get_game_relationships(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemId>,
+    query: web::Query<GameRelationshipQuery>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let mut get_result = game_relationships_service::get_game_relationships(
+        &pool,
+        &logged_user.id,
+        &id,
+        query.0.rtype,
+    )
+    .await;
+    populate_get_result(&mut get_result, |games| {
+        game_image_service::populate_related_games_cover(&image_client_provider, games)
+    });
+    handle_get_result(get_result)
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/games/list",
     tag = "Games",
     params(
         QuicksearchQuery,
+        CountQuery,
+        SavedSearchQuery,
+        FieldsQuery,
+        GameIncludeQuery,
     ),
     request_body(content = SearchDTO, description = "Query", content_type = "application/json"),
     responses(
         (status = 200, description = "Games obtained", body = GamePageResult, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
         (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
         (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
         (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
@@ -138,15 +232,40 @@ pub async fn get_games(
     pool: web::Data<PgPool>,
     image_client_provider: web::Data<ImageClientProvider>,
     query: web::Query<QuicksearchQuery>,
+    count_query: web::Query<CountQuery>,
+    saved_search_query: web::Query<SavedSearchQuery>,
+    fields_query: web::Query<FieldsQuery>,
+    include_query: web::Query<GameIncludeQuery>,
     body: web::Json<SearchDTO>,
     logged_user: LoggedUser,
 ) -> impl Responder {
-    let mut search_result =
-        games_service::search_games(&pool, &logged_user.id, body.0, query.0.q).await;
+    let include = include_query.0.include;
+    let search_result = match saved_searches_service::apply_saved_search(
+        &pool,
+        &logged_user.id,
+        SavedSearchType::Game,
+        saved_search_query.0.saved_search,
+        apply_count_query(body.0, count_query.0),
+    )
+    .await
+    {
+        Ok(search) => games_service::search_games(&pool, &logged_user.id, search, query.0.q).await,
+        Err(error) => Err(error),
+    };
+    let mut search_result = match search_result {
+        Ok(page) => {
+            game_include_service::include_games_page(&pool, &logged_user.id, page, include.clone())
+                .await
+        }
+        Err(error) => Err(error),
+    };
     populate_get_page_result(&mut search_result, |games| {
-        game_image_service::populate_games_cover(&image_client_provider, games)
+        game_image_service::populate_games_cover(&image_client_provider, games);
+        for game in games {
+            game_image_service::populate_game_includes_images(&image_client_provider, game);
+        }
     });
-    handle_get_result(search_result)
+    handle_get_page_fields_result(search_result, fields_query.0.fields, include)
 }
 
 #[utoipa::path(
@@ -229,6 +348,51 @@ pub async fn post_game_cover(
     handle_action_result(upload_result)
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/games/{id}/merge/{other_id}",
+    tag = "Games",
+    params(
+        ("id" = String, Path, description = "Game id to keep"),
+        ("other_id" = String, Path, description = "Game id to merge into the kept one and delete"),
+    ),
+    request_body(content = GameMergeDTO, description = "Merge options", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Games merged", body = GameDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Game not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/games/{id}/merge/{other_id}")]
+pub async fn merge_games(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemIdAndRelatedId>,
+    body: web::Json<GameMergeDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemIdAndRelatedId(id, other_id) = path.into_inner();
+    let mut merge_result = games_service::merge_games(
+        &pool,
+        &image_client_provider,
+        &logged_user.id,
+        &id,
+        &other_id,
+        body.0,
+    )
+    .await;
+    populate_get_result(&mut merge_result, |game| {
+        game_image_service::populate_game_cover(&image_client_provider, game)
+    });
+    handle_get_result(merge_result)
+}
+
 #[utoipa::path(
     put,
     path = "/api/v1/games/{id}",
@@ -341,7 +505,7 @@ pub async fn link_game_tag(
         ("id" = String, Path, description = "Game id"),
         ("other_id" = String, Path, description = "Platform id"),
     ),
-    request_body(content = DateDTO, description = "Available date", content_type = "application/json"),
+    request_body(content = AvailabilityDTO, description = "Available date and purchase", content_type = "application/json"),
     responses(
         (status = 204, description = "Game and Platform linked"),
         (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
@@ -358,7 +522,7 @@ pub async fn link_game_tag(
 pub async fn link_game_platform(
     pool: web::Data<PgPool>,
     path: web::Path<ItemIdAndRelatedId>,
-    body: web::Json<DateDTO>,
+    body: web::Json<AvailabilityDTO>,
     logged_user: LoggedUser,
 ) -> impl Responder {
     let ItemIdAndRelatedId(id, platform_id) = path.into_inner();
@@ -367,7 +531,127 @@ pub async fn link_game_platform(
         &logged_user.id,
         &id,
         &platform_id,
-        body.date,
+        body.0,
+    )
+    .await;
+    handle_action_result(create_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/games/{id}/platforms/{other_id}/availability",
+    tag = "Games",
+    params(
+        ("id" = String, Path, description = "Game id"),
+        ("other_id" = String, Path, description = "Platform id"),
+    ),
+    request_body(content = AvailabilityDTO, description = "Available date and purchase", content_type = "application/json"),
+    responses(
+        (status = 204, description = "Game availability on Platform updated"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Game and Platform link not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/games/{id}/platforms/{other_id}/availability")]
+pub async fn put_game_platform_availability(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemIdAndRelatedId>,
+    body: web::Json<AvailabilityDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemIdAndRelatedId(id, platform_id) = path.into_inner();
+    let update_result = game_available_service::update_game_available(
+        &pool,
+        &logged_user.id,
+        &id,
+        &platform_id,
+        body.0,
+    )
+    .await;
+    handle_update_result(update_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/games/{id}/series/{other_id}",
+    tag = "Games",
+    params(
+        ("id" = String, Path, description = "Game id"),
+        ("other_id" = String, Path, description = "Series id"),
+    ),
+    request_body(content = GameSeriesPositionDTO, description = "Position in the series", content_type = "application/json"),
+    responses(
+        (status = 204, description = "Game and Series linked"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Game or Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/games/{id}/series/{other_id}")]
+pub async fn link_game_series(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemIdAndRelatedId>,
+    body: web::Json<GameSeriesPositionDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemIdAndRelatedId(id, series_id) = path.into_inner();
+    let create_result = game_series_service::create_game_series(
+        &pool,
+        &logged_user.id,
+        &id,
+        &series_id,
+        body.position,
+    )
+    .await;
+    handle_action_result(create_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/games/{id}/relationships/{other_id}",
+    tag = "Games",
+    params(
+        ("id" = String, Path, description = "Game id"),
+        ("other_id" = String, Path, description = "Related game id"),
+    ),
+    request_body(content = NewGameRelationshipDTO, description = "Relationship type", content_type = "application/json"),
+    responses(
+        (status = 204, description = "Games linked"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Game not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/games/{id}/relationships/{other_id}")]
+pub async fn link_game_relationship(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemIdAndRelatedId>,
+    body: web::Json<NewGameRelationshipDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemIdAndRelatedId(id, related_game_id) = path.into_inner();
+    let create_result = game_relationships_service::create_game_relationship(
+        &pool,
+        &logged_user.id,
+        &id,
+        &related_game_id,
+        body.rtype,
     )
     .await;
     handle_action_result(create_result)
@@ -500,3 +784,72 @@ pub async fn unlink_game_platform(
             .await;
     handle_action_result(delete_result)
 }
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/games/{id}/series/{other_id}",
+    tag = "Games",
+    params(
+        ("id" = String, Path, description = "Game id"),
+        ("other_id" = String, Path, description = "Series id"),
+    ),
+    responses(
+        (status = 204, description = "Game and Series unlinked"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Game or Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/games/{id}/series/{other_id}")]
+pub async fn unlink_game_series(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemIdAndRelatedId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemIdAndRelatedId(id, series_id) = path.into_inner();
+    let delete_result =
+        game_series_service::delete_game_series(&pool, &logged_user.id, &id, &series_id).await;
+    handle_action_result(delete_result)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/games/{id}/relationships/{other_id}",
+    tag = "Games",
+    params(
+        ("id" = String, Path, description = "Game id"),
+        ("other_id" = String, Path, description = "Related game id"),
+    ),
+    responses(
+        (status = 204, description = "Games unlinked"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Relationship of Games not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/games/{id}/relationships/{other_id}")]
+pub async fn unlink_game_relationship(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemIdAndRelatedId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemIdAndRelatedId(id, related_game_id) = path.into_inner();
+    let delete_result = game_relationships_service::delete_game_relationship(
+        &pool,
+        &logged_user.id,
+        &id,
+        &related_game_id,
+    )
+    .await;
+    handle_action_result(delete_result)
+}
@@ -0,0 +1,172 @@
+use actix_web::{delete, put, web, Responder};
+use sqlx::PgPool;
+
+use crate::models::{
+    GameBulkDTO, GameBulkPlatformsDTO, GameBulkTagsDTO, GameBulkUpdateDTO, LoggedUser,
+};
+use crate::providers::ImageClientProvider;
+use crate::services::game_bulk_service;
+
+use super::base::handle_get_result;
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/games/bulk",
+    tag = "Games",
+    request_body(content = GameBulkUpdateDTO, description = "Games and changes to apply", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Games updated, with the result of each one", body = GameBulkResultDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/games/bulk")]
+pub async fn put_games_bulk(
+    pool: web::Data<PgPool>,
+    body: web::Json<GameBulkUpdateDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let bulk_result = game_bulk_service::update_games(&pool, &logged_user.id, body.0).await;
+    handle_get_result(bulk_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/games/bulk/tags",
+    tag = "Games",
+    request_body(content = GameBulkTagsDTO, description = "Games and tags to link", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Games and Tags linked, with the result of each game", body = GameBulkResultDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Tag not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/games/bulk/tags")]
+pub async fn link_games_bulk_tags(
+    pool: web::Data<PgPool>,
+    body: web::Json<GameBulkTagsDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let bulk_result = game_bulk_service::link_games_tags(&pool, &logged_user.id, body.0).await;
+    handle_get_result(bulk_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/games/bulk/platforms",
+    tag = "Games",
+    request_body(content = GameBulkPlatformsDTO, description = "Games and platforms to link", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Games and Platforms linked, with the result of each game", body = GameBulkResultDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Platform not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/games/bulk/platforms")]
+pub async fn link_games_bulk_platforms(
+    pool: web::Data<PgPool>,
+    body: web::Json<GameBulkPlatformsDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let bulk_result = game_bulk_service::link_games_platforms(&pool, &logged_user.id, body.0).await;
+    handle_get_result(bulk_result)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/games/bulk",
+    tag = "Games",
+    request_body(content = GameBulkDTO, description = "Games to delete", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Games deleted, with the result of each one", body = GameBulkResultDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/games/bulk")]
+pub async fn delete_games_bulk(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    body: web::Json<GameBulkDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let bulk_result =
+        game_bulk_service::delete_games(&pool, &image_client_provider, &logged_user.id, body.0)
+            .await;
+    handle_get_result(bulk_result)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/games/bulk/tags",
+    tag = "Games",
+    request_body(content = GameBulkTagsDTO, description = "Games and tags to unlink", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Games and Tags unlinked, with the result of each game", body = GameBulkResultDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/games/bulk/tags")]
+pub async fn unlink_games_bulk_tags(
+    pool: web::Data<PgPool>,
+    body: web::Json<GameBulkTagsDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let bulk_result = game_bulk_service::unlink_games_tags(&pool, &logged_user.id, body.0).await;
+    handle_get_result(bulk_result)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/games/bulk/platforms",
+    tag = "Games",
+    request_body(content = GameBulkPlatformsDTO, description = "Games and platforms to unlink", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Games and Platforms unlinked, with the result of each game", body = GameBulkResultDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/games/bulk/platforms")]
+pub async fn unlink_games_bulk_platforms(
+    pool: web::Data<PgPool>,
+    body: web::Json<GameBulkPlatformsDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let bulk_result =
+        game_bulk_service::unlink_games_platforms(&pool, &logged_user.id, body.0).await;
+    handle_get_result(bulk_result)
+}
@@ -0,0 +1,40 @@
+use actix_web::{get, web, Responder};
+use sqlx::PgPool;
+
+use crate::models::{GlobalSearchQuery, LoggedUser};
+use crate::providers::ImageClientProvider;
+use crate::services::global_search_service;
+
+use super::base::handle_get_result;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/search",
+    tag = "Search",
+    params(
+        GlobalSearchQuery,
+    ),
+    responses(
+        (status = 200, description = "Games, DLCs, platforms and tags found", body = GlobalSearchDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/search")]
+pub async fn get_global_search(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    query: web::Query<GlobalSearchQuery>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let GlobalSearchQuery { q, limit } = query.into_inner();
+    let search_result =
+        global_search_service::search_all(&pool, &image_client_provider, &logged_user.id, q, limit)
+            .await;
+    handle_get_result(search_result)
+}
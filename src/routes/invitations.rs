@@ -0,0 +1,101 @@
+use actix_web::{delete, get, post, web, Responder};
+use sqlx::PgPool;
+
+use crate::models::{ItemId, LoggedUser, NewInvitationDTO, RegistrationConfig};
+use crate::services::invitations_service;
+
+use super::base::{handle_create_result, handle_delete_result, handle_get_result, require_admin};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/invitations",
+    tag = "Invitations",
+    responses(
+        (status = 200, description = "Invitations obtained", body = [InvitationDTO], content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/invitations")]
+pub async fn get_invitations(pool: web::Data<PgPool>, logged_user: LoggedUser) -> impl Responder {
+    if let Err(error) = require_admin(&pool, &logged_user.id).await {
+        return error;
+    }
+
+    let get_result = invitations_service::get_invitations(&pool, &logged_user.id).await;
+    handle_get_result(get_result)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/invitations",
+    tag = "Invitations",
+    request_body(content = NewInvitationDTO, description = "Invitation to be created", content_type = "application/json"),
+    responses(
+        (status = 201, description = "Invitation created", body = InvitationDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/invitations")]
+pub async fn post_invitation(
+    pool: web::Data<PgPool>,
+    registration_config: web::Data<RegistrationConfig>,
+    body: web::Json<NewInvitationDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    if let Err(error) = require_admin(&pool, &logged_user.id).await {
+        return error;
+    }
+
+    let create_result = invitations_service::create_invitation(
+        &pool,
+        &logged_user.id,
+        &registration_config,
+        body.0,
+    )
+    .await;
+    handle_create_result(create_result)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/invitations/{id}",
+    tag = "Invitations",
+    params(
+        ("id" = String, Path, description = "Invitation id"),
+    ),
+    responses(
+        (status = 204, description = "Invitation deleted"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Invitation not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/invitations/{id}")]
+pub async fn delete_invitation(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    if let Err(error) = require_admin(&pool, &logged_user.id).await {
+        return error;
+    }
+
+    let ItemId(id) = path.into_inner();
+    let delete_result = invitations_service::delete_invitation(&pool, &logged_user.id, &id).await;
+    handle_delete_result(delete_result)
+}
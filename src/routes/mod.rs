@@ -1,22 +1,38 @@
 mod auth;
 mod base;
+mod custom_fields;
 mod dlc_finish;
 mod dlcs;
 mod game_finish;
 mod game_logs;
+mod game_status_history;
 mod games;
+mod games_bulk;
+mod global_search;
+mod invitations;
 mod is_alive;
 mod platform;
+mod saved_searches;
+mod series;
+mod spending;
 mod tags;
 mod users;
 
 pub use auth::*;
+pub use custom_fields::*;
 pub use dlc_finish::*;
 pub use dlcs::*;
 pub use game_finish::*;
 pub use game_logs::*;
+pub use game_status_history::*;
 pub use games::*;
+pub use games_bulk::*;
+pub use global_search::*;
+pub use invitations::*;
 pub use is_alive::*;
 pub use platform::*;
+pub use saved_searches::*;
+pub use series::*;
+pub use spending::*;
 pub use tags::*;
 pub use users::*;
@@ -2,16 +2,19 @@ use actix_web::{delete, get, post, put, web, Responder};
 use sqlx::PgPool;
 
 use crate::models::{
-    FileTempPath, ItemId, LoggedUser, NewPlatformDTO, QuicksearchQuery, SearchDTO,
+    CountQuery, FileTempPath, ItemId, LoggedUser, NewPlatformDTO, QuicksearchQuery,
+    SavedSearchQuery, SavedSearchType, SearchDTO,
 };
 use crate::providers::ImageClientProvider;
 use crate::services::{
     dlc_available_service, game_available_service, platform_image_service, platforms_service,
+    saved_searches_service,
 };
 
 use super::base::{
-    handle_action_result, handle_create_result, handle_delete_result, handle_get_result,
-    handle_multipart_result, handle_update_result, populate_get_page_result, populate_get_result,
+    apply_count_query, handle_action_result, handle_create_result, handle_delete_result,
+    handle_get_result, handle_multipart_result, handle_update_result, populate_get_page_result,
+    populate_get_result,
 };
 
 #[utoipa::path(
@@ -121,6 +124,8 @@ pub async fn get_dlc_platforms(
     tag = "Platforms",
     params(
         QuicksearchQuery,
+        CountQuery,
+        SavedSearchQuery,
     ),
     request_body(content = SearchDTO, description = "Query", content_type = "application/json"),
     responses(
@@ -138,11 +143,25 @@ pub async fn get_platforms(
     pool: web::Data<PgPool>,
     image_client_provider: web::Data<ImageClientProvider>,
     query: web::Query<QuicksearchQuery>,
+    count_query: web::Query<CountQuery>,
+    saved_search_query: web::Query<SavedSearchQuery>,
     body: web::Json<SearchDTO>,
     logged_user: LoggedUser,
 ) -> impl Responder {
-    let mut search_result =
-        platforms_service::search_platforms(&pool, &logged_user.id, body.0, query.0.q).await;
+    let mut search_result = match saved_searches_service::apply_saved_search(
+        &pool,
+        &logged_user.id,
+        SavedSearchType::Platform,
+        saved_search_query.0.saved_search,
+        apply_count_query(body.0, count_query.0),
+    )
+    .await
+    {
+        Ok(search) => {
+            platforms_service::search_platforms(&pool, &logged_user.id, search, query.0.q).await
+        }
+        Err(error) => Err(error),
+    };
     populate_get_page_result(&mut search_result, |platform| {
         platform_image_service::populate_platforms_icon(&image_client_provider, platform)
     });
@@ -0,0 +1,169 @@
+use actix_web::{delete, get, post, put, web, Responder};
+use sqlx::PgPool;
+
+use crate::models::{
+    CountQuery, ItemId, LoggedUser, NewSavedSearchDTO, QuicksearchQuery, SearchDTO,
+};
+use crate::services::saved_searches_service;
+
+use super::base::{
+    apply_count_query, handle_create_result, handle_delete_result, handle_get_result,
+    handle_update_result,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/saved-searches/{id}",
+    tag = "Saved searches",
+    params(
+        ("id" = String, Path, description = "Saved search id"),
+    ),
+    responses(
+        (status = 200, description = "Saved search obtained", body = SavedSearchDTO, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Saved search not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/saved-searches/{id}")]
+pub async fn get_saved_search(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let get_result = saved_searches_service::get_saved_search(&pool, &logged_user.id, &id).await;
+    handle_get_result(get_result)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/saved-searches/list",
+    tag = "Saved searches",
+    params(
+        QuicksearchQuery,
+        CountQuery,
+    ),
+    request_body(content = SearchDTO, description = "Query", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Saved searches obtained", body = SavedSearchPageResult, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/saved-searches/list")]
+pub async fn get_saved_searches(
+    pool: web::Data<PgPool>,
+    query: web::Query<QuicksearchQuery>,
+    count_query: web::Query<CountQuery>,
+    body: web::Json<SearchDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let search_result = saved_searches_service::search_saved_searches(
+        &pool,
+        &logged_user.id,
+        apply_count_query(body.0, count_query.0),
+        query.0.q,
+    )
+    .await;
+    handle_get_result(search_result)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/saved-searches",
+    tag = "Saved searches",
+    request_body(content = NewSavedSearchDTO, description = "Saved search to be created", content_type = "application/json"),
+    responses(
+        (status = 201, description = "Saved search created", body = SavedSearchDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Saved search not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/saved-searches")]
+pub async fn post_saved_search(
+    pool: web::Data<PgPool>,
+    body: web::Json<NewSavedSearchDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let create_result =
+        saved_searches_service::create_saved_search(&pool, &logged_user.id, body.0).await;
+    handle_create_result(create_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/saved-searches/{id}",
+    tag = "Saved searches",
+    params(
+        ("id" = String, Path, description = "Saved search id"),
+    ),
+    request_body(content = NewSavedSearchDTO, description = "Saved search to be updated", content_type = "application/json"),
+    responses(
+        (status = 204, description = "Saved search updated"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Saved search not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/saved-searches/{id}")]
+pub async fn put_saved_search(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    body: web::Json<NewSavedSearchDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let update_result =
+        saved_searches_service::update_saved_search(&pool, &logged_user.id, &id, body.0).await;
+    handle_update_result(update_result)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/saved-searches/{id}",
+    tag = "Saved searches",
+    params(
+        ("id" = String, Path, description = "Saved search id"),
+    ),
+    responses(
+        (status = 204, description = "Saved search deleted"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Saved search not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/saved-searches/{id}")]
+pub async fn delete_saved_search(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let delete_result =
+        saved_searches_service::delete_saved_search(&pool, &logged_user.id, &id).await;
+    handle_delete_result(delete_result)
+}
@@ -0,0 +1,401 @@
+use actix_web::{delete, get, post, put, web, Responder};
+use sqlx::PgPool;
+
+use crate::models::{
+    CountQuery, FileTempPath, ItemId, LoggedUser, NewSeriesDTO, QuicksearchQuery, SearchDTO,
+};
+use crate::providers::ImageClientProvider;
+use crate::services::{
+    game_image_service, game_series_service, series_image_service, series_service,
+};
+
+use super::base::{
+    apply_count_query, handle_action_result, handle_create_result, handle_delete_result,
+    handle_get_result, handle_multipart_result, handle_update_result, populate_get_page_result,
+    populate_get_result,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/series/{id}",
+    tag = "Series",
+    params(
+        ("id" = String, Path, description = "Series id"),
+    ),
+    responses(
+        (status = 200, description = "Series obtained", body = SeriesDTO, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/series/{id}")]
+pub async fn get_series(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let mut get_result = series_service::get_series(&pool, &logged_user.id, &id).await;
+    populate_get_result(&mut get_result, |series| {
+        series_image_service::populate_series_image(&image_client_provider, series)
+    });
+    handle_get_result(get_result)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/games/{id}/series",
+    tag = "Series",
+    params(
+        ("id" = String, Path, description = "Game id"),
+    ),
+    responses(
+        (status = 200, description = "Series obtained", body = [SeriesDTO], content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Game not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/games/{id}/series")]
+pub async fn get_game_series(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let mut get_result = game_series_service::get_game_series(&pool, &logged_user.id, &id).await;
+    populate_get_result(&mut get_result, |series| {
+        series_image_service::populate_series_list_image(&image_client_provider, series)
+    });
+    handle_get_result(get_result)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/series/{id}/games",
+    tag = "Series",
+    params(
+        ("id" = String, Path, description = "Series id"),
+    ),
+    responses(
+        (status = 200, description = "Games obtained in series order", body = [GameInSeriesDTO], content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/series/{id}/games")]
+pub async fn get_series_games(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let mut get_result = game_series_service::get_series_games(&pool, &logged_user.id, &id).await;
+    populate_get_result(&mut get_result, |games| {
+        game_image_service::populate_games_in_series_cover(&image_client_provider, games)
+    });
+    handle_get_result(get_result)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/series/{id}/progress",
+    tag = "Series",
+    params(
+        ("id" = String, Path, description = "Series id"),
+    ),
+    responses(
+        (status = 200, description = "Series progress obtained", body = SeriesProgressDTO, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/series/{id}/progress")]
+pub async fn get_series_progress(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let get_result = game_series_service::get_series_progress(&pool, &logged_user.id, &id).await;
+    handle_get_result(get_result)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/series/list",
+    tag = "Series",
+    params(
+        QuicksearchQuery,
+        CountQuery,
+    ),
+    request_body(content = SearchDTO, description = "Query", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Series obtained", body = SeriesPageResult, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/series/list")]
+pub async fn get_series_list(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    query: web::Query<QuicksearchQuery>,
+    count_query: web::Query<CountQuery>,
+    body: web::Json<SearchDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let mut search_result = series_service::search_series(
+        &pool,
+        &logged_user.id,
+        apply_count_query(body.0, count_query.0),
+        query.0.q,
+    )
+    .await;
+    populate_get_page_result(&mut search_result, |series| {
+        series_image_service::populate_series_list_image(&image_client_provider, series)
+    });
+    handle_get_result(search_result)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/series",
+    tag = "Series",
+    request_body(content = NewSeriesDTO, description = "Series to be createad", content_type = "application/json"),
+    responses(
+        (status = 201, description = "Series created", body = SeriesDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/series")]
+pub async fn post_series(
+    pool: web::Data<PgPool>,
+    body: web::Json<NewSeriesDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let create_result = series_service::create_series(&pool, &logged_user.id, body.0).await;
+    handle_create_result(create_result)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/series/{id}/image",
+    tag = "Series",
+    params(
+        ("id" = String, Path, description = "Series id"),
+    ),
+    request_body(content = Image, description = "Series image to be uploaded", content_type = "multipart/form-data"),
+    responses(
+        (status = 204, description = "Series image uploaded"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/series/{id}/image")]
+pub async fn post_series_image(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemId>,
+    body: actix_multipart::Multipart,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+
+    let file_path_result = crate::multipart_utils::get_multipart_file_path(body).await;
+    let FileTempPath {
+        directory_path,
+        file_path,
+    } = match handle_multipart_result(file_path_result) {
+        Ok(res) => res,
+        Err(err) => return err,
+    };
+
+    let upload_result = series_service::set_series_image(
+        &pool,
+        &image_client_provider,
+        &logged_user.id,
+        &id,
+        &file_path,
+    )
+    .await;
+
+    crate::temp_file_utils::delete_temp_dir(&directory_path).await;
+
+    handle_action_result(upload_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/series/{id}",
+    tag = "Series",
+    params(
+        ("id" = String, Path, description = "Series id"),
+    ),
+    request_body(content = NewSeriesDTO, description = "Series to be updated", content_type = "application/json"),
+    responses(
+        (status = 204, description = "Series updated"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/series/{id}")]
+pub async fn put_series(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    body: web::Json<NewSeriesDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let update_result = series_service::update_series(&pool, &logged_user.id, &id, body.0).await;
+    handle_update_result(update_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/series/{id}/image",
+    tag = "Series",
+    params(
+        ("id" = String, Path, description = "Series id"),
+    ),
+    request_body(content = String, description = "New series image name", content_type = "application/json"),
+    responses(
+        (status = 204, description = "Series image renamed"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/series/{id}/image")]
+pub async fn put_series_image(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemId>,
+    body: web::Json<String>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let update_result = series_service::rename_series_image(
+        &pool,
+        &image_client_provider,
+        &logged_user.id,
+        &id,
+        &body.0,
+    )
+    .await;
+    handle_action_result(update_result)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/series/{id}",
+    tag = "Series",
+    params(
+        ("id" = String, Path, description = "Series id"),
+    ),
+    responses(
+        (status = 204, description = "Series deleted"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/series/{id}")]
+pub async fn delete_series(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let delete_result =
+        series_service::delete_series(&pool, &image_client_provider, &logged_user.id, &id).await;
+    handle_delete_result(delete_result)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/series/{id}/image",
+    tag = "Series",
+    params(
+        ("id" = String, Path, description = "Series id"),
+    ),
+    responses(
+        (status = 204, description = "Series image deleted"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Series not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[delete("/series/{id}/image")]
+pub async fn delete_series_image(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let delete_result =
+        series_service::delete_series_image(&pool, &image_client_provider, &logged_user.id, &id)
+            .await;
+    handle_action_result(delete_result)
+}
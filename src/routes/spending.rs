@@ -0,0 +1,50 @@
+use actix_web::{post, web, Responder};
+use sqlx::PgPool;
+
+use crate::models::{LoggedUser, StartEndDateQuery};
+use crate::providers::ImageClientProvider;
+use crate::routes::base::populate_get_result;
+use crate::services::{game_image_service, spending_service};
+
+use super::base::handle_get_result;
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/spending/review",
+    tag = "Spending",
+    params(
+        StartEndDateQuery,
+    ),
+    responses(
+        (status = 200, description = "Spending review obtained", body = SpendingReviewDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/spending/review")]
+pub async fn get_spending_review(
+    pool: web::Data<PgPool>,
+    image_client_provider: web::Data<ImageClientProvider>,
+    query: web::Query<StartEndDateQuery>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let mut get_result = spending_service::get_spending_review(
+        &pool,
+        &logged_user.id,
+        query.start_date,
+        query.end_date,
+    )
+    .await;
+    populate_get_result(&mut get_result, |review| {
+        game_image_service::populate_games_spending_review_cover(
+            &image_client_provider,
+            &mut review.games,
+        )
+    });
+    handle_get_result(get_result)
+}
@@ -1,13 +1,37 @@
 use actix_web::{delete, get, post, put, web, Responder};
 use sqlx::PgPool;
 
-use crate::models::{ItemId, LoggedUser, NewTagDTO, QuicksearchQuery, SearchDTO};
-use crate::services::{game_tags_service, tags_service};
+use crate::models::{
+    CountQuery, ItemId, LoggedUser, NewTagDTO, QuicksearchQuery, SavedSearchQuery, SavedSearchType,
+    SearchDTO,
+};
+use crate::services::{dlc_tags_service, game_tags_service, saved_searches_service, tags_service};
 
 use super::base::{
-    handle_create_result, handle_delete_result, handle_get_result, handle_update_result,
+    apply_count_query, handle_create_result, handle_delete_result, handle_get_result,
+    handle_update_result,
 };
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/tags/tree",
+    tag = "Tags",
+    responses(
+        (status = 200, description = "Tag tree obtained", body = [TagTreeDTO], content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/tags/tree")]
+pub async fn get_tag_tree(pool: web::Data<PgPool>, logged_user: LoggedUser) -> impl Responder {
+    let get_result = tags_service::get_tag_tree(&pool, &logged_user.id).await;
+    handle_get_result(get_result)
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/tags/{id}",
@@ -66,12 +90,43 @@ pub async fn get_game_tags(
     handle_get_result(get_result)
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/dlcs/{id}/tags",
+    tag = "Tags",
+    params(
+        ("id" = String, Path, description = "DLC id"),
+    ),
+    responses(
+        (status = 200, description = "Tags obtained", body = [TagDTO], content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "DLC not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[get("/dlcs/{id}/tags")]
+pub async fn get_dlc_tags(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let ItemId(id) = path.into_inner();
+    let get_result = dlc_tags_service::get_dlc_tags(&pool, &logged_user.id, &id).await;
+    handle_get_result(get_result)
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/tags/list",
     tag = "Tags",
     params(
         QuicksearchQuery,
+        CountQuery,
+        SavedSearchQuery,
     ),
     request_body(content = SearchDTO, description = "Query", content_type = "application/json"),
     responses(
@@ -88,10 +143,23 @@ pub async fn get_game_tags(
 pub async fn get_tags(
     pool: web::Data<PgPool>,
     query: web::Query<QuicksearchQuery>,
+    count_query: web::Query<CountQuery>,
+    saved_search_query: web::Query<SavedSearchQuery>,
     body: web::Json<SearchDTO>,
     logged_user: LoggedUser,
 ) -> impl Responder {
-    let search_result = tags_service::search_tags(&pool, &logged_user.id, body.0, query.0.q).await;
+    let search_result = match saved_searches_service::apply_saved_search(
+        &pool,
+        &logged_user.id,
+        SavedSearchType::Tag,
+        saved_search_query.0.saved_search,
+        apply_count_query(body.0, count_query.0),
+    )
+    .await
+    {
+        Ok(search) => tags_service::search_tags(&pool, &logged_user.id, search, query.0.q).await,
+        Err(error) => Err(error),
+    };
     handle_get_result(search_result)
 }
 
@@ -2,13 +2,14 @@ use actix_web::{delete, get, post, put, web, Responder};
 use sqlx::PgPool;
 
 use crate::models::{
-    ItemId, LoggedUser, NewUserDTO, PasswordChangeDTO, PasswordQuery, QuicksearchQuery, SearchDTO,
+    CountQuery, ItemId, LoggedUser, NewUserDTO, PasswordChangeDTO, PasswordPolicy, PasswordQuery,
+    QuicksearchQuery, SearchDTO, TotpCodeDTO,
 };
-use crate::services::users_service;
+use crate::services::{user_totp_service, users_service};
 
 use super::base::{
-    handle_action_result, handle_create_result, handle_delete_result, handle_get_result,
-    handle_update_result, require_admin,
+    apply_count_query, handle_action_result, handle_create_result, handle_delete_result,
+    handle_get_result, handle_update_result, require_admin,
 };
 
 #[utoipa::path(
@@ -63,6 +64,7 @@ pub async fn get_current_user(pool: web::Data<PgPool>, logged_user: LoggedUser)
     tag = "Users",
     params(
         QuicksearchQuery,
+        CountQuery,
     ),
     request_body(content = SearchDTO, description = "Query", content_type = "application/json"),
     responses(
@@ -79,9 +81,12 @@ pub async fn get_current_user(pool: web::Data<PgPool>, logged_user: LoggedUser)
 pub async fn get_users(
     pool: web::Data<PgPool>,
     query: web::Query<QuicksearchQuery>,
+    count_query: web::Query<CountQuery>,
     body: web::Json<SearchDTO>,
 ) -> impl Responder {
-    let search_result = users_service::search_users(&pool, body.0, query.0.q).await;
+    let search_result =
+        users_service::search_users(&pool, apply_count_query(body.0, count_query.0), query.0.q)
+            .await;
     handle_get_result(search_result)
 }
 
@@ -108,6 +113,7 @@ pub async fn get_users(
 #[post("/users")]
 pub async fn post_user(
     pool: web::Data<PgPool>,
+    password_policy: web::Data<PasswordPolicy>,
     query: web::Query<PasswordQuery>,
     body: web::Json<NewUserDTO>,
     logged_user: LoggedUser,
@@ -116,7 +122,8 @@ pub async fn post_user(
         return error;
     }
 
-    let create_result = users_service::create_user(&pool, body.0, &query.0.password).await;
+    let create_result =
+        users_service::create_user(&pool, &password_policy, body.0, &query.0.password).await;
     handle_create_result(create_result)
 }
 
@@ -171,14 +178,122 @@ pub async fn put_user(
 #[put("/myself/change-password")]
 pub async fn change_password(
     pool: web::Data<PgPool>,
+    password_policy: web::Data<PasswordPolicy>,
     form: web::Form<PasswordChangeDTO>,
     logged_user: LoggedUser,
 ) -> impl Responder {
     let change_password_result =
-        users_service::change_user_password(&pool, &logged_user.id, form.0).await;
+        users_service::change_user_password(&pool, &password_policy, &logged_user.id, form.0).await;
     handle_action_result(change_password_result)
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/myself/totp",
+    tag = "Users",
+    responses(
+        (status = 201, description = "Two-factor authentication enrollment started", body = TotpEnrollmentDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "User not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[post("/myself/totp")]
+pub async fn enroll_totp(pool: web::Data<PgPool>, logged_user: LoggedUser) -> impl Responder {
+    let enroll_result = user_totp_service::enroll_totp(&pool, &logged_user.id).await;
+    handle_create_result(enroll_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/myself/totp/activate",
+    tag = "Users",
+    request_body(content = TotpCodeDTO, description = "Code generated with the enrolled secret", content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Two-factor authentication activated", body = TotpRecoveryCodesDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Two-factor authentication not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 429, description = "Too many wrong codes", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/myself/totp/activate")]
+pub async fn activate_totp(
+    pool: web::Data<PgPool>,
+    form: web::Form<TotpCodeDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let activate_result = user_totp_service::activate_totp(&pool, &logged_user.id, form.0).await;
+    handle_get_result(activate_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/myself/totp/recovery-codes",
+    tag = "Users",
+    request_body(content = TotpCodeDTO, description = "Current two-factor authentication code", content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Recovery codes regenerated", body = TotpRecoveryCodesDTO, content_type = "application/json"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Two-factor authentication not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 429, description = "Too many wrong codes", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/myself/totp/recovery-codes")]
+pub async fn regenerate_totp_recovery_codes(
+    pool: web::Data<PgPool>,
+    form: web::Form<TotpCodeDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let regenerate_result =
+        user_totp_service::regenerate_recovery_codes(&pool, &logged_user.id, form.0).await;
+    handle_get_result(regenerate_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/myself/totp/disable",
+    tag = "Users",
+    request_body(content = TotpCodeDTO, description = "Current two-factor authentication code or a recovery code", content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 204, description = "Two-factor authentication disabled"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "Two-factor authentication not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 429, description = "Too many wrong codes", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/myself/totp/disable")]
+pub async fn disable_totp(
+    pool: web::Data<PgPool>,
+    form: web::Form<TotpCodeDTO>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    let disable_result = user_totp_service::disable_totp(&pool, &logged_user.id, form.0).await;
+    handle_action_result(disable_result)
+}
+
 #[utoipa::path(
     put,
     path = "/api/v1/users/{id}/promote",
@@ -247,6 +362,75 @@ pub async fn demote_user(
     handle_update_result(update_result)
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/{id}/unlock",
+    tag = "Users",
+    params(
+        ("id" = String, Path, description = "User id"),
+    ),
+    responses(
+        (status = 204, description = "User login unlocked"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "User not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/users/{id}/unlock")]
+pub async fn unlock_user(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    if let Err(error) = require_admin(&pool, &logged_user.id).await {
+        return error;
+    }
+
+    let ItemId(id) = path.into_inner();
+    let unlock_result = users_service::unlock_user(&pool, &id).await;
+    handle_action_result(unlock_result)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/{id}/reset-password",
+    tag = "Users",
+    params(
+        ("id" = String, Path, description = "User id"),
+        PasswordQuery,
+    ),
+    responses(
+        (status = 204, description = "User password reset, must be changed on next login"),
+        (status = 400, description = "Bad request", body = ErrorMessage, content_type = "application/json"),
+        (status = 401, description = "Unauthorized", body = ErrorMessage, content_type = "application/json"),
+        (status = 403, description = "Forbidden", body = ErrorMessage, content_type = "application/json"),
+        (status = 404, description = "User not found", body = ErrorMessage, content_type = "application/json"),
+        (status = 500, description = "Internal server error", body = ErrorMessage, content_type = "application/json"),
+    ),
+    security(
+        ("OAuth2" = [])
+    )
+)]
+#[put("/users/{id}/reset-password")]
+pub async fn reset_user_password(
+    pool: web::Data<PgPool>,
+    path: web::Path<ItemId>,
+    query: web::Query<PasswordQuery>,
+    logged_user: LoggedUser,
+) -> impl Responder {
+    if let Err(error) = require_admin(&pool, &logged_user.id).await {
+        return error;
+    }
+
+    let ItemId(id) = path.into_inner();
+    let reset_result = users_service::reset_user_password(&pool, &id, &query.0.password).await;
+    handle_action_result(reset_result)
+}
+
 #[utoipa::path(
     delete,
     path = "/api/v1/users/{id}",
@@ -6,13 +6,16 @@ use crate::errors::{ApiErrors, TokenErrors};
 use crate::models::{GrantType, TokenRequest, TokenResponse};
 use crate::repository::user_repository;
 
-use super::users_service;
+use super::{login_attempts_service, user_totp_service, users_service};
+
+const INVALID_CREDENTIALS_MESSAGE: &str = "Invalid username or password.";
 
 pub async fn get_token(
     pool: &PgPool,
     encoding_key: &EncodingKey,
     decoding_key: &DecodingKey,
     token_request: TokenRequest,
+    ip: Option<&str>,
 ) -> Result<TokenResponse, TokenErrors> {
     match token_request.grant_type {
         GrantType::Password => {
@@ -33,6 +36,8 @@ pub async fn get_token(
                 encoding_key,
                 &token_request.username.unwrap(), // Safe unwrap: already checked before
                 &token_request.password.unwrap(), // Safe unwrap: already checked before
+                token_request.totp.as_deref(),
+                ip,
             )
             .await
         }
@@ -59,19 +64,102 @@ async fn get_token_from_password(
     encoding_key: &EncodingKey,
     username: &str,
     password: &str,
+    totp: Option<&str>,
+    ip: Option<&str>,
 ) -> Result<TokenResponse, TokenErrors> {
-    let user: User = user_repository::find_first_by_username(pool, username)
+    let locked = login_attempts_service::is_login_locked(pool, username, ip)
+        .await
+        .map_err(map_login_attempts_error)?;
+    if locked {
+        return Err(TokenErrors::TooManyAttempts(String::from(
+            "Too many failed login attempts. Try again later.",
+        )));
+    }
+
+    let user: Option<User> = user_repository::find_first_by_username(pool, username)
         .await
-        .map_err(|_| TokenErrors::UnknownError(String::from("User could not be retrieved.")))?
-        .ok_or_else(|| TokenErrors::InvalidRequest(String::from("User does not exist.")))?;
+        .map_err(|_| TokenErrors::UnknownError(String::from("User could not be retrieved.")))?;
+
+    let verify_pass: bool = match &user {
+        Some(user) => crate::auth::verify_password(password, &user.password).map_err(|_| {
+            TokenErrors::UnknownError(String::from("Password verification failed."))
+        })?,
+        None => {
+            // Same work as a wrong password so both cases cannot be distinguished
+            crate::auth::verify_dummy_password(password);
+            false
+        }
+    };
+
+    match user {
+        Some(user) if verify_pass => {
+            let user_id = user.id.to_string();
+            check_totp(pool, &user_id, username, totp, ip).await?;
 
-    let verify_pass: bool = crate::auth::verify_password(password, &user.password)
-        .map_err(|_| TokenErrors::UnknownError(String::from("Password verification failed.")))?;
+            login_attempts_service::reset_login_attempts(pool, username)
+                .await
+                .map_err(map_login_attempts_error)?;
+
+            if crate::auth::password_needs_rehash(&user.password) {
+                // Login should not fail because the hash could not be upgraded
+                if users_service::rehash_user_password(pool, &user_id, password)
+                    .await
+                    .is_err()
+                {
+                    log::warn!("Password hash could not be upgraded for user {}.", user_id);
+                }
+            }
+
+            // Until the password gets changed only a limited token is provided
+            crate::auth::generate_token_response(&user_id, user.must_change_password, encoding_key)
+        }
+        _ => {
+            login_attempts_service::register_failed_login(pool, username, ip)
+                .await
+                .map_err(map_login_attempts_error)?;
+
+            Err(TokenErrors::InvalidGrant(String::from(
+                INVALID_CREDENTIALS_MESSAGE,
+            )))
+        }
+    }
+}
 
-    if verify_pass {
-        crate::auth::generate_token_response(&user.id.to_string(), encoding_key)
+async fn check_totp(
+    pool: &PgPool,
+    user_id: &str,
+    username: &str,
+    totp: Option<&str>,
+    ip: Option<&str>,
+) -> Result<(), TokenErrors> {
+    let totp_enabled = user_totp_service::is_totp_enabled(pool, user_id)
+        .await
+        .map_err(map_totp_error)?;
+    if !totp_enabled {
+        return Ok(());
+    }
+
+    let totp =
+        match totp {
+            Some(totp) if !crate::string_utils::string_is_blank(totp) => totp,
+            _ => return Err(TokenErrors::TotpRequired(String::from(
+                "Request was missing the 'totp' parameter required by two-factor authentication.",
+            ))),
+        };
+
+    let verify_totp = user_totp_service::verify_login_code(pool, user_id, totp)
+        .await
+        .map_err(map_totp_error)?;
+    if verify_totp {
+        Ok(())
     } else {
-        Err(TokenErrors::InvalidGrant(String::from("Wrong password.")))
+        login_attempts_service::register_failed_login(pool, username, ip)
+            .await
+            .map_err(map_login_attempts_error)?;
+
+        Err(TokenErrors::InvalidGrant(String::from(
+            "Invalid two-factor authentication code.",
+        )))
     }
 }
 
@@ -100,5 +188,19 @@ async fn get_token_from_refresh(
             _ => TokenErrors::UnknownError(String::default()), // Other errors will never happen with a get call
         })?;
 
-    crate::auth::generate_token_response(&user.id, encoding_key)
+    crate::auth::generate_token_response(&user.id, user.must_change_password, encoding_key)
+}
+
+fn map_login_attempts_error(err: ApiErrors) -> TokenErrors {
+    match err {
+        ApiErrors::UnknownError(msg) => TokenErrors::UnknownError(msg),
+        _ => TokenErrors::UnknownError(String::default()), // Other errors will never happen with login attempts
+    }
+}
+
+fn map_totp_error(err: ApiErrors) -> TokenErrors {
+    match err {
+        ApiErrors::UnknownError(msg) => TokenErrors::UnknownError(msg),
+        _ => TokenErrors::UnknownError(String::default()), // Other errors will never happen with an enabled TOTP
+    }
 }
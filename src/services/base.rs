@@ -7,7 +7,10 @@ use crate::entities::PageResult;
 use crate::errors::{
     error_message_builder, ApiErrors, MappingError, RepositoryError, SearchErrors,
 };
-use crate::models::{FilterDTO, Merge, ModelInfo, PageResultDTO, SearchDTO};
+use crate::models::{
+    AvailabilityDTO, FacetDTO, FilterDTO, Merge, ModelInfo, PageResultDTO, PlatformDTO,
+    PlatformType, SearchDTO,
+};
 use crate::providers::ImageClientProvider;
 
 pub fn handle_result<E, T>(repository_result: Result<E, RepositoryError>) -> Result<E, ApiErrors>
@@ -61,13 +64,13 @@ where
     Ok(entity_list.into_iter().map(T::from).collect())
 }
 
-pub(super) fn handle_get_list_paged_result<E, T>(
-    repository_result: Result<PageResult<E>, SearchErrors>,
-) -> Result<PageResultDTO<T>, ApiErrors>
+pub(super) fn handle_search_result<E, T>(
+    repository_result: Result<E, SearchErrors>,
+) -> Result<E, ApiErrors>
 where
-    T: From<E> + ModelInfo,
+    T: ModelInfo,
 {
-    let entity_search = repository_result.map_err(|err| match err {
+    repository_result.map_err(|err| match err {
         SearchErrors::Mapping(map_err) => {
             ApiErrors::InvalidParameter(error_message_builder::inner_error(
                 &error_message_builder::database_error(T::MODEL_NAME),
@@ -77,11 +80,37 @@ where
         SearchErrors::Repository(_) => {
             ApiErrors::UnknownError(error_message_builder::database_error(T::MODEL_NAME))
         }
-    })?;
+    })
+}
+
+pub(super) fn handle_get_list_paged_result<E, T>(
+    repository_result: Result<PageResult<E>, SearchErrors>,
+) -> Result<PageResultDTO<T>, ApiErrors>
+where
+    T: From<E> + ModelInfo,
+{
+    let entity_search = handle_search_result::<PageResult<E>, T>(repository_result)?;
+
+    let page = entity_search.page;
+    let size = entity_search.size;
+    let total_count = entity_search.total_count;
+    // Page is always the first one when using a cursor
+    let has_next = if entity_search.cursor {
+        Some(entity_search.next_cursor.is_some())
+    } else {
+        total_count.map(|total| (page + 1).saturating_mul(size) < total)
+    };
     Ok(PageResultDTO {
         data: entity_search.data.into_iter().map(T::from).collect(),
-        page: entity_search.page,
-        size: entity_search.size,
+        page,
+        size,
+        total_count,
+        total_pages: total_count.map(|total| if size == 0 { 0 } else { total.div_ceil(size) }),
+        has_next,
+        next_cursor: entity_search.next_cursor,
+        facets: entity_search
+            .facets
+            .map(|facets| facets.into_iter().map(FacetDTO::from).collect()),
     })
 }
 
@@ -212,6 +241,39 @@ pub(super) fn check_start_end(start_date: NaiveDate, end_date: NaiveDate) -> Res
     Ok(())
 }
 
+pub(super) fn check_availability(
+    availability: &AvailabilityDTO,
+    platform: &PlatformDTO,
+) -> Result<(), ApiErrors> {
+    if let Some(end_date) = availability.end_date {
+        if !matches!(platform.ptype, Some(PlatformType::Subscription)) {
+            return Err(ApiErrors::InvalidParameter(String::from(
+                "End date is only allowed on subscription platforms",
+            )));
+        }
+        check_start_end(availability.date, end_date)?;
+    }
+
+    if let Some(price) = availability.price {
+        if price < 0 {
+            return Err(ApiErrors::InvalidParameter(String::from(
+                "Price cannot be negative",
+            )));
+        }
+
+        let has_currency = availability
+            .currency
+            .as_ref()
+            .is_some_and(|currency| !currency.trim().is_empty());
+        if !has_currency {
+            return Err(ApiErrors::InvalidParameter(String::from(
+                "Currency is required with price",
+            )));
+        }
+    }
+    Ok(())
+}
+
 pub(super) fn optional_start_end_to_datetime(
     start_date: Option<NaiveDate>,
     end_date: Option<NaiveDate>,
@@ -257,8 +319,8 @@ where
             .iter()
             .map(move |field| crate::models::FilterDTO {
                 field: field.to_string(),
-                operator: crate::models::OperatorType::Contains,
-                value: crate::models::SearchValue::Value(quicksearch_value.clone()),
+                operator: crate::models::OperatorType::Similar,
+                value: Some(crate::models::SearchValue::Value(quicksearch_value.clone())),
                 chain_operator: Some(crate::models::ChainOperatorType::Or),
             })
             .collect();
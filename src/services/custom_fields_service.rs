@@ -0,0 +1,147 @@
+use sqlx::PgPool;
+
+use crate::entities::{CustomField, CustomFieldSearch};
+use crate::errors::ApiErrors;
+use crate::models::{
+    CustomFieldDTO, CustomFieldPageResult, CustomFieldType, NewCustomFieldDTO, SearchDTO,
+};
+use crate::repository::custom_field_repository;
+
+use super::base::{
+    create_merged, handle_action_result, handle_already_exists_result, handle_create_result,
+    handle_get_list_paged_result, handle_get_list_result, handle_get_result,
+    handle_not_found_result, handle_query_mapping, handle_update_result, update_merged,
+};
+
+pub async fn get_custom_field(
+    pool: &PgPool,
+    user_id: &str,
+    custom_field_id: &str,
+) -> Result<CustomFieldDTO, ApiErrors> {
+    let find_result = custom_field_repository::find_by_id(pool, user_id, custom_field_id).await;
+    handle_get_result(find_result)
+}
+
+pub async fn search_custom_fields(
+    pool: &PgPool,
+    user_id: &str,
+    search: SearchDTO,
+    quicksearch: Option<String>,
+) -> Result<CustomFieldPageResult, ApiErrors> {
+    let search = handle_query_mapping::<CustomFieldDTO, CustomFieldSearch>(search, quicksearch)?;
+    let find_result = custom_field_repository::search_all(pool, user_id, search).await;
+    handle_get_list_paged_result(find_result)
+}
+
+pub(super) async fn find_custom_fields(
+    pool: &PgPool,
+    user_id: &str,
+) -> Result<Vec<CustomFieldDTO>, ApiErrors> {
+    let find_result = custom_field_repository::find_all(pool, user_id).await;
+    handle_get_list_result(find_result)
+}
+
+pub async fn create_custom_field(
+    pool: &PgPool,
+    user_id: &str,
+    custom_field: NewCustomFieldDTO,
+) -> Result<CustomFieldDTO, ApiErrors> {
+    create_merged(
+        custom_field,
+        async move |created_custom_field_id| {
+            get_custom_field(pool, user_id, &created_custom_field_id).await
+        },
+        async move |custom_field_to_create| {
+            validate_custom_field(&custom_field_to_create)?;
+
+            let exists_result =
+                custom_field_repository::exists_with_unique(pool, user_id, &custom_field_to_create)
+                    .await;
+            handle_already_exists_result::<CustomFieldDTO>(exists_result)?;
+
+            let create_result =
+                custom_field_repository::create(pool, user_id, &custom_field_to_create).await;
+            handle_create_result::<String, CustomFieldDTO>(create_result)
+        },
+    )
+    .await
+}
+
+pub async fn update_custom_field(
+    pool: &PgPool,
+    user_id: &str,
+    custom_field_id: &str,
+    custom_field: NewCustomFieldDTO,
+) -> Result<(), ApiErrors> {
+    // Stored values would no longer match the type
+    if let Some(ftype) = custom_field.ftype {
+        let current = get_custom_field(pool, user_id, custom_field_id).await?;
+        if current.ftype != ftype {
+            return Err(ApiErrors::InvalidParameter(String::from(
+                "Custom field type cannot be changed.",
+            )));
+        }
+    }
+
+    update_merged(
+        custom_field,
+        async move || get_custom_field(pool, user_id, custom_field_id).await,
+        async move |custom_field_to_update| {
+            validate_custom_field(&custom_field_to_update)?;
+
+            let exists_result = custom_field_repository::exists_with_unique_except_id(
+                pool,
+                user_id,
+                &custom_field_to_update,
+                custom_field_id,
+            )
+            .await;
+            handle_already_exists_result::<CustomFieldDTO>(exists_result)?;
+
+            let update_result = custom_field_repository::update_by_id(
+                pool,
+                user_id,
+                custom_field_id,
+                &custom_field_to_update,
+            )
+            .await;
+            handle_update_result::<CustomFieldDTO>(update_result)
+        },
+    )
+    .await
+}
+
+pub async fn delete_custom_field(
+    pool: &PgPool,
+    user_id: &str,
+    custom_field_id: &str,
+) -> Result<(), ApiErrors> {
+    exists_custom_field(pool, user_id, custom_field_id).await?;
+
+    let delete_result = custom_field_repository::delete_by_id(pool, user_id, custom_field_id).await;
+    handle_action_result::<CustomFieldDTO>(delete_result)
+}
+
+pub async fn exists_custom_field(
+    pool: &PgPool,
+    user_id: &str,
+    custom_field_id: &str,
+) -> Result<(), ApiErrors> {
+    let exists_result = custom_field_repository::exists_by_id(pool, user_id, custom_field_id).await;
+    handle_not_found_result::<CustomFieldDTO>(exists_result)
+}
+
+fn validate_custom_field(custom_field: &CustomField) -> Result<(), ApiErrors> {
+    let ftype = CustomFieldType::try_from(custom_field.ftype).map_err(|_| {
+        ApiErrors::InvalidParameter(String::from("Custom field type is not valid."))
+    })?;
+    let options = serde_json::from_str::<Vec<String>>(&custom_field.options).unwrap_or_default();
+
+    if ftype == CustomFieldType::Enum && options.is_empty() {
+        return Err(ApiErrors::InvalidParameter(String::from(
+            "Enum custom field needs at least one option.",
+        )));
+    }
+
+    Ok(())
+}
@@ -1,13 +1,14 @@
 use chrono::NaiveDate;
 use sqlx::PgPool;
 
+use crate::entities::{Availability, DLCPurchase};
 use crate::errors::ApiErrors;
-use crate::models::{DLCAvailableDTO, PlatformAvailableDTO};
+use crate::models::{AvailabilityDTO, DLCAvailableDTO, PlatformAvailableDTO};
 use crate::repository::dlc_available_repository;
 
 use super::base::{
-    handle_action_result, handle_already_exists_result, handle_get_list_result,
-    handle_not_found_result,
+    check_availability, handle_action_result, handle_already_exists_result, handle_get_list_result,
+    handle_not_found_result, handle_result,
 };
 use super::{dlcs_service, platforms_service};
 
@@ -35,25 +36,66 @@ pub async fn get_dlc_platforms(
     handle_get_list_result(find_result)
 }
 
+pub(super) async fn find_purchases_between(
+    pool: &PgPool,
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<DLCPurchase>, ApiErrors> {
+    let find_result =
+        dlc_available_repository::find_all_purchases_between(pool, user_id, start_date, end_date)
+            .await;
+    handle_result::<Vec<DLCPurchase>, DLCAvailableDTO>(find_result)
+}
+
 pub async fn create_dlc_available(
     pool: &PgPool,
     user_id: &str,
     dlc_id: &str,
     platform_id: &str,
-    available_date: NaiveDate,
+    availability: AvailabilityDTO,
 ) -> Result<(), ApiErrors> {
     dlcs_service::exists_dlc(pool, user_id, dlc_id).await?;
-    platforms_service::exists_platform(pool, user_id, platform_id).await?;
+    let platform = platforms_service::get_platform(pool, user_id, platform_id).await?;
+    check_availability(&availability, &platform)?;
 
     let exists_result =
         dlc_available_repository::exists_by_id(pool, user_id, dlc_id, platform_id).await;
     handle_already_exists_result::<DLCAvailableDTO>(exists_result)?;
 
-    let create_result =
-        dlc_available_repository::create(pool, user_id, dlc_id, platform_id, available_date).await;
+    let create_result = dlc_available_repository::create(
+        pool,
+        user_id,
+        dlc_id,
+        platform_id,
+        &Availability::from(availability),
+    )
+    .await;
     handle_action_result::<DLCAvailableDTO>(create_result)
 }
 
+pub async fn update_dlc_available(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+    platform_id: &str,
+    availability: AvailabilityDTO,
+) -> Result<(), ApiErrors> {
+    exists_dlc_available(pool, user_id, dlc_id, platform_id).await?;
+    let platform = platforms_service::get_platform(pool, user_id, platform_id).await?;
+    check_availability(&availability, &platform)?;
+
+    let update_result = dlc_available_repository::update_by_id(
+        pool,
+        user_id,
+        dlc_id,
+        platform_id,
+        &Availability::from(availability),
+    )
+    .await;
+    handle_action_result::<DLCAvailableDTO>(update_result)
+}
+
 pub async fn delete_dlc_available(
     pool: &PgPool,
     user_id: &str,
@@ -1,13 +1,14 @@
 use chrono::NaiveDate;
 use sqlx::PgPool;
 
+use crate::entities::DLCFinishDate;
 use crate::errors::ApiErrors;
 use crate::models::DLCFinish;
 use crate::repository::dlc_finish_repository;
 
 use super::base::{
     handle_action_result, handle_already_exists_result, handle_get_list_result_raw,
-    handle_get_result_raw, handle_not_found_result,
+    handle_get_result_raw, handle_not_found_result, handle_result,
 };
 use super::dlcs_service;
 
@@ -33,6 +34,17 @@ pub async fn get_dlc_finishes(
     handle_get_list_result_raw::<NaiveDate, DLCFinish>(find_result)
 }
 
+pub(super) async fn find_first_dlc_finishes_by_dlcs(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_ids: Vec<String>,
+) -> Result<Vec<DLCFinishDate>, ApiErrors> {
+    let find_result =
+        dlc_finish_repository::find_all_first_by_user_id_and_dlc_id_in(pool, user_id, dlc_ids)
+            .await;
+    handle_result::<Vec<DLCFinishDate>, DLCFinish>(find_result)
+}
+
 pub async fn create_dlc_finish(
     pool: &PgPool,
     user_id: &str,
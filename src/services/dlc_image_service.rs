@@ -1,5 +1,5 @@
 use crate::errors::ApiErrors;
-use crate::models::{DLCAvailableDTO, DLCWithFinishDTO, DLCDTO};
+use crate::models::{DLCAvailableDTO, DLCFinishedReviewDTO, DLCWithFinishDTO, DLCDTO};
 use crate::providers::ImageClientProvider;
 
 use super::base::{build_image_filename, extract_image_name, handle_image_client_provider};
@@ -51,6 +51,19 @@ pub fn populate_dlcs_with_finish_cover(
     }
 }
 
+pub fn populate_dlcs_finished_review_cover(
+    provider: &ImageClientProvider,
+    dlcs: &mut Vec<DLCFinishedReviewDTO>,
+) {
+    if let Ok(client) = handle_image_client_provider(provider) {
+        for dlc in dlcs {
+            if let Some(cover_filename) = &dlc.cover_filename {
+                dlc.cover_url = Some(client.get_image_uri(DLC_FOLDER, cover_filename));
+            }
+        }
+    }
+}
+
 pub(super) async fn set_dlc_cover(
     image_client_provider: &ImageClientProvider,
     user_id: &str,
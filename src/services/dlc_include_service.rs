@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+
+use crate::errors::{error_message_builder, ApiErrors};
+use crate::models::{DLCPageResult, ModelInfo, TagDTO, DLCDTO};
+
+use super::dlc_tags_service;
+
+const INCLUDE_TAGS: &str = "tags";
+
+pub async fn include_dlc(
+    pool: &PgPool,
+    user_id: &str,
+    mut dlc: DLCDTO,
+    include: Option<String>,
+) -> Result<DLCDTO, ApiErrors> {
+    populate_dlcs_includes(pool, user_id, std::slice::from_mut(&mut dlc), include).await?;
+    Ok(dlc)
+}
+
+pub async fn include_dlcs_page(
+    pool: &PgPool,
+    user_id: &str,
+    mut page: DLCPageResult,
+    include: Option<String>,
+) -> Result<DLCPageResult, ApiErrors> {
+    populate_dlcs_includes(pool, user_id, &mut page.data, include).await?;
+    Ok(page)
+}
+
+/// Embeds the requested relations, fetching each one with a single query for all the DLCs
+async fn populate_dlcs_includes(
+    pool: &PgPool,
+    user_id: &str,
+    dlcs: &mut [DLCDTO],
+    include: Option<String>,
+) -> Result<(), ApiErrors> {
+    let includes = parse_includes(include)?;
+    if includes.is_empty() || dlcs.is_empty() {
+        return Ok(());
+    }
+
+    let dlc_ids: Vec<String> = dlcs.iter().map(|dlc| dlc.id.clone()).collect();
+
+    if includes.contains(&INCLUDE_TAGS) {
+        let mut map = HashMap::<String, Vec<TagDTO>>::new();
+        for tag in dlc_tags_service::find_tags_by_dlcs(pool, user_id, dlc_ids).await? {
+            map.entry(tag.dlc_id.to_string())
+                .or_default()
+                .push(TagDTO::from(tag));
+        }
+        for dlc in dlcs.iter_mut() {
+            dlc.tags = Some(map.remove(&dlc.id).unwrap_or_default());
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_includes(include: Option<String>) -> Result<Vec<&'static str>, ApiErrors> {
+    match include {
+        Some(include) => include
+            .split(',')
+            .map(str::trim)
+            .filter(|relation| !relation.is_empty())
+            .map(|relation| {
+                [INCLUDE_TAGS]
+                    .into_iter()
+                    .find(|available| *available == relation)
+                    .ok_or_else(|| {
+                        ApiErrors::InvalidParameter(error_message_builder::relation_not_found(
+                            DLCDTO::MODEL_NAME,
+                            relation,
+                        ))
+                    })
+            })
+            .collect(),
+        None => Ok(vec![]),
+    }
+}
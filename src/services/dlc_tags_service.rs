@@ -0,0 +1,82 @@
+use sqlx::PgPool;
+
+use crate::entities::TagWithDLCId;
+use crate::errors::ApiErrors;
+use crate::models::{DLCTag, TagDTO, DLCDTO};
+use crate::repository::dlc_tag_repository;
+
+use super::base::{
+    handle_action_result, handle_already_exists_result, handle_get_list_result,
+    handle_not_found_result, handle_result,
+};
+use super::{dlcs_service, tags_service};
+
+pub async fn get_tag_dlcs(
+    pool: &PgPool,
+    user_id: &str,
+    tag_id: &str,
+) -> Result<Vec<DLCDTO>, ApiErrors> {
+    tags_service::exists_tag(pool, user_id, tag_id).await?;
+
+    let find_result = dlc_tag_repository::find_all_dlcs_with_tag(pool, user_id, tag_id).await;
+    handle_get_list_result(find_result)
+}
+
+pub async fn get_dlc_tags(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+) -> Result<Vec<TagDTO>, ApiErrors> {
+    dlcs_service::exists_dlc(pool, user_id, dlc_id).await?;
+
+    let find_result = dlc_tag_repository::find_all_tags_with_dlc(pool, user_id, dlc_id).await;
+    handle_get_list_result(find_result)
+}
+
+pub(super) async fn find_tags_by_dlcs(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_ids: Vec<String>,
+) -> Result<Vec<TagWithDLCId>, ApiErrors> {
+    let find_result =
+        dlc_tag_repository::find_all_tags_with_dlc_id_in(pool, user_id, dlc_ids).await;
+    handle_result::<Vec<TagWithDLCId>, TagDTO>(find_result)
+}
+
+pub async fn create_dlc_tag(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+    tag_id: &str,
+) -> Result<(), ApiErrors> {
+    dlcs_service::exists_dlc(pool, user_id, dlc_id).await?;
+    tags_service::exists_tag(pool, user_id, tag_id).await?;
+
+    let exists_result = dlc_tag_repository::exists_by_id(pool, user_id, dlc_id, tag_id).await;
+    handle_already_exists_result::<DLCTag>(exists_result)?;
+
+    let create_result = dlc_tag_repository::create(pool, user_id, dlc_id, tag_id).await;
+    handle_action_result::<DLCTag>(create_result)
+}
+
+pub async fn delete_dlc_tag(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+    tag_id: &str,
+) -> Result<(), ApiErrors> {
+    exists_dlc_tag(pool, user_id, dlc_id, tag_id).await?;
+
+    let delete_result = dlc_tag_repository::delete_by_id(pool, user_id, dlc_id, tag_id).await;
+    handle_action_result::<DLCTag>(delete_result)
+}
+
+pub async fn exists_dlc_tag(
+    pool: &PgPool,
+    user_id: &str,
+    dlc_id: &str,
+    tag_id: &str,
+) -> Result<(), ApiErrors> {
+    let exists_result = dlc_tag_repository::exists_by_id(pool, user_id, dlc_id, tag_id).await;
+    handle_not_found_result::<DLCTag>(exists_result)
+}
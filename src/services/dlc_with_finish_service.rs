@@ -1,12 +1,15 @@
 use chrono::NaiveDate;
 use sqlx::PgPool;
 
-use crate::entities::DLCSearch;
+use crate::entities::{DLCSearch, DLCWithDate};
 use crate::errors::ApiErrors;
 use crate::models::{DLCWithFinishDTO, DLCWithFinishPageResult, SearchDTO};
 use crate::repository::dlc_with_finish_repository;
 
-use super::base::{check_optional_start_end, handle_get_list_paged_result, handle_query_mapping};
+use super::base::{
+    check_optional_start_end, check_start_end, handle_get_list_paged_result, handle_query_mapping,
+    handle_result,
+};
 
 pub async fn search_first_finished_dlcs(
     pool: &PgPool,
@@ -43,3 +46,17 @@ pub async fn search_last_finished_dlcs(
     .await;
     handle_get_list_paged_result(find_result)
 }
+
+pub(super) async fn find_dlc_with_finishes_between(
+    pool: &PgPool,
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<DLCWithDate>, ApiErrors> {
+    check_start_end(start_date, end_date)?;
+
+    let find_result =
+        dlc_with_finish_repository::find_all_by_date_between(pool, user_id, start_date, end_date)
+            .await;
+    handle_result::<Vec<DLCWithDate>, DLCWithFinishDTO>(find_result)
+}
@@ -1,6 +1,7 @@
+use chrono::NaiveDate;
 use sqlx::PgPool;
 
-use crate::entities::DLCSearch;
+use crate::entities::{DLCSearch, DLC};
 use crate::errors::{error_message_builder, ApiErrors};
 use crate::models::{DLCPageResult, GameDTO, NewDLCDTO, SearchDTO, DLCDTO};
 use crate::providers::ImageClientProvider;
@@ -9,7 +10,8 @@ use crate::repository::dlc_repository;
 use super::base::{
     create_merged, handle_action_result, handle_already_exists_result, handle_create_result,
     handle_get_list_paged_result, handle_get_list_result, handle_get_result,
-    handle_not_found_result, handle_query_mapping, handle_update_result, update_merged,
+    handle_not_found_result, handle_query_mapping, handle_result, handle_update_result,
+    start_end_to_datetime, update_merged,
 };
 use super::{dlc_image_service, games_service};
 
@@ -41,6 +43,36 @@ pub async fn get_game_dlcs(
     handle_get_list_result(find_result)
 }
 
+pub(super) async fn find_dlcs_by_base_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<DLC>, ApiErrors> {
+    let find_result = dlc_repository::find_all_by_base_game_id_in(pool, user_id, game_ids).await;
+    handle_result::<Vec<DLC>, DLCDTO>(find_result)
+}
+
+/// DLCs of the given base games finished or updated between the dates, both inclusive.
+pub(super) async fn find_dlcs_by_base_games_with_activity_between(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<DLC>, ApiErrors> {
+    let (start_datetime, end_datetime) = start_end_to_datetime(start_date, end_date);
+
+    let find_result = dlc_repository::find_all_by_base_game_id_in_with_activity_between(
+        pool,
+        user_id,
+        game_ids,
+        start_datetime,
+        end_datetime,
+    )
+    .await;
+    handle_result::<Vec<DLC>, DLCDTO>(find_result)
+}
+
 pub async fn search_dlcs(
     pool: &PgPool,
     user_id: &str,
@@ -1,16 +1,24 @@
-use chrono::NaiveDate;
+use chrono::{Duration, NaiveDate};
 use sqlx::PgPool;
 
+use crate::entities::{
+    Availability, GamePurchase, GameWithAvailabilityEnd, PlatformWithGameIdAndDate,
+};
 use crate::errors::ApiErrors;
-use crate::models::{GameAvailableDTO, GameStatus, NewGameDTO, PlatformAvailableDTO};
+use crate::models::{
+    AvailabilityDTO, GameAvailableDTO, GameExpiringDTO, GameStatus, NewGameDTO,
+    PlatformAvailableDTO,
+};
 use crate::repository::game_available_repository;
 
 use super::base::{
-    handle_action_result, handle_already_exists_result, handle_get_list_result,
-    handle_not_found_result, handle_result,
+    check_availability, handle_action_result, handle_already_exists_result, handle_get_list_result,
+    handle_get_list_result_raw, handle_not_found_result, handle_result,
 };
 use super::{games_service, platforms_service};
 
+const DEFAULT_EXPIRING_DAYS: u32 = 30;
+
 pub async fn get_platform_games(
     pool: &PgPool,
     user_id: &str,
@@ -35,15 +43,60 @@ pub async fn get_game_platforms(
     handle_get_list_result(find_result)
 }
 
+/// Games whose availability ends within the given days, soonest first
+pub async fn get_expiring_games(
+    pool: &PgPool,
+    user_id: &str,
+    days: Option<u32>,
+) -> Result<Vec<GameExpiringDTO>, ApiErrors> {
+    let today = crate::date_utils::now().date();
+    let days = Duration::days(i64::from(days.unwrap_or(DEFAULT_EXPIRING_DAYS)));
+    let end_date = today
+        .checked_add_signed(days)
+        .ok_or_else(|| ApiErrors::InvalidParameter(String::from("Days out of range")))?;
+
+    let find_result = game_available_repository::find_all_games_with_end_date_between(
+        pool, user_id, today, end_date,
+    )
+    .await;
+    let games =
+        handle_get_list_result_raw::<GameWithAvailabilityEnd, GameAvailableDTO>(find_result)?;
+    Ok(games.into_iter().map(GameExpiringDTO::from).collect())
+}
+
+pub(super) async fn find_platforms_by_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<PlatformWithGameIdAndDate>, ApiErrors> {
+    let find_result =
+        game_available_repository::find_all_platforms_with_game_id_in(pool, user_id, game_ids)
+            .await;
+    handle_result::<Vec<PlatformWithGameIdAndDate>, PlatformAvailableDTO>(find_result)
+}
+
+pub(super) async fn find_purchases_between(
+    pool: &PgPool,
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<GamePurchase>, ApiErrors> {
+    let find_result =
+        game_available_repository::find_all_purchases_between(pool, user_id, start_date, end_date)
+            .await;
+    handle_result::<Vec<GamePurchase>, GameAvailableDTO>(find_result)
+}
+
 pub async fn create_game_available(
     pool: &PgPool,
     user_id: &str,
     game_id: &str,
     platform_id: &str,
-    available_date: NaiveDate,
+    availability: AvailabilityDTO,
 ) -> Result<(), ApiErrors> {
     let game = games_service::get_game(pool, user_id, game_id).await?;
-    platforms_service::exists_platform(pool, user_id, platform_id).await?;
+    let platform = platforms_service::get_platform(pool, user_id, platform_id).await?;
+    check_availability(&availability, &platform)?;
 
     let exists_result =
         game_available_repository::exists_by_id(pool, user_id, game_id, platform_id).await;
@@ -64,17 +117,45 @@ pub async fn create_game_available(
                 save_folder: None,
                 screenshot_folder: None,
                 backup: None,
+                custom_fields: None,
             },
         )
         .await?
     }
 
-    let create_result =
-        game_available_repository::create(pool, user_id, game_id, platform_id, available_date)
-            .await;
+    let create_result = game_available_repository::create(
+        pool,
+        user_id,
+        game_id,
+        platform_id,
+        &Availability::from(availability),
+    )
+    .await;
     handle_action_result::<GameAvailableDTO>(create_result)
 }
 
+pub async fn update_game_available(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    platform_id: &str,
+    availability: AvailabilityDTO,
+) -> Result<(), ApiErrors> {
+    exists_game_available(pool, user_id, game_id, platform_id).await?;
+    let platform = platforms_service::get_platform(pool, user_id, platform_id).await?;
+    check_availability(&availability, &platform)?;
+
+    let update_result = game_available_repository::update_by_id(
+        pool,
+        user_id,
+        game_id,
+        platform_id,
+        &Availability::from(availability),
+    )
+    .await;
+    handle_action_result::<GameAvailableDTO>(update_result)
+}
+
 pub async fn delete_game_available(
     pool: &PgPool,
     user_id: &str,
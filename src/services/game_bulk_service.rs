@@ -0,0 +1,314 @@
+use std::collections::{HashMap, HashSet};
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::entities::{Availability, Game, GameBulkOutcome, GameSearch};
+use crate::errors::{error_message_builder, ApiErrors};
+use crate::models::{
+    GameAvailableDTO, GameBulkDTO, GameBulkItemResultDTO, GameBulkPlatformsDTO, GameBulkResultDTO,
+    GameBulkTagsDTO, GameBulkUpdateDTO, GameDTO, GameStatus, GameTag, Merge, ModelInfo, NewGameDTO,
+    SearchDTO,
+};
+use crate::providers::ImageClientProvider;
+use crate::repository::{game_bulk_repository, game_repository};
+
+use super::base::{handle_query_mapping, handle_result, handle_search_result};
+use super::{
+    game_custom_values_service, game_image_service, games_service, platforms_service, tags_service,
+};
+
+pub async fn update_games(
+    pool: &PgPool,
+    user_id: &str,
+    mut bulk: GameBulkUpdateDTO,
+) -> Result<GameBulkResultDTO, ApiErrors> {
+    let custom_values = game_custom_values_service::validate_custom_values(
+        pool,
+        user_id,
+        bulk.game.custom_fields.take(),
+    )
+    .await?;
+    let game_ids = find_target_games(pool, user_id, bulk.ids, bulk.search).await?;
+
+    let changes = bulk.game;
+    let to_wishlist = changes
+        .status
+        .as_ref()
+        .is_some_and(|status| *status == GameStatus::Wishlist);
+    let update_result = game_bulk_repository::update_multiple(
+        pool,
+        user_id,
+        &parse_ids(&game_ids),
+        |game| Game::from(GameDTO::from(game).merge(changes.clone())),
+        to_wishlist,
+        &custom_values,
+    )
+    .await;
+    let outcomes = handle_result::<_, GameDTO>(update_result)?;
+
+    Ok(build_result(&game_ids, outcomes))
+}
+
+pub async fn delete_games(
+    pool: &PgPool,
+    image_client_provider: &ImageClientProvider,
+    user_id: &str,
+    bulk: GameBulkDTO,
+) -> Result<GameBulkResultDTO, ApiErrors> {
+    let game_ids = find_target_games(pool, user_id, bulk.ids, bulk.search).await?;
+
+    let delete_result =
+        game_bulk_repository::delete_multiple(pool, user_id, &parse_ids(&game_ids)).await;
+    let deleted_games = handle_result::<_, GameDTO>(delete_result)?;
+
+    // Covers are only removed once the games are gone
+    for game in &deleted_games {
+        if let Some(cover_filename) = &game.cover_filename {
+            let delete_cover_result =
+                game_image_service::delete_game_cover(image_client_provider, cover_filename).await;
+            if delete_cover_result.is_err() {
+                log::warn!("Game deletion - Image client could not delete Game with image.")
+            }
+        }
+    }
+
+    let deleted_ids = deleted_games.iter().map(|game| game.id);
+    Ok(build_result(&game_ids, written(deleted_ids)))
+}
+
+pub async fn link_games_tags(
+    pool: &PgPool,
+    user_id: &str,
+    bulk: GameBulkTagsDTO,
+) -> Result<GameBulkResultDTO, ApiErrors> {
+    for tag_id in &bulk.tag_ids {
+        tags_service::exists_tag(pool, user_id, tag_id).await?;
+    }
+    let game_ids = find_target_games(pool, user_id, bulk.ids, bulk.search).await?;
+
+    let create_result = game_bulk_repository::create_tags_multiple(
+        pool,
+        user_id,
+        &parse_ids(&game_ids),
+        &bulk.tag_ids,
+    )
+    .await;
+    let linked_ids = handle_result::<_, GameTag>(create_result)?;
+
+    Ok(build_result(&game_ids, written(linked_ids)))
+}
+
+pub async fn unlink_games_tags(
+    pool: &PgPool,
+    user_id: &str,
+    bulk: GameBulkTagsDTO,
+) -> Result<GameBulkResultDTO, ApiErrors> {
+    for tag_id in &bulk.tag_ids {
+        tags_service::exists_tag(pool, user_id, tag_id).await?;
+    }
+    let game_ids = find_target_games(pool, user_id, bulk.ids, bulk.search).await?;
+
+    let delete_result = game_bulk_repository::delete_tags_multiple(
+        pool,
+        user_id,
+        &parse_ids(&game_ids),
+        &bulk.tag_ids,
+    )
+    .await;
+    let unlinked_ids = handle_result::<_, GameTag>(delete_result)?;
+
+    Ok(build_result(&game_ids, written(unlinked_ids)))
+}
+
+pub async fn link_games_platforms(
+    pool: &PgPool,
+    user_id: &str,
+    bulk: GameBulkPlatformsDTO,
+) -> Result<GameBulkResultDTO, ApiErrors> {
+    for platform_id in &bulk.platform_ids {
+        platforms_service::exists_platform(pool, user_id, platform_id).await?;
+    }
+    // Purchase details are not shared between games, so they are left empty
+    let availability = Availability {
+        added_date: bulk
+            .available_date
+            .unwrap_or_else(|| crate::date_utils::now().date()),
+        end_date: None,
+        price: None,
+        currency: None,
+        store: None,
+        gift: false,
+        subscription: false,
+    };
+    let game_ids = find_target_games(pool, user_id, bulk.ids, bulk.search).await?;
+
+    // Same as linking one by one, an available game is no longer wishlisted
+    let make_available = |game: Game| {
+        let game = GameDTO::from(game);
+        if game.status != GameStatus::Wishlist || bulk.platform_ids.is_empty() {
+            return None;
+        }
+
+        Some(Game::from(game.merge(NewGameDTO {
+            status: Some(GameStatus::NextUp),
+            name: None,
+            edition: None,
+            release_year: None,
+            rating: None,
+            notes: None,
+            save_folder: None,
+            screenshot_folder: None,
+            backup: None,
+            custom_fields: None,
+        })))
+    };
+    let create_result = game_bulk_repository::create_platforms_multiple(
+        pool,
+        user_id,
+        &parse_ids(&game_ids),
+        &bulk.platform_ids,
+        &availability,
+        make_available,
+    )
+    .await;
+    let linked_ids = handle_result::<_, GameAvailableDTO>(create_result)?;
+
+    Ok(build_result(&game_ids, written(linked_ids)))
+}
+
+pub async fn unlink_games_platforms(
+    pool: &PgPool,
+    user_id: &str,
+    bulk: GameBulkPlatformsDTO,
+) -> Result<GameBulkResultDTO, ApiErrors> {
+    for platform_id in &bulk.platform_ids {
+        platforms_service::exists_platform(pool, user_id, platform_id).await?;
+    }
+    let game_ids = find_target_games(pool, user_id, bulk.ids, bulk.search).await?;
+
+    let delete_result = game_bulk_repository::delete_platforms_multiple(
+        pool,
+        user_id,
+        &parse_ids(&game_ids),
+        &bulk.platform_ids,
+    )
+    .await;
+    let unlinked_ids = handle_result::<_, GameAvailableDTO>(delete_result)?;
+
+    Ok(build_result(&game_ids, written(unlinked_ids)))
+}
+
+async fn find_target_games(
+    pool: &PgPool,
+    user_id: &str,
+    ids: Option<Vec<String>>,
+    search: Option<SearchDTO>,
+) -> Result<Vec<String>, ApiErrors> {
+    match (ids, search) {
+        (Some(ids), None) => {
+            // Repeated ids are only processed once, however they are written
+            let mut seen_ids = HashSet::new();
+            Ok(ids
+                .into_iter()
+                .filter(|id| {
+                    let normalized_id = Uuid::parse_str(id)
+                        .map(|id| id.to_string())
+                        .unwrap_or_else(|_| id.clone());
+                    seen_ids.insert(normalized_id)
+                })
+                .collect())
+        }
+        (None, Some(search)) => {
+            // Every match is targeted, so paging from the search is ignored
+            let search = SearchDTO {
+                page: None,
+                size: None,
+                count: None,
+                cursor: None,
+                facets: None,
+                ..search
+            };
+            let search = games_service::map_game_search(pool, user_id, search).await?;
+            let search = handle_query_mapping::<GameDTO, GameSearch>(search, None)?;
+            let find_result =
+                game_repository::find_all_ids_with_search(pool, user_id, search).await;
+            let ids = handle_search_result::<_, GameDTO>(find_result)?;
+            Ok(ids.into_iter().map(|id| id.to_string()).collect())
+        }
+        _ => Err(ApiErrors::InvalidParameter(String::from(
+            "Either ids or search must be provided",
+        ))),
+    }
+}
+
+fn succeeded(game_id: &str) -> GameBulkItemResultDTO {
+    GameBulkItemResultDTO {
+        id: game_id.to_string(),
+        success: true,
+        error: None,
+    }
+}
+
+fn failed(game_id: &str, message: String) -> GameBulkItemResultDTO {
+    GameBulkItemResultDTO {
+        id: game_id.to_string(),
+        success: false,
+        error: Some(message),
+    }
+}
+
+/// Only valid ids can match a game, the rest end up not found
+fn parse_ids(game_ids: &[String]) -> Vec<String> {
+    game_ids
+        .iter()
+        .filter_map(|game_id| Uuid::parse_str(game_id).ok())
+        .map(|game_id| game_id.to_string())
+        .collect()
+}
+
+fn written(game_ids: impl IntoIterator<Item = Uuid>) -> HashMap<Uuid, GameBulkOutcome> {
+    game_ids
+        .into_iter()
+        .map(|game_id| (game_id, GameBulkOutcome::Written))
+        .collect()
+}
+
+/// Result of every targeted game in the requested order, from what was actually written
+fn build_result(
+    game_ids: &[String],
+    mut outcomes: HashMap<Uuid, GameBulkOutcome>,
+) -> GameBulkResultDTO {
+    let results: Vec<GameBulkItemResultDTO> = game_ids
+        .iter()
+        .map(|game_id| {
+            let outcome = Uuid::parse_str(game_id)
+                .ok()
+                .and_then(|id| outcomes.remove(&id));
+            match outcome {
+                Some(GameBulkOutcome::Written) => succeeded(game_id),
+                Some(GameBulkOutcome::AlreadyExists) => failed(
+                    game_id,
+                    error_message_builder::already_exists(
+                        GameDTO::MODEL_NAME,
+                        GameDTO::UNIQUE_FIELDS,
+                    ),
+                ),
+                Some(GameBulkOutcome::Available) => {
+                    failed(game_id, String::from("Game has platforms available"))
+                }
+                None => failed(
+                    game_id,
+                    error_message_builder::not_found(GameDTO::MODEL_NAME, GameDTO::ID_FIELDS),
+                ),
+            }
+        })
+        .collect();
+    let succeeded = results.iter().filter(|result| result.success).count() as u64;
+
+    GameBulkResultDTO {
+        succeeded,
+        failed: results.len() as u64 - succeeded,
+        results,
+    }
+}
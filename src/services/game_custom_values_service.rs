@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::entities::{
+    GameCustomValue, GameCustomValueIden, GameCustomValueWithField, CUSTOM_FIELD_SEARCH_PREFIX,
+};
+use crate::errors::{error_message_builder, ApiErrors};
+use crate::models::{
+    CustomFieldDTO, CustomFieldType, FilterGroupDTO, FilterNodeDTO, GameDTO, ModelInfo, SearchDTO,
+};
+use crate::repository::game_custom_value_repository;
+
+use super::base::handle_result;
+use super::custom_fields_service;
+
+/// Prefix of custom fields in game searches, followed by the name of the field
+const CUSTOM_FIELD_PREFIX: &str = "custom.";
+
+pub(super) async fn find_custom_values_by_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameCustomValueWithField>, ApiErrors> {
+    let find_result =
+        game_custom_value_repository::find_all_with_game_id_in(pool, user_id, game_ids).await;
+    handle_result::<Vec<GameCustomValueWithField>, CustomFieldDTO>(find_result)
+}
+
+/// Checks every value matches the type of its field, null values are removed when set
+pub(super) async fn validate_custom_values(
+    pool: &PgPool,
+    user_id: &str,
+    custom_values: Option<HashMap<String, serde_json::Value>>,
+) -> Result<Vec<GameCustomValue>, ApiErrors> {
+    let custom_values = match custom_values {
+        Some(custom_values) if !custom_values.is_empty() => custom_values,
+        _ => return Ok(vec![]),
+    };
+
+    let custom_fields = custom_fields_service::find_custom_fields(pool, user_id).await?;
+    custom_values
+        .into_iter()
+        .map(|(name, value)| {
+            let custom_field = custom_fields
+                .iter()
+                .find(|custom_field| custom_field.name == name)
+                .ok_or_else(|| {
+                    ApiErrors::InvalidParameter(error_message_builder::field_not_found(
+                        GameDTO::MODEL_NAME,
+                        &format!("{CUSTOM_FIELD_PREFIX}{name}"),
+                    ))
+                })?;
+            to_custom_value(custom_field, value)
+        })
+        .collect()
+}
+
+/// Translates custom fields used to filter or sort games by their name into the searchable ones.
+/// Unknown names are kept, so they fail as any other unknown field.
+pub(super) async fn map_custom_field_search(
+    pool: &PgPool,
+    user_id: &str,
+    mut search: SearchDTO,
+) -> Result<SearchDTO, ApiErrors> {
+    let mut uses_custom_fields = false;
+    for_each_search_field(&mut search, &mut |field| {
+        uses_custom_fields |= field.starts_with(CUSTOM_FIELD_PREFIX)
+    });
+    if !uses_custom_fields {
+        return Ok(search);
+    }
+
+    let search_names: HashMap<String, String> =
+        custom_fields_service::find_custom_fields(pool, user_id)
+            .await?
+            .into_iter()
+            .map(|custom_field| {
+                (
+                    format!("{CUSTOM_FIELD_PREFIX}{}", custom_field.name),
+                    custom_field_search_name(&custom_field),
+                )
+            })
+            .collect();
+    for_each_search_field(&mut search, &mut |field| {
+        if let Some(search_name) = search_names.get(field.as_str()) {
+            *field = search_name.clone();
+        }
+    });
+
+    Ok(search)
+}
+
+fn for_each_search_field(search: &mut SearchDTO, action: &mut impl FnMut(&mut String)) {
+    for filter in search.filter.iter_mut().flatten() {
+        action(&mut filter.field);
+    }
+    if let Some(filter_group) = &mut search.filter_group {
+        for_each_filter_group_field(filter_group, action);
+    }
+    for sort in search.sort.iter_mut().flatten() {
+        action(&mut sort.field);
+    }
+}
+
+fn for_each_filter_group_field(
+    filter_group: &mut FilterGroupDTO,
+    action: &mut impl FnMut(&mut String),
+) {
+    for node in &mut filter_group.filters {
+        match node {
+            FilterNodeDTO::Filter(filter) => action(&mut filter.field),
+            FilterNodeDTO::Group(group) => for_each_filter_group_field(group, action),
+        }
+    }
+}
+
+fn custom_field_search_name(custom_field: &CustomFieldDTO) -> String {
+    let column = match custom_field.ftype {
+        CustomFieldType::Text | CustomFieldType::Enum => GameCustomValueIden::TextValue,
+        CustomFieldType::Number => GameCustomValueIden::NumberValue,
+        CustomFieldType::Date => GameCustomValueIden::DateValue,
+        CustomFieldType::Bool => GameCustomValueIden::BoolValue,
+    };
+    format!(
+        "{CUSTOM_FIELD_SEARCH_PREFIX}{}:{}",
+        column.to_string(),
+        custom_field.id
+    )
+}
+
+fn to_custom_value(
+    custom_field: &CustomFieldDTO,
+    value: serde_json::Value,
+) -> Result<GameCustomValue, ApiErrors> {
+    let mut custom_value = GameCustomValue {
+        field_id: custom_field.id.clone(),
+        text_value: None,
+        number_value: None,
+        date_value: None,
+        bool_value: None,
+    };
+
+    let valid = match (custom_field.ftype, value) {
+        (_, serde_json::Value::Null) => true,
+        (CustomFieldType::Text, serde_json::Value::String(text)) => {
+            custom_value.text_value = Some(text);
+            true
+        }
+        (CustomFieldType::Enum, serde_json::Value::String(option))
+            if custom_field.options.contains(&option) =>
+        {
+            custom_value.text_value = Some(option);
+            true
+        }
+        (CustomFieldType::Number, serde_json::Value::Number(number)) => {
+            custom_value.number_value = number.as_f64();
+            custom_value.number_value.is_some()
+        }
+        (CustomFieldType::Date, serde_json::Value::String(date)) => {
+            custom_value.date_value = date.parse::<NaiveDate>().ok();
+            custom_value.date_value.is_some()
+        }
+        (CustomFieldType::Bool, serde_json::Value::Bool(bool_value)) => {
+            custom_value.bool_value = Some(bool_value);
+            true
+        }
+        _ => false,
+    };
+
+    if !valid {
+        return Err(ApiErrors::InvalidParameter(format!(
+            "Value of custom field \"{}\" is not valid.",
+            custom_field.name
+        )));
+    }
+
+    Ok(custom_value)
+}
@@ -34,6 +34,16 @@ pub async fn get_game_finishes(
     handle_get_list_result_raw::<NaiveDate, GameFinishDTO>(find_result)
 }
 
+pub(super) async fn find_game_finishes_by_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameFinish>, ApiErrors> {
+    let find_result =
+        game_finish_repository::find_all_by_user_id_and_game_id_in(pool, user_id, game_ids).await;
+    handle_result::<Vec<GameFinish>, GameFinishDTO>(find_result)
+}
+
 pub(super) async fn find_first_game_finishes_by_games(
     pool: &PgPool,
     user_id: &str,
@@ -1,11 +1,13 @@
 use crate::errors::ApiErrors;
 use crate::models::{
-    GameAvailableDTO, GameDTO, GameFinishedReviewDTO, GamePlayedReviewDTO, GameWithFinishDTO,
-    GameWithLogDTO, GameWithLogsDTO,
+    GameAvailableDTO, GameDTO, GameExpiringDTO, GameFinishedReviewDTO, GameInSeriesDTO,
+    GamePlayedReviewDTO, GameSpendingReviewDTO, GameStatusReviewDTO, GameWithFinishDTO,
+    GameWithLogDTO, GameWithLogsDTO, RelatedGameDTO,
 };
 use crate::providers::ImageClientProvider;
 
 use super::base::{build_image_filename, extract_image_name, handle_image_client_provider};
+use super::{dlc_image_service, platform_image_service};
 
 const GAME_FOLDER: &str = "Game";
 const GAME_HEADER_SUFFIX: &str = "header";
@@ -18,6 +20,15 @@ pub fn populate_game_cover(provider: &ImageClientProvider, game: &mut GameDTO) {
     }
 }
 
+pub fn populate_game_includes_images(provider: &ImageClientProvider, game: &mut GameDTO) {
+    if let Some(platforms) = &mut game.platforms {
+        platform_image_service::populate_platforms_available_icon(provider, platforms);
+    }
+    if let Some(dlcs) = &mut game.dlcs {
+        dlc_image_service::populate_dlcs_cover(provider, dlcs);
+    }
+}
+
 pub fn populate_games_cover(provider: &ImageClientProvider, games: &mut Vec<GameDTO>) {
     if let Ok(client) = handle_image_client_provider(provider) {
         for game in games {
@@ -41,6 +52,32 @@ pub fn populate_games_available_cover(
     }
 }
 
+pub fn populate_games_in_series_cover(
+    provider: &ImageClientProvider,
+    games: &mut Vec<GameInSeriesDTO>,
+) {
+    if let Ok(client) = handle_image_client_provider(provider) {
+        for game in games {
+            if let Some(cover_filename) = &game.cover_filename {
+                game.cover_url = Some(client.get_image_uri(GAME_FOLDER, cover_filename));
+            }
+        }
+    }
+}
+
+pub fn populate_related_games_cover(
+    provider: &ImageClientProvider,
+    games: &mut Vec<RelatedGameDTO>,
+) {
+    if let Ok(client) = handle_image_client_provider(provider) {
+        for game in games {
+            if let Some(cover_filename) = &game.cover_filename {
+                game.cover_url = Some(client.get_image_uri(GAME_FOLDER, cover_filename));
+            }
+        }
+    }
+}
+
 pub fn populate_games_with_finish_cover(
     provider: &ImageClientProvider,
     games: &mut Vec<GameWithFinishDTO>,
@@ -106,6 +143,45 @@ pub fn populate_games_finished_review_cover(
     }
 }
 
+pub fn populate_games_status_review_cover(
+    provider: &ImageClientProvider,
+    games: &mut Vec<GameStatusReviewDTO>,
+) {
+    if let Ok(client) = handle_image_client_provider(provider) {
+        for game in games {
+            if let Some(cover_filename) = &game.cover_filename {
+                game.cover_url = Some(client.get_image_uri(GAME_FOLDER, cover_filename));
+            }
+        }
+    }
+}
+
+pub fn populate_games_spending_review_cover(
+    provider: &ImageClientProvider,
+    games: &mut Vec<GameSpendingReviewDTO>,
+) {
+    if let Ok(client) = handle_image_client_provider(provider) {
+        for game in games {
+            if let Some(cover_filename) = &game.cover_filename {
+                game.cover_url = Some(client.get_image_uri(GAME_FOLDER, cover_filename));
+            }
+        }
+    }
+}
+
+pub fn populate_games_expiring_cover(
+    provider: &ImageClientProvider,
+    games: &mut Vec<GameExpiringDTO>,
+) {
+    if let Ok(client) = handle_image_client_provider(provider) {
+        for game in games {
+            if let Some(cover_filename) = &game.cover_filename {
+                game.cover_url = Some(client.get_image_uri(GAME_FOLDER, cover_filename));
+            }
+        }
+    }
+}
+
 pub async fn set_game_cover(
     image_client_provider: &ImageClientProvider,
     user_id: &str,
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+
+use crate::errors::{error_message_builder, ApiErrors};
+use crate::models::{
+    DurationDef, GameDTO, GamePageResult, ModelInfo, PlatformAvailableDTO, TagDTO, DLCDTO,
+};
+
+use super::{
+    dlcs_service, game_available_service, game_custom_values_service, game_finishes_service,
+    game_logs_service, game_tags_service,
+};
+
+const INCLUDE_TAGS: &str = "tags";
+const INCLUDE_PLATFORMS: &str = "platforms";
+const INCLUDE_DLCS: &str = "dlcs";
+const INCLUDE_FINISHES: &str = "finishes";
+const INCLUDE_TOTAL_TIME: &str = "total_time";
+const INCLUDE_CUSTOM_FIELDS: &str = "custom_fields";
+
+/// Custom fields are always embedded when getting a single game
+pub fn with_single_game_includes(include: Option<String>) -> Option<String> {
+    match include {
+        Some(include) => Some(format!("{include},{INCLUDE_CUSTOM_FIELDS}")),
+        None => Some(String::from(INCLUDE_CUSTOM_FIELDS)),
+    }
+}
+
+pub async fn include_game(
+    pool: &PgPool,
+    user_id: &str,
+    mut game: GameDTO,
+    include: Option<String>,
+) -> Result<GameDTO, ApiErrors> {
+    populate_games_includes(pool, user_id, std::slice::from_mut(&mut game), include).await?;
+    Ok(game)
+}
+
+pub async fn include_games_page(
+    pool: &PgPool,
+    user_id: &str,
+    mut page: GamePageResult,
+    include: Option<String>,
+) -> Result<GamePageResult, ApiErrors> {
+    populate_games_includes(pool, user_id, &mut page.data, include).await?;
+    Ok(page)
+}
+
+/// Embeds the requested relations, fetching each one with a single query for all the games
+async fn populate_games_includes(
+    pool: &PgPool,
+    user_id: &str,
+    games: &mut [GameDTO],
+    include: Option<String>,
+) -> Result<(), ApiErrors> {
+    let includes = parse_includes(include)?;
+    if includes.is_empty() || games.is_empty() {
+        return Ok(());
+    }
+
+    let game_ids: Vec<String> = games.iter().map(|game| game.id.clone()).collect();
+
+    if includes.contains(&INCLUDE_TAGS) {
+        let mut map = HashMap::<String, Vec<TagDTO>>::new();
+        for tag in game_tags_service::find_tags_by_games(pool, user_id, game_ids.clone()).await? {
+            map.entry(tag.game_id.to_string())
+                .or_default()
+                .push(TagDTO::from(tag));
+        }
+        for game in games.iter_mut() {
+            game.tags = Some(map.remove(&game.id).unwrap_or_default());
+        }
+    }
+
+    if includes.contains(&INCLUDE_PLATFORMS) {
+        let mut map = HashMap::<String, Vec<PlatformAvailableDTO>>::new();
+        for platform in
+            game_available_service::find_platforms_by_games(pool, user_id, game_ids.clone()).await?
+        {
+            map.entry(platform.game_id.to_string())
+                .or_default()
+                .push(PlatformAvailableDTO::from(platform));
+        }
+        for game in games.iter_mut() {
+            game.platforms = Some(map.remove(&game.id).unwrap_or_default());
+        }
+    }
+
+    if includes.contains(&INCLUDE_DLCS) {
+        let mut map = HashMap::<String, Vec<DLCDTO>>::new();
+        for dlc in dlcs_service::find_dlcs_by_base_games(pool, user_id, game_ids.clone()).await? {
+            if let Some(base_game_id) = dlc.base_game_id {
+                map.entry(base_game_id.to_string())
+                    .or_default()
+                    .push(DLCDTO::from(dlc));
+            }
+        }
+        for game in games.iter_mut() {
+            game.dlcs = Some(map.remove(&game.id).unwrap_or_default());
+        }
+    }
+
+    if includes.contains(&INCLUDE_FINISHES) {
+        let mut map = HashMap::<String, Vec<_>>::new();
+        for finish in
+            game_finishes_service::find_game_finishes_by_games(pool, user_id, game_ids.clone())
+                .await?
+        {
+            map.entry(finish.game_id.to_string())
+                .or_default()
+                .push(finish.date);
+        }
+        for game in games.iter_mut() {
+            game.finishes = Some(map.remove(&game.id).unwrap_or_default());
+        }
+    }
+
+    if includes.contains(&INCLUDE_CUSTOM_FIELDS) {
+        let mut map = HashMap::<String, HashMap<String, serde_json::Value>>::new();
+        for custom_value in
+            game_custom_values_service::find_custom_values_by_games(pool, user_id, game_ids.clone())
+                .await?
+        {
+            map.entry(custom_value.game_id.to_string())
+                .or_default()
+                .insert(
+                    custom_value.name.clone(),
+                    serde_json::Value::from(custom_value),
+                );
+        }
+        for game in games.iter_mut() {
+            game.custom_fields = Some(map.remove(&game.id).unwrap_or_default());
+        }
+    }
+
+    if includes.contains(&INCLUDE_TOTAL_TIME) {
+        let mut map = HashMap::<String, DurationDef>::new();
+        for total in game_logs_service::find_sum_game_logs_by_games(pool, user_id, game_ids).await?
+        {
+            map.insert(
+                total.game_id.to_string(),
+                DurationDef::from(total.query_time),
+            );
+        }
+        for game in games.iter_mut() {
+            game.total_time = Some(map.remove(&game.id).unwrap_or_default());
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_includes(include: Option<String>) -> Result<Vec<&'static str>, ApiErrors> {
+    match include {
+        Some(include) => include
+            .split(',')
+            .map(str::trim)
+            .filter(|relation| !relation.is_empty())
+            .map(|relation| {
+                [
+                    INCLUDE_TAGS,
+                    INCLUDE_PLATFORMS,
+                    INCLUDE_DLCS,
+                    INCLUDE_FINISHES,
+                    INCLUDE_TOTAL_TIME,
+                    INCLUDE_CUSTOM_FIELDS,
+                ]
+                .into_iter()
+                .find(|available| *available == relation)
+                .ok_or_else(|| {
+                    ApiErrors::InvalidParameter(error_message_builder::relation_not_found(
+                        GameDTO::MODEL_NAME,
+                        relation,
+                    ))
+                })
+            })
+            .collect(),
+        None => Ok(vec![]),
+    }
+}
@@ -2,7 +2,7 @@ use chrono::{Duration, NaiveDateTime};
 use sqlx::postgres::types::PgInterval;
 use sqlx::PgPool;
 
-use crate::entities::{GameLog, GameLogWithTime};
+use crate::entities::{GameLog, GameLogTotalTime, GameLogWithTime};
 use crate::errors::ApiErrors;
 use crate::models::{DurationDef, GameLogDTO, Merge, NewGameLogDTO};
 use crate::repository::game_log_repository;
@@ -36,6 +36,16 @@ pub async fn get_game_logs(
     handle_get_list_result::<GameLogWithTime, GameLogDTO>(find_result)
 }
 
+pub(super) async fn find_sum_game_logs_by_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameLogTotalTime>, ApiErrors> {
+    let find_result =
+        game_log_repository::find_sum_time_by_game_id_in(pool, user_id, game_ids).await;
+    handle_result::<Vec<GameLogTotalTime>, GameLogDTO>(find_result)
+}
+
 pub(super) async fn find_first_game_logs_by_games(
     pool: &PgPool,
     user_id: &str,
@@ -47,6 +57,16 @@ pub(super) async fn find_first_game_logs_by_games(
     handle_result::<Vec<GameLogWithTime>, GameLogDTO>(find_result)
 }
 
+pub(super) async fn find_first_game_sessions_by_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameLogWithTime>, ApiErrors> {
+    let find_result =
+        game_log_repository::find_all_first_sessions_by_game_id_in(pool, user_id, game_ids).await;
+    handle_result::<Vec<GameLogWithTime>, GameLogDTO>(find_result)
+}
+
 pub async fn create_game_log(
     pool: &PgPool,
     user_id: &str,
@@ -0,0 +1,144 @@
+use std::collections::{HashMap, HashSet};
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::entities::Game;
+use crate::errors::ApiErrors;
+use crate::models::{GameDTO, GameRelationship, GameRelationshipType, RelatedGameDTO};
+use crate::repository::{game_relationship_repository, game_repository};
+
+use super::base::{
+    handle_action_result, handle_already_exists_result, handle_get_list_result,
+    handle_get_list_result_raw, handle_not_found_result,
+};
+use super::games_service;
+
+/// Games related to the given one in both directions, inverse ones pointing to it
+pub async fn get_game_relationships(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    rtype: Option<GameRelationshipType>,
+) -> Result<Vec<RelatedGameDTO>, ApiErrors> {
+    games_service::exists_game(pool, user_id, game_id).await?;
+
+    let find_related_result =
+        game_relationship_repository::find_all_related_games(pool, user_id, game_id).await;
+    let mut related_games: Vec<RelatedGameDTO> = handle_get_list_result(find_related_result)?;
+
+    let find_relating_result =
+        game_relationship_repository::find_all_relating_games(pool, user_id, game_id).await;
+    let relating_games: Vec<RelatedGameDTO> = handle_get_list_result(find_relating_result)?;
+    related_games.extend(relating_games.into_iter().map(|game| RelatedGameDTO {
+        inverse: true,
+        ..game
+    }));
+
+    if let Some(rtype) = rtype {
+        related_games.retain(|game| game.relationship_type == rtype);
+    }
+
+    Ok(related_games)
+}
+
+/// Games contained in each of the given collections, by collection id and sorted by name
+pub async fn find_contained_games_by_collections(
+    pool: &PgPool,
+    user_id: &str,
+    collection_ids: Vec<String>,
+) -> Result<HashMap<Uuid, Vec<Game>>, ApiErrors> {
+    let find_result = game_relationship_repository::find_all_with_game_id_in_and_type(
+        pool,
+        user_id,
+        collection_ids,
+        i16::from(GameRelationshipType::CollectionContains),
+    )
+    .await;
+    let relationships = handle_get_list_result_raw::<_, GameRelationship>(find_result)?;
+
+    let contained_ids = relationships
+        .iter()
+        .map(|relationship| relationship.related_game_id.to_string())
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect();
+    let find_games_result =
+        game_repository::find_all_with_id_in(pool, user_id, contained_ids).await;
+    let games: HashMap<Uuid, Game> = handle_get_list_result_raw::<_, GameDTO>(find_games_result)?
+        .into_iter()
+        .map(|game| (game.id, game))
+        .collect();
+
+    let mut contained_games = HashMap::<Uuid, Vec<Game>>::new();
+    for relationship in relationships {
+        if let Some(game) = games.get(&relationship.related_game_id) {
+            contained_games
+                .entry(relationship.game_id)
+                .or_default()
+                .push(game.clone());
+        }
+    }
+    for games in contained_games.values_mut() {
+        games.sort_by(|game, other_game| game.name.cmp(&other_game.name));
+    }
+
+    Ok(contained_games)
+}
+
+pub async fn create_game_relationship(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    related_game_id: &str,
+    rtype: GameRelationshipType,
+) -> Result<(), ApiErrors> {
+    if game_id == related_game_id {
+        return Err(ApiErrors::InvalidParameter(String::from(
+            "Game cannot be related to itself",
+        )));
+    }
+
+    games_service::exists_game(pool, user_id, game_id).await?;
+    games_service::exists_game(pool, user_id, related_game_id).await?;
+
+    // Only one relationship is kept between two games
+    let exists_result =
+        game_relationship_repository::exists_between_games(pool, user_id, game_id, related_game_id)
+            .await;
+    handle_already_exists_result::<GameRelationship>(exists_result)?;
+
+    let create_result = game_relationship_repository::create(
+        pool,
+        user_id,
+        game_id,
+        related_game_id,
+        i16::from(rtype),
+    )
+    .await;
+    handle_action_result::<GameRelationship>(create_result)
+}
+
+pub async fn delete_game_relationship(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    related_game_id: &str,
+) -> Result<(), ApiErrors> {
+    exists_game_relationship(pool, user_id, game_id, related_game_id).await?;
+
+    let delete_result =
+        game_relationship_repository::delete_by_id(pool, user_id, game_id, related_game_id).await;
+    handle_action_result::<GameRelationship>(delete_result)
+}
+
+pub async fn exists_game_relationship(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    related_game_id: &str,
+) -> Result<(), ApiErrors> {
+    let exists_result =
+        game_relationship_repository::exists_by_id(pool, user_id, game_id, related_game_id).await;
+    handle_not_found_result::<GameRelationship>(exists_result)
+}
@@ -1,18 +1,25 @@
 use std::collections::{HashMap, HashSet};
 
 use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::postgres::types::PgInterval;
 use sqlx::PgPool;
+use uuid::Uuid;
 
-use crate::entities::{GameFinish, GameLogWithTime, GameWithDate, GameWithLog};
+use crate::entities::{
+    DLCFinishDate, DLCWithDate, Game, GameFinish, GameLogWithTime, GameStatusChange, GameWithDate,
+    GameWithLog, GameWithStatusChange, DLC,
+};
 use crate::errors::ApiErrors;
 use crate::models::{
-    DurationDef, GameFinishedReviewDTO, GameLogDTO, GamePlayedReviewDTO, GameStreakDTO,
-    GamesFinishedReviewDTO, GamesLogDTO, GamesPlayedReviewDTO, GamesStreakDTO,
+    DLCFinishedReviewDTO, DurationDef, GameFinishedReviewDTO, GameLogDTO, GamePlayedReviewDTO,
+    GameStatus, GameStatusChangeDTO, GameStatusReviewDTO, GameStreakDTO, GamesFinishedReviewDTO,
+    GamesLogDTO, GamesPlayedReviewDTO, GamesStatusReviewDTO, GamesStreakDTO, DLCDTO,
 };
 
 use super::{
-    game_finishes_service, game_logs_service, game_with_finish_service, game_with_logs_service,
-    logs_utils,
+    dlc_finishes_service, dlc_with_finish_service, dlcs_service, game_finishes_service,
+    game_logs_service, game_relationships_service, game_status_history_service,
+    game_with_finish_service, game_with_logs_service, logs_utils,
 };
 
 pub async fn get_played_games_review(
@@ -25,16 +32,36 @@ pub async fn get_played_games_review(
         game_with_logs_service::find_game_with_logs_between(pool, user_id, start_date, end_date)
             .await?;
 
-    let game_ids = game_with_logs
+    let collection_ids = game_with_logs
         .iter()
         .map(|game| game.id.to_string())
         .collect::<HashSet<String>>()
         .into_iter()
         .collect();
+    let contained_games = game_relationships_service::find_contained_games_by_collections(
+        pool,
+        user_id,
+        collection_ids,
+    )
+    .await?;
+    let game_with_logs = attribute_collections_logs(game_with_logs, &contained_games);
+
+    let game_ids = game_with_logs
+        .iter()
+        .map(|game| game.id.to_string())
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect::<Vec<String>>();
     let first_logs =
-        game_logs_service::find_first_game_logs_by_games(pool, user_id, game_ids).await?;
+        game_logs_service::find_first_game_logs_by_games(pool, user_id, game_ids.clone()).await?;
+    let first_logs =
+        attribute_collections_first_logs(pool, user_id, first_logs, &contained_games).await?;
+    let dlcs = dlcs_service::find_dlcs_by_base_games_with_activity_between(
+        pool, user_id, game_ids, start_date, end_date,
+    )
+    .await?;
 
-    let review = build_played_review(game_with_logs, first_logs);
+    let review = build_played_review(game_with_logs, first_logs, dlcs);
     Ok(review)
 }
 
@@ -58,13 +85,195 @@ pub async fn get_finished_games_review(
     let first_finishes =
         game_finishes_service::find_first_game_finishes_by_games(pool, user_id, game_ids).await?;
 
-    let review = build_finished_review(game_with_finishes, first_finishes);
+    let dlc_with_finishes = dlc_with_finish_service::find_dlc_with_finishes_between(
+        pool, user_id, start_date, end_date,
+    )
+    .await?;
+
+    let dlc_ids = dlc_with_finishes
+        .iter()
+        .map(|dlc| dlc.id.to_string())
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect();
+    let first_dlc_finishes =
+        dlc_finishes_service::find_first_dlc_finishes_by_dlcs(pool, user_id, dlc_ids).await?;
+
+    let mut review = build_finished_review(game_with_finishes, first_finishes);
+    fill_finished_dlcs_review(&mut review, dlc_with_finishes, first_dlc_finishes);
+    Ok(review)
+}
+
+pub async fn get_status_games_review(
+    pool: &PgPool,
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<GamesStatusReviewDTO, ApiErrors> {
+    let game_with_status_changes =
+        game_status_history_service::find_game_with_status_changes_between(
+            pool, user_id, start_date, end_date,
+        )
+        .await?;
+
+    let game_ids = game_with_status_changes
+        .iter()
+        .map(|game| game.id.to_string())
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect();
+    let first_playing_changes =
+        game_status_history_service::find_first_playing_changes_by_games(pool, user_id, game_ids)
+            .await?;
+
+    let review = build_status_review(game_with_status_changes, first_playing_changes);
     Ok(review)
 }
 
+/// Time logged on a collection is attributed to the games it contains, split evenly between them
+/// as if they were played one after the other during the session
+fn attribute_collections_logs(
+    game_with_logs: Vec<GameWithLog>,
+    contained_games: &HashMap<Uuid, Vec<Game>>,
+) -> Vec<GameWithLog> {
+    let mut attributed_logs = vec![];
+    for game_with_log in game_with_logs {
+        match contained_games.get(&game_with_log.id) {
+            Some(games) => {
+                let parts = split_session(
+                    game_with_log.log_start_datetime,
+                    game_with_log.log_end_datetime,
+                    games.len(),
+                );
+                attributed_logs.extend(games.iter().zip(parts).map(
+                    |(game, (start_datetime, end_datetime, time))| {
+                        game_with_log_part(game, start_datetime, end_datetime, time)
+                    },
+                ));
+            }
+            None => attributed_logs.push(game_with_log),
+        }
+    }
+
+    attributed_logs
+}
+
+/// Contained games get the part of the first session of their collections the same way,
+/// keeping it when it comes before their own first log
+async fn attribute_collections_first_logs(
+    pool: &PgPool,
+    user_id: &str,
+    first_logs: Vec<GameLogWithTime>,
+    contained_games: &HashMap<Uuid, Vec<Game>>,
+) -> Result<Vec<GameLogWithTime>, ApiErrors> {
+    if contained_games.is_empty() {
+        return Ok(first_logs);
+    }
+
+    let collection_ids = contained_games.keys().map(Uuid::to_string).collect();
+    let first_sessions =
+        game_logs_service::find_first_game_sessions_by_games(pool, user_id, collection_ids).await?;
+
+    let mut first_logs_map: HashMap<Uuid, GameLogWithTime> = first_logs
+        .into_iter()
+        .map(|first_log| (first_log.game_id, first_log))
+        .collect();
+    for first_session in first_sessions {
+        let games = match contained_games.get(&first_session.game_id) {
+            Some(games) => games,
+            None => continue,
+        };
+
+        let parts = split_session(
+            first_session.datetime,
+            first_session.end_datetime,
+            games.len(),
+        );
+        for (game, (start_datetime, end_datetime, time)) in games.iter().zip(parts) {
+            let is_earlier = !first_logs_map
+                .get(&game.id)
+                .is_some_and(|first_log| first_log.datetime <= start_datetime);
+            if is_earlier {
+                first_logs_map.insert(
+                    game.id,
+                    GameLogWithTime {
+                        game_id: game.id,
+                        datetime: start_datetime,
+                        end_datetime,
+                        query_time: time,
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(first_logs_map.into_values().collect())
+}
+
+/// Consecutive equal parts of the session.
+/// The last one ends with the session, so rounding does not lose any time.
+fn split_session(
+    start_datetime: NaiveDateTime,
+    end_datetime: NaiveDateTime,
+    parts_count: usize,
+) -> Vec<(NaiveDateTime, NaiveDateTime, PgInterval)> {
+    let parts_count_i32 = i32::try_from(parts_count).expect("Count was not within valid range");
+    let part_time = (end_datetime - start_datetime) / parts_count_i32;
+
+    let mut part_start_datetime = start_datetime;
+    let mut parts = vec![];
+    for index in 0..parts_count {
+        let part_end_datetime = if index + 1 == parts_count {
+            end_datetime
+        } else {
+            part_start_datetime + part_time
+        };
+        let part_micros = (part_end_datetime - part_start_datetime)
+            .num_microseconds()
+            .unwrap_or_default();
+
+        parts.push((
+            part_start_datetime,
+            part_end_datetime,
+            PgInterval::from(DurationDef::microseconds(part_micros)),
+        ));
+        part_start_datetime = part_end_datetime;
+    }
+
+    parts
+}
+
+fn game_with_log_part(
+    game: &Game,
+    start_datetime: NaiveDateTime,
+    end_datetime: NaiveDateTime,
+    time: PgInterval,
+) -> GameWithLog {
+    GameWithLog {
+        id: game.id,
+        user_id: game.user_id,
+        name: game.name.clone(),
+        edition: game.edition.clone(),
+        release_year: game.release_year,
+        cover_filename: game.cover_filename.clone(),
+        added_datetime: game.added_datetime,
+        updated_datetime: game.updated_datetime,
+        status: game.status,
+        rating: game.rating,
+        notes: game.notes.clone(),
+        save_folder: game.save_folder.clone(),
+        screenshot_folder: game.screenshot_folder.clone(),
+        backup: game.backup,
+        log_start_datetime: start_datetime,
+        log_end_datetime: end_datetime,
+        log_time: time,
+    }
+}
+
 fn build_played_review(
     game_with_logs: Vec<GameWithLog>,
     first_logs: Vec<GameLogWithTime>,
+    dlcs: Vec<DLC>,
 ) -> GamesPlayedReviewDTO {
     let mut map = HashMap::<String, GamePlayedReviewDTO>::new();
 
@@ -204,6 +413,7 @@ fn build_played_review(
         total_rated,
         total_rated_by_rating,
         games: map.into_values().collect(),
+        dlcs: dlcs.into_iter().map(DLCDTO::from).collect(),
     }
 }
 
@@ -268,6 +478,111 @@ fn build_finished_review(
         total_finished_grouped: total_finished_by_month,
         total_finished_by_release_year,
         games: map.into_values().collect(),
+        total_dlcs_finished: 0,
+        total_dlcs_first_finished: 0,
+        dlcs: vec![],
+    }
+}
+
+fn fill_finished_dlcs_review(
+    review: &mut GamesFinishedReviewDTO,
+    dlc_with_finishes: Vec<DLCWithDate>,
+    first_finishes: Vec<DLCFinishDate>,
+) {
+    let mut map = HashMap::<String, DLCFinishedReviewDTO>::new();
+
+    // Fill finishes map
+    for dlc_with_finish in dlc_with_finishes {
+        let dlc_id = dlc_with_finish.id.to_string();
+
+        let finish_date = dlc_with_finish.query_date;
+
+        let dlc = map
+            .entry(dlc_id)
+            .or_insert_with(|| DLCFinishedReviewDTO::from(dlc_with_finish));
+        fill_finished_dlc_review(dlc, finish_date);
+    }
+
+    // Fill first finished
+    for first_finish in first_finishes {
+        let dlc_id = first_finish.dlc_id.to_string();
+
+        if let Some(dlc) = map.get_mut(&dlc_id) {
+            dlc.first_finished = dlc.first_finish == first_finish.date;
+        }
+    }
+
+    for dlc in map.values() {
+        review.total_dlcs_finished += 1;
+        review.total_dlcs_first_finished += if dlc.first_finished { 1 } else { 0 };
+    }
+    review.dlcs = map.into_values().collect();
+}
+
+fn build_status_review(
+    game_with_status_changes: Vec<GameWithStatusChange>,
+    first_playing_changes: Vec<GameStatusChange>,
+) -> GamesStatusReviewDTO {
+    let mut map = HashMap::<String, GameStatusReviewDTO>::new();
+
+    // Fill changes map
+    for game_with_status_change in game_with_status_changes {
+        let game_id = game_with_status_change.id.to_string();
+
+        let change = GameStatusChangeDTO::from(&game_with_status_change);
+
+        let game = map
+            .entry(game_id)
+            .or_insert_with(|| GameStatusReviewDTO::from(game_with_status_change));
+        fill_status_game_review(game, change);
+    }
+
+    // Fill first played
+    for first_playing_change in first_playing_changes {
+        let game_id = first_playing_change.game_id.to_string();
+
+        if let Some(game) = map.get_mut(&game_id) {
+            let first_playing_datetime = first_playing_change.datetime;
+            game.first_played = game
+                .changes
+                .iter()
+                .any(|change| change.datetime == first_playing_datetime);
+
+            if game.first_played {
+                let days = (first_playing_datetime - game.added_datetime).num_days();
+                game.days_to_first_played = Some(days);
+            }
+        }
+    }
+
+    // Fill globals
+    let mut total_changes = 0;
+    let mut total_first_played = 0;
+    let mut total_days_to_first_played = 0;
+    let mut total_abandoned = 0;
+    for game in map.values() {
+        total_changes +=
+            i32::try_from(game.changes.len()).expect("Count was not within valid range");
+        total_abandoned += if game.abandoned { 1 } else { 0 };
+
+        if let Some(days) = game.days_to_first_played {
+            total_first_played += 1;
+            total_days_to_first_played += days;
+        }
+    }
+
+    let average_days_to_first_played = if total_first_played > 0 {
+        Some(total_days_to_first_played as f64 / f64::from(total_first_played))
+    } else {
+        None
+    };
+
+    GamesStatusReviewDTO {
+        total_changes,
+        total_first_played,
+        average_days_to_first_played,
+        total_abandoned,
+        games: map.into_values().collect(),
     }
 }
 
@@ -398,6 +713,34 @@ fn fill_finished_game_review(game: &mut GameFinishedReviewDTO, finish_date: Naiv
     }
 }
 
+fn fill_finished_dlc_review(dlc: &mut DLCFinishedReviewDTO, finish_date: NaiveDate) {
+    // Fill finishes
+    logs_utils::fill_game_finishes(&mut dlc.finishes, finish_date);
+    dlc.total_finished =
+        i32::try_from(dlc.finishes.len()).expect("Count was not within valid range");
+
+    if finish_date < dlc.first_finish {
+        dlc.first_finish = finish_date;
+    }
+    if finish_date > dlc.last_finish {
+        dlc.last_finish = finish_date;
+    }
+}
+
+fn fill_status_game_review(game: &mut GameStatusReviewDTO, change: GameStatusChangeDTO) {
+    // Abandoned when stopped playing without reaching played
+    if change.previous_status == Some(GameStatus::Playing)
+        && change.status != GameStatus::Played
+        && change.status != GameStatus::Playing
+    {
+        game.abandoned = true;
+    } else if change.status == GameStatus::Played || change.status == GameStatus::Playing {
+        game.abandoned = false;
+    }
+
+    game.changes.push(change);
+}
+
 fn fill_longest_game_streak(game: &mut GamePlayedReviewDTO) {
     if let Some(last_streak) = game.streaks.last() {
         let last_streak_days = last_streak.days;
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use sqlx::PgPool;
+
+use crate::errors::ApiErrors;
+use crate::models::{DurationDef, GameInSeriesDTO, SeriesDTO, SeriesProgressDTO};
+use crate::repository::game_series_repository;
+
+use super::base::{handle_action_result, handle_get_list_result, handle_not_found_result};
+use super::{game_finishes_service, game_logs_service, games_service, series_service};
+
+pub async fn get_series_games(
+    pool: &PgPool,
+    user_id: &str,
+    series_id: &str,
+) -> Result<Vec<GameInSeriesDTO>, ApiErrors> {
+    series_service::exists_series(pool, user_id, series_id).await?;
+
+    let find_result =
+        game_series_repository::find_all_games_with_series(pool, user_id, series_id).await;
+    handle_get_list_result(find_result)
+}
+
+pub async fn get_game_series(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+) -> Result<Vec<SeriesDTO>, ApiErrors> {
+    games_service::exists_game(pool, user_id, game_id).await?;
+
+    let find_result =
+        game_series_repository::find_all_series_with_game(pool, user_id, game_id).await;
+    handle_get_list_result(find_result)
+}
+
+pub async fn get_series_progress(
+    pool: &PgPool,
+    user_id: &str,
+    series_id: &str,
+) -> Result<SeriesProgressDTO, ApiErrors> {
+    let games = get_series_games(pool, user_id, series_id).await?;
+    let game_ids: Vec<String> = games.iter().map(|game| game.id.clone()).collect();
+
+    let finishes =
+        game_finishes_service::find_game_finishes_by_games(pool, user_id, game_ids.clone()).await?;
+    let finished_games: HashSet<_> = finishes.iter().map(|finish| finish.game_id).collect();
+    let last_finish_date = finishes.iter().map(|finish| finish.date).max();
+
+    let total_time = game_logs_service::find_sum_game_logs_by_games(pool, user_id, game_ids)
+        .await?
+        .into_iter()
+        .map(|total| DurationDef::from(total.query_time).micros)
+        .sum();
+
+    Ok(SeriesProgressDTO {
+        total_games: games.len() as u64,
+        finished_games: finished_games.len() as u64,
+        total_time: DurationDef::microseconds(total_time),
+        last_finish_date,
+    })
+}
+
+/// Links the game to the series, or moves it when it is already part of it
+pub async fn create_game_series(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    series_id: &str,
+    position: Option<i32>,
+) -> Result<(), ApiErrors> {
+    games_service::exists_game(pool, user_id, game_id).await?;
+    let games = get_series_games(pool, user_id, series_id).await?;
+
+    let current_game = games.iter().find(|game| game.id == game_id);
+    match (current_game, position) {
+        (Some(_), Some(position)) => {
+            let update_result = game_series_repository::update_position_by_id(
+                pool, user_id, game_id, series_id, position,
+            )
+            .await;
+            handle_action_result::<GameInSeriesDTO>(update_result)
+        }
+        // Nothing to move
+        (Some(_), None) => Ok(()),
+        (None, _) => {
+            // New games go after the last one by default
+            let position = position.unwrap_or_else(|| {
+                games
+                    .iter()
+                    .map(|game| game.position)
+                    .max()
+                    .map_or(1, |last_position| last_position + 1)
+            });
+            let create_result =
+                game_series_repository::create(pool, user_id, game_id, series_id, position).await;
+            handle_action_result::<GameInSeriesDTO>(create_result)
+        }
+    }
+}
+
+pub async fn delete_game_series(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    series_id: &str,
+) -> Result<(), ApiErrors> {
+    exists_game_series(pool, user_id, game_id, series_id).await?;
+
+    let delete_result =
+        game_series_repository::delete_by_id(pool, user_id, game_id, series_id).await;
+    handle_action_result::<GameInSeriesDTO>(delete_result)
+}
+
+pub async fn exists_game_series(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+    series_id: &str,
+) -> Result<(), ApiErrors> {
+    let exists_result =
+        game_series_repository::exists_by_id(pool, user_id, game_id, series_id).await;
+    handle_not_found_result::<GameInSeriesDTO>(exists_result)
+}
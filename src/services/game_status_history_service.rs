@@ -0,0 +1,56 @@
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::entities::{GameStatusChange, GameWithStatusChange};
+use crate::errors::ApiErrors;
+use crate::models::{GameStatus, GameStatusChangeDTO};
+use crate::repository::game_status_history_repository;
+
+use super::base::{check_start_end, handle_get_list_result, handle_result, start_end_to_datetime};
+use super::games_service;
+
+pub async fn get_game_status_history(
+    pool: &PgPool,
+    user_id: &str,
+    game_id: &str,
+) -> Result<Vec<GameStatusChangeDTO>, ApiErrors> {
+    games_service::exists_game(pool, user_id, game_id).await?;
+
+    let find_result =
+        game_status_history_repository::find_all_by_game_id(pool, user_id, game_id).await;
+    handle_get_list_result::<GameStatusChange, GameStatusChangeDTO>(find_result)
+}
+
+pub(super) async fn find_game_with_status_changes_between(
+    pool: &PgPool,
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<GameWithStatusChange>, ApiErrors> {
+    check_start_end(start_date, end_date)?;
+
+    let (start_datetime, end_datetime) = start_end_to_datetime(start_date, end_date);
+    let find_result = game_status_history_repository::find_all_games_by_datetime_between(
+        pool,
+        user_id,
+        start_datetime,
+        end_datetime,
+    )
+    .await;
+    handle_result::<Vec<GameWithStatusChange>, GameStatusChangeDTO>(find_result)
+}
+
+pub(super) async fn find_first_playing_changes_by_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<GameStatusChange>, ApiErrors> {
+    let find_result = game_status_history_repository::find_all_first_by_status_and_game_id_in(
+        pool,
+        user_id,
+        i16::from(GameStatus::Playing),
+        game_ids,
+    )
+    .await;
+    handle_result::<Vec<GameStatusChange>, GameStatusChangeDTO>(find_result)
+}
@@ -1,12 +1,13 @@
 use sqlx::PgPool;
 
+use crate::entities::TagWithGameId;
 use crate::errors::ApiErrors;
 use crate::models::{GameDTO, GameTag, TagDTO};
 use crate::repository::game_tag_repository;
 
 use super::base::{
     handle_action_result, handle_already_exists_result, handle_get_list_result,
-    handle_not_found_result,
+    handle_not_found_result, handle_result,
 };
 use super::{games_service, tags_service};
 
@@ -32,6 +33,16 @@ pub async fn get_game_tags(
     handle_get_list_result(find_result)
 }
 
+pub(super) async fn find_tags_by_games(
+    pool: &PgPool,
+    user_id: &str,
+    game_ids: Vec<String>,
+) -> Result<Vec<TagWithGameId>, ApiErrors> {
+    let find_result =
+        game_tag_repository::find_all_tags_with_game_id_in(pool, user_id, game_ids).await;
+    handle_result::<Vec<TagWithGameId>, TagDTO>(find_result)
+}
+
 pub async fn create_game_tag(
     pool: &PgPool,
     user_id: &str,
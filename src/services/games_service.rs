@@ -1,8 +1,10 @@
 use sqlx::PgPool;
 
-use crate::entities::GameSearch;
+use crate::entities::{Game, GameSearch};
 use crate::errors::{error_message_builder, ApiErrors};
-use crate::models::{GameDTO, GamePageResult, GameStatus, NewGameDTO, SearchDTO};
+use crate::models::{
+    GameDTO, GameMergeDTO, GameMergeStrategy, GamePageResult, GameStatus, NewGameDTO, SearchDTO,
+};
 use crate::providers::ImageClientProvider;
 use crate::repository::game_repository;
 
@@ -11,7 +13,7 @@ use super::base::{
     handle_get_list_paged_result, handle_get_result, handle_not_found_result, handle_query_mapping,
     handle_update_result, update_merged,
 };
-use super::{game_available_service, game_image_service};
+use super::{game_available_service, game_custom_values_service, game_image_service, tags_service};
 
 pub async fn get_game(pool: &PgPool, user_id: &str, game_id: &str) -> Result<GameDTO, ApiErrors> {
     let find_result = game_repository::find_by_id(pool, user_id, game_id).await;
@@ -24,6 +26,7 @@ pub async fn search_games(
     search: SearchDTO,
     quicksearch: Option<String>,
 ) -> Result<GamePageResult, ApiErrors> {
+    let search = map_game_search(pool, user_id, search).await?;
     let search = handle_query_mapping::<GameDTO, GameSearch>(search, quicksearch)?;
     let find_result = game_repository::search_all(pool, user_id, search).await;
     handle_get_list_paged_result(find_result)
@@ -32,8 +35,15 @@ pub async fn search_games(
 pub async fn create_game(
     pool: &PgPool,
     user_id: &str,
-    game: NewGameDTO,
+    mut game: NewGameDTO,
 ) -> Result<GameDTO, ApiErrors> {
+    let custom_values = game_custom_values_service::validate_custom_values(
+        pool,
+        user_id,
+        game.custom_fields.take(),
+    )
+    .await?;
+
     create_merged(
         game,
         async move |created_game_id| get_game(pool, user_id, &created_game_id).await,
@@ -42,7 +52,8 @@ pub async fn create_game(
                 game_repository::exists_with_unique(pool, user_id, &game_to_create).await;
             handle_already_exists_result::<GameDTO>(exists_result)?;
 
-            let create_result = game_repository::create(pool, user_id, &game_to_create).await;
+            let create_result =
+                game_repository::create(pool, user_id, &game_to_create, &custom_values).await;
             handle_create_result::<String, GameDTO>(create_result)
         },
     )
@@ -53,9 +64,15 @@ pub async fn update_game(
     pool: &PgPool,
     user_id: &str,
     game_id: &str,
-    game: NewGameDTO,
+    mut game: NewGameDTO,
 ) -> Result<(), ApiErrors> {
     let new_status = game.status.clone();
+    let custom_values = game_custom_values_service::validate_custom_values(
+        pool,
+        user_id,
+        game.custom_fields.take(),
+    )
+    .await?;
 
     update_merged(
         game,
@@ -75,8 +92,14 @@ pub async fn update_game(
                 game_available_service::exists_no_game_available(pool, user_id, game_id).await?
             }
 
-            let update_result =
-                game_repository::update_by_id(pool, user_id, game_id, &game_to_update).await;
+            let update_result = game_repository::update_by_id(
+                pool,
+                user_id,
+                game_id,
+                &game_to_update,
+                &custom_values,
+            )
+            .await;
             handle_update_result::<GameDTO>(update_result)
         },
     )
@@ -103,6 +126,66 @@ pub async fn delete_game(
     handle_action_result::<GameDTO>(delete_result)
 }
 
+pub async fn merge_games(
+    pool: &PgPool,
+    image_client_provider: &ImageClientProvider,
+    user_id: &str,
+    game_id: &str,
+    other_game_id: &str,
+    merge: GameMergeDTO,
+) -> Result<GameDTO, ApiErrors> {
+    if game_id == other_game_id {
+        return Err(ApiErrors::InvalidParameter(String::from(
+            "Game cannot be merged into itself",
+        )));
+    }
+
+    let game = get_game(pool, user_id, game_id).await?;
+    let other_game = get_game(pool, user_id, other_game_id).await?;
+
+    let mut merged_game =
+        merge_game_user_info(game, &other_game, merge.strategy.unwrap_or_default());
+
+    // Same as linking a platform, an available game is no longer wishlisted
+    if merged_game.status == GameStatus::Wishlist {
+        let available_platforms = game_available_service::find_platforms_by_games(
+            pool,
+            user_id,
+            vec![game_id.to_string(), other_game_id.to_string()],
+        )
+        .await?;
+        if !available_platforms.is_empty() {
+            merged_game.status = GameStatus::NextUp;
+        }
+    }
+
+    // The cover of the other game is kept only when the game has none
+    let mut cover_to_delete = other_game.cover_filename.clone();
+    if merged_game.cover_filename.is_none() {
+        merged_game.cover_filename = cover_to_delete.take();
+    }
+
+    let merge_result = game_repository::merge_by_id(
+        pool,
+        user_id,
+        game_id,
+        other_game_id,
+        &Game::from(merged_game),
+    )
+    .await;
+    handle_action_result::<GameDTO>(merge_result)?;
+
+    if let Some(cover_filename) = &cover_to_delete {
+        let delete_cover_result =
+            game_image_service::delete_game_cover(image_client_provider, cover_filename).await;
+        if delete_cover_result.is_err() {
+            log::warn!("Game merge - Image client could not delete merged Game image.")
+        }
+    }
+
+    get_game(pool, user_id, game_id).await
+}
+
 pub async fn set_game_cover(
     pool: &PgPool,
     image_client_provider: &ImageClientProvider,
@@ -147,11 +230,63 @@ pub async fn delete_game_cover(
     set_game_cover_filename(pool, user_id, game_id, Option::<String>::None).await
 }
 
+/// Translates the game search fields resolved from user data into the searchable ones
+pub(super) async fn map_game_search(
+    pool: &PgPool,
+    user_id: &str,
+    search: SearchDTO,
+) -> Result<SearchDTO, ApiErrors> {
+    let search = game_custom_values_service::map_custom_field_search(pool, user_id, search).await?;
+    tags_service::map_tag_tree_search(pool, user_id, search).await
+}
+
 pub async fn exists_game(pool: &PgPool, user_id: &str, game_id: &str) -> Result<(), ApiErrors> {
     let exists_result = game_repository::exists_by_id(pool, user_id, game_id).await;
     handle_not_found_result::<GameDTO>(exists_result)
 }
 
+fn merge_game_user_info(
+    game: GameDTO,
+    other_game: &GameDTO,
+    strategy: GameMergeStrategy,
+) -> GameDTO {
+    match strategy {
+        GameMergeStrategy::Target => game,
+        GameMergeStrategy::Source => GameDTO {
+            status: other_game.status.clone(),
+            rating: other_game.rating,
+            notes: other_game.notes.clone(),
+            save_folder: other_game.save_folder.clone(),
+            screenshot_folder: other_game.screenshot_folder.clone(),
+            backup: other_game.backup,
+            ..game
+        },
+        GameMergeStrategy::Combine => GameDTO {
+            rating: game.rating.max(other_game.rating),
+            notes: match (game.notes.is_empty(), other_game.notes.is_empty()) {
+                (false, false) => format!("{}\n\n{}", game.notes, other_game.notes),
+                (true, _) => other_game.notes.clone(),
+                (false, true) => game.notes.clone(),
+            },
+            save_folder: first_not_empty(&game.save_folder, &other_game.save_folder),
+            screenshot_folder: first_not_empty(
+                &game.screenshot_folder,
+                &other_game.screenshot_folder,
+            ),
+            backup: game.backup || other_game.backup,
+            ..game
+        },
+    }
+}
+
+fn first_not_empty(value: &str, other_value: &str) -> String {
+    if value.is_empty() {
+        other_value.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
 async fn get_game_cover_filename(
     pool: &PgPool,
     user_id: &str,
@@ -0,0 +1,57 @@
+use sqlx::PgPool;
+
+use crate::errors::{error_message_builder, ApiErrors};
+use crate::models::{GlobalSearchDTO, SearchDTO, SearchResultDTO};
+use crate::providers::ImageClientProvider;
+
+use super::{
+    dlc_image_service, dlcs_service, game_image_service, games_service, platform_image_service,
+    platforms_service, tags_service,
+};
+
+const DEFAULT_LIMIT: u64 = 5;
+const MAX_LIMIT: u64 = 20;
+
+pub async fn search_all(
+    pool: &PgPool,
+    image_client_provider: &ImageClientProvider,
+    user_id: &str,
+    query: Option<String>,
+    limit: Option<u64>,
+) -> Result<GlobalSearchDTO, ApiErrors> {
+    let query = query
+        .filter(|value| !crate::string_utils::string_is_blank(value))
+        .ok_or_else(|| ApiErrors::InvalidParameter(error_message_builder::missing_param("q")))?;
+
+    // Without sorts the quicksearch orders each type by relevance
+    let search = SearchDTO {
+        size: Some(limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)),
+        ..Default::default()
+    };
+
+    let mut games =
+        games_service::search_games(pool, user_id, search.clone(), Some(query.clone())).await?;
+    game_image_service::populate_games_cover(image_client_provider, &mut games.data);
+
+    let mut dlcs =
+        dlcs_service::search_dlcs(pool, user_id, search.clone(), Some(query.clone())).await?;
+    dlc_image_service::populate_dlcs_cover(image_client_provider, &mut dlcs.data);
+
+    let mut platforms =
+        platforms_service::search_platforms(pool, user_id, search.clone(), Some(query.clone()))
+            .await?;
+    platform_image_service::populate_platforms_icon(image_client_provider, &mut platforms.data);
+
+    let tags = tags_service::search_tags(pool, user_id, search, Some(query)).await?;
+
+    Ok(GlobalSearchDTO {
+        games: games.data.into_iter().map(SearchResultDTO::from).collect(),
+        dlcs: dlcs.data.into_iter().map(SearchResultDTO::from).collect(),
+        platforms: platforms
+            .data
+            .into_iter()
+            .map(SearchResultDTO::from)
+            .collect(),
+        tags: tags.data.into_iter().map(SearchResultDTO::from).collect(),
+    })
+}
@@ -0,0 +1,71 @@
+use chrono::Duration;
+use sqlx::PgPool;
+
+use crate::errors::ApiErrors;
+use crate::models::{InvitationDTO, NewInvitationDTO, RegistrationConfig};
+use crate::repository::invitation_repository;
+
+use super::base::{
+    handle_action_result, handle_create_result, handle_get_list_result, handle_get_result,
+    handle_not_found_result,
+};
+
+pub async fn get_invitation(
+    pool: &PgPool,
+    user_id: &str,
+    invitation_id: &str,
+) -> Result<InvitationDTO, ApiErrors> {
+    let repository_result = invitation_repository::find_by_id(pool, user_id, invitation_id).await;
+    handle_get_result(repository_result)
+}
+
+pub async fn get_invitations(
+    pool: &PgPool,
+    user_id: &str,
+) -> Result<Vec<InvitationDTO>, ApiErrors> {
+    let find_result = invitation_repository::find_all(pool, user_id).await;
+    handle_get_list_result(find_result)
+}
+
+pub async fn create_invitation(
+    pool: &PgPool,
+    user_id: &str,
+    config: &RegistrationConfig,
+    invitation: NewInvitationDTO,
+) -> Result<InvitationDTO, ApiErrors> {
+    let now = crate::date_utils::now();
+    let expiry_datetime = invitation
+        .expiry_datetime
+        .unwrap_or_else(|| now + Duration::days(config.invitation_expiry_days));
+    if expiry_datetime <= now {
+        return Err(ApiErrors::InvalidParameter(String::from(
+            "Expiry datetime must be in the future.",
+        )));
+    }
+
+    let code = crate::uuid_utils::new_random_uuid();
+    let create_result = invitation_repository::create(pool, user_id, &code, expiry_datetime).await;
+    let created_invitation_id = handle_create_result::<String, InvitationDTO>(create_result)?;
+
+    get_invitation(pool, user_id, &created_invitation_id).await
+}
+
+pub async fn delete_invitation(
+    pool: &PgPool,
+    user_id: &str,
+    invitation_id: &str,
+) -> Result<(), ApiErrors> {
+    exists_invitation(pool, user_id, invitation_id).await?;
+
+    let delete_result = invitation_repository::delete_by_id(pool, user_id, invitation_id).await;
+    handle_action_result::<InvitationDTO>(delete_result)
+}
+
+pub async fn exists_invitation(
+    pool: &PgPool,
+    user_id: &str,
+    invitation_id: &str,
+) -> Result<(), ApiErrors> {
+    let exists_result = invitation_repository::exists_by_id(pool, user_id, invitation_id).await;
+    handle_not_found_result::<InvitationDTO>(exists_result)
+}
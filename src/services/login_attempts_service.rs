@@ -0,0 +1,180 @@
+use chrono::{Duration, NaiveDateTime};
+use sqlx::PgPool;
+
+use crate::entities::{LoginAttempt, LoginAttemptSourceType};
+use crate::errors::ApiErrors;
+use crate::models::LoginAttempts;
+use crate::repository::login_attempt_repository;
+
+use super::base::{handle_action_result, handle_result};
+
+// Failed attempts allowed before starting to delay the next ones
+const FREE_FAILED_ATTEMPTS: i32 = 3;
+// Failed attempts allowed before locking the source out
+const USERNAME_MAX_FAILED_ATTEMPTS: i32 = 10;
+const IP_MAX_FAILED_ATTEMPTS: i32 = 50;
+const TOTP_USER_MAX_FAILED_ATTEMPTS: i32 = 10;
+
+const BACKOFF_BASE_SECONDS: i64 = 1;
+const LOCKOUT_SECONDS: i64 = 15 * crate::date_utils::SECONDS_PER_MINUTE;
+// Failed attempts older than this are forgotten
+const ATTEMPTS_WINDOW_SECONDS: i64 = crate::date_utils::SECONDS_PER_DAY;
+
+pub async fn is_login_locked(
+    pool: &PgPool,
+    username: &str,
+    ip: Option<&str>,
+) -> Result<bool, ApiErrors> {
+    let now = crate::date_utils::now();
+
+    if is_source_locked(pool, LoginAttemptSourceType::Username, username, now).await? {
+        return Ok(true);
+    }
+
+    if let Some(ip) = ip {
+        return is_source_locked(pool, LoginAttemptSourceType::Ip, ip, now).await;
+    }
+
+    Ok(false)
+}
+
+pub async fn register_failed_login(
+    pool: &PgPool,
+    username: &str,
+    ip: Option<&str>,
+) -> Result<(), ApiErrors> {
+    let now = crate::date_utils::now();
+
+    register_failed_attempt(
+        pool,
+        LoginAttemptSourceType::Username,
+        username,
+        USERNAME_MAX_FAILED_ATTEMPTS,
+        now,
+    )
+    .await?;
+
+    if let Some(ip) = ip {
+        register_failed_attempt(
+            pool,
+            LoginAttemptSourceType::Ip,
+            ip,
+            IP_MAX_FAILED_ATTEMPTS,
+            now,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Only the username counter is reset, otherwise logging into an own account between guesses
+/// would keep clearing the IP counter
+pub async fn reset_login_attempts(pool: &PgPool, username: &str) -> Result<(), ApiErrors> {
+    reset_attempts(pool, LoginAttemptSourceType::Username, username).await
+}
+
+/// Failed codes when managing two-factor authentication, counted by user as the request
+/// is already authenticated
+pub async fn is_totp_locked(pool: &PgPool, user_id: &str) -> Result<bool, ApiErrors> {
+    let now = crate::date_utils::now();
+    is_source_locked(pool, LoginAttemptSourceType::TotpUser, user_id, now).await
+}
+
+pub async fn register_failed_totp(pool: &PgPool, user_id: &str) -> Result<(), ApiErrors> {
+    let now = crate::date_utils::now();
+    register_failed_attempt(
+        pool,
+        LoginAttemptSourceType::TotpUser,
+        user_id,
+        TOTP_USER_MAX_FAILED_ATTEMPTS,
+        now,
+    )
+    .await
+}
+
+pub async fn reset_totp_attempts(pool: &PgPool, user_id: &str) -> Result<(), ApiErrors> {
+    reset_attempts(pool, LoginAttemptSourceType::TotpUser, user_id).await
+}
+
+pub async fn unlock_username(pool: &PgPool, username: &str) -> Result<(), ApiErrors> {
+    reset_attempts(pool, LoginAttemptSourceType::Username, username).await
+}
+
+async fn is_source_locked(
+    pool: &PgPool,
+    source_type: LoginAttemptSourceType,
+    source: &str,
+    now: NaiveDateTime,
+) -> Result<bool, ApiErrors> {
+    let find_result =
+        login_attempt_repository::find_by_source(pool, i16::from(source_type), source).await;
+    let login_attempt = handle_result::<Option<LoginAttempt>, LoginAttempts>(find_result)?;
+
+    Ok(login_attempt
+        .and_then(|attempt| attempt.locked_until_datetime)
+        .is_some_and(|locked_until| locked_until > now))
+}
+
+async fn register_failed_attempt(
+    pool: &PgPool,
+    source_type: LoginAttemptSourceType,
+    source: &str,
+    max_failed_attempts: i32,
+    now: NaiveDateTime,
+) -> Result<(), ApiErrors> {
+    let window_start = now - Duration::seconds(ATTEMPTS_WINDOW_SECONDS);
+    let increment_result = login_attempt_repository::increment_failed_count(
+        pool,
+        i16::from(source_type),
+        source,
+        now,
+        window_start,
+    )
+    .await;
+    let failed_count = handle_result::<i32, LoginAttempts>(increment_result)?;
+
+    if let Some(locked_until) = calculate_locked_until(failed_count, max_failed_attempts, now) {
+        let update_result = login_attempt_repository::update_locked_until_by_source(
+            pool,
+            i16::from(source_type),
+            source,
+            locked_until,
+        )
+        .await;
+        handle_action_result::<LoginAttempts>(update_result)?;
+    }
+
+    Ok(())
+}
+
+async fn reset_attempts(
+    pool: &PgPool,
+    source_type: LoginAttemptSourceType,
+    source: &str,
+) -> Result<(), ApiErrors> {
+    let delete_result =
+        login_attempt_repository::delete_by_source(pool, i16::from(source_type), source).await;
+    handle_action_result::<LoginAttempts>(delete_result)
+}
+
+fn calculate_locked_until(
+    failed_count: i32,
+    max_failed_attempts: i32,
+    now: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    if failed_count >= max_failed_attempts {
+        return Some(now + Duration::seconds(LOCKOUT_SECONDS));
+    }
+
+    if failed_count > FREE_FAILED_ATTEMPTS {
+        // Double the delay with every failed attempt, never exceeding the lockout
+        let exponent = (failed_count - FREE_FAILED_ATTEMPTS - 1).min(32) as u32;
+        let backoff_seconds = BACKOFF_BASE_SECONDS
+            .saturating_mul(2_i64.saturating_pow(exponent))
+            .min(LOCKOUT_SECONDS);
+        return Some(now + Duration::seconds(backoff_seconds));
+    }
+
+    None
+}
@@ -1,21 +1,38 @@
 pub mod auth_service;
 mod base;
+pub mod custom_fields_service;
 pub mod dlc_available_service;
 pub mod dlc_finishes_service;
 pub mod dlc_image_service;
+pub mod dlc_include_service;
+pub mod dlc_tags_service;
 pub mod dlc_with_finish_service;
 pub mod dlcs_service;
 pub mod game_available_service;
+pub mod game_bulk_service;
+pub mod game_custom_values_service;
 pub mod game_finishes_service;
 pub mod game_image_service;
+pub mod game_include_service;
 pub mod game_logs_service;
+pub mod game_relationships_service;
 pub mod game_review_service;
+pub mod game_series_service;
+pub mod game_status_history_service;
 pub mod game_tags_service;
 pub mod game_with_finish_service;
 pub mod game_with_logs_service;
 pub mod games_service;
+pub mod global_search_service;
+pub mod invitations_service;
+pub mod login_attempts_service;
 mod logs_utils;
 pub mod platform_image_service;
 pub mod platforms_service;
+pub mod saved_searches_service;
+pub mod series_image_service;
+pub mod series_service;
+pub mod spending_service;
 pub mod tags_service;
+pub mod user_totp_service;
 pub mod users_service;
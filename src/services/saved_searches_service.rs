@@ -0,0 +1,188 @@
+use sqlx::PgPool;
+
+use crate::entities::{
+    DLCSearch, GameSearch, PlatformSearch, SavedSearch, SavedSearchSearch, TagSearch,
+};
+use crate::errors::ApiErrors;
+use crate::models::{
+    GameDTO, NewSavedSearchDTO, PlatformDTO, SavedSearchDTO, SavedSearchPageResult,
+    SavedSearchType, SearchDTO, TagDTO, DLCDTO,
+};
+use crate::repository::saved_search_repository;
+
+use super::games_service;
+
+use super::base::{
+    create_merged, handle_action_result, handle_already_exists_result, handle_create_result,
+    handle_get_list_paged_result, handle_get_result, handle_not_found_result, handle_query_mapping,
+    handle_update_result, update_merged,
+};
+
+pub async fn get_saved_search(
+    pool: &PgPool,
+    user_id: &str,
+    saved_search_id: &str,
+) -> Result<SavedSearchDTO, ApiErrors> {
+    let find_result = saved_search_repository::find_by_id(pool, user_id, saved_search_id).await;
+    handle_get_result(find_result)
+}
+
+pub async fn search_saved_searches(
+    pool: &PgPool,
+    user_id: &str,
+    search: SearchDTO,
+    quicksearch: Option<String>,
+) -> Result<SavedSearchPageResult, ApiErrors> {
+    let search = handle_query_mapping::<SavedSearchDTO, SavedSearchSearch>(search, quicksearch)?;
+    let find_result = saved_search_repository::search_all(pool, user_id, search).await;
+    handle_get_list_paged_result(find_result)
+}
+
+pub async fn create_saved_search(
+    pool: &PgPool,
+    user_id: &str,
+    saved_search: NewSavedSearchDTO,
+) -> Result<SavedSearchDTO, ApiErrors> {
+    create_merged(
+        saved_search,
+        async move |created_saved_search_id| {
+            get_saved_search(pool, user_id, &created_saved_search_id).await
+        },
+        async move |saved_search_to_create| {
+            validate_saved_search(pool, user_id, &saved_search_to_create).await?;
+
+            let exists_result =
+                saved_search_repository::exists_with_unique(pool, user_id, &saved_search_to_create)
+                    .await;
+            handle_already_exists_result::<SavedSearchDTO>(exists_result)?;
+
+            let create_result =
+                saved_search_repository::create(pool, user_id, &saved_search_to_create).await;
+            handle_create_result::<String, SavedSearchDTO>(create_result)
+        },
+    )
+    .await
+}
+
+pub async fn update_saved_search(
+    pool: &PgPool,
+    user_id: &str,
+    saved_search_id: &str,
+    saved_search: NewSavedSearchDTO,
+) -> Result<(), ApiErrors> {
+    update_merged(
+        saved_search,
+        async move || get_saved_search(pool, user_id, saved_search_id).await,
+        async move |saved_search_to_update| {
+            validate_saved_search(pool, user_id, &saved_search_to_update).await?;
+
+            let exists_result = saved_search_repository::exists_with_unique_except_id(
+                pool,
+                user_id,
+                &saved_search_to_update,
+                saved_search_id,
+            )
+            .await;
+            handle_already_exists_result::<SavedSearchDTO>(exists_result)?;
+
+            let update_result = saved_search_repository::update_by_id(
+                pool,
+                user_id,
+                saved_search_id,
+                &saved_search_to_update,
+            )
+            .await;
+            handle_update_result::<SavedSearchDTO>(update_result)
+        },
+    )
+    .await
+}
+
+pub async fn delete_saved_search(
+    pool: &PgPool,
+    user_id: &str,
+    saved_search_id: &str,
+) -> Result<(), ApiErrors> {
+    exists_saved_search(pool, user_id, saved_search_id).await?;
+
+    let delete_result = saved_search_repository::delete_by_id(pool, user_id, saved_search_id).await;
+    handle_action_result::<SavedSearchDTO>(delete_result)
+}
+
+pub async fn exists_saved_search(
+    pool: &PgPool,
+    user_id: &str,
+    saved_search_id: &str,
+) -> Result<(), ApiErrors> {
+    let exists_result = saved_search_repository::exists_by_id(pool, user_id, saved_search_id).await;
+    handle_not_found_result::<SavedSearchDTO>(exists_result)
+}
+
+/// Obtains the search to execute on a list.
+/// When a saved one is requested, paging and facets from the given one override its own.
+pub async fn apply_saved_search(
+    pool: &PgPool,
+    user_id: &str,
+    stype: SavedSearchType,
+    saved_search_id: Option<String>,
+    search: SearchDTO,
+) -> Result<SearchDTO, ApiErrors> {
+    let saved_search_id = match saved_search_id {
+        Some(id) => id,
+        None => return Ok(search),
+    };
+
+    let saved_search = get_saved_search(pool, user_id, &saved_search_id).await?;
+    if saved_search.stype != stype {
+        return Err(ApiErrors::InvalidParameter(String::from(
+            "Saved search cannot be executed on this list.",
+        )));
+    }
+
+    let saved = saved_search.search;
+    let merged_search = SearchDTO {
+        page: search.page.or(saved.page),
+        size: search.size.or(saved.size),
+        count: search.count.or(saved.count),
+        cursor: search.cursor.or(saved.cursor),
+        facets: search.facets.or(saved.facets),
+        ..saved
+    };
+    validate_search(pool, user_id, stype, merged_search.clone()).await?;
+
+    Ok(merged_search)
+}
+
+async fn validate_saved_search(
+    pool: &PgPool,
+    user_id: &str,
+    saved_search: &SavedSearch,
+) -> Result<(), ApiErrors> {
+    let stype = SavedSearchType::try_from(saved_search.stype).map_err(|_| {
+        ApiErrors::InvalidParameter(String::from("Saved search type is not valid."))
+    })?;
+    let search = serde_json::from_str::<SearchDTO>(&saved_search.search)
+        .map_err(|_| ApiErrors::InvalidParameter(String::from("Saved search is not valid.")))?;
+
+    validate_search(pool, user_id, stype, search).await
+}
+
+/// Checks every field of the search exists on the model of the saved search type
+async fn validate_search(
+    pool: &PgPool,
+    user_id: &str,
+    stype: SavedSearchType,
+    search: SearchDTO,
+) -> Result<(), ApiErrors> {
+    match stype {
+        SavedSearchType::Game => {
+            let search = games_service::map_game_search(pool, user_id, search).await?;
+            handle_query_mapping::<GameDTO, GameSearch>(search, None).map(|_| ())
+        }
+        SavedSearchType::DLC => handle_query_mapping::<DLCDTO, DLCSearch>(search, None).map(|_| ()),
+        SavedSearchType::Platform => {
+            handle_query_mapping::<PlatformDTO, PlatformSearch>(search, None).map(|_| ())
+        }
+        SavedSearchType::Tag => handle_query_mapping::<TagDTO, TagSearch>(search, None).map(|_| ()),
+    }
+}
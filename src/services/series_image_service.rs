@@ -0,0 +1,81 @@
+use crate::errors::ApiErrors;
+use crate::models::SeriesDTO;
+use crate::providers::ImageClientProvider;
+
+use super::base::{build_image_filename, extract_image_name, handle_image_client_provider};
+
+const SERIES_FOLDER: &str = "Series";
+const SERIES_IMAGE_SUFFIX: &str = "image";
+
+pub fn populate_series_image(provider: &ImageClientProvider, series: &mut SeriesDTO) {
+    if let Ok(client) = handle_image_client_provider(provider) {
+        if let Some(image_filename) = &series.image_filename {
+            series.image_url = Some(client.get_image_uri(SERIES_FOLDER, image_filename));
+        }
+    }
+}
+
+pub fn populate_series_list_image(
+    provider: &ImageClientProvider,
+    series_list: &mut Vec<SeriesDTO>,
+) {
+    if let Ok(client) = handle_image_client_provider(provider) {
+        for series in series_list {
+            if let Some(image_filename) = &series.image_filename {
+                series.image_url = Some(client.get_image_uri(SERIES_FOLDER, image_filename));
+            }
+        }
+    }
+}
+
+pub(super) async fn set_series_image(
+    image_client_provider: &ImageClientProvider,
+    user_id: &str,
+    series_id: &str,
+    file_path: &str,
+) -> Result<String, ApiErrors> {
+    let image_client = handle_image_client_provider(image_client_provider)?;
+
+    let format_filename = build_series_image_filename(user_id, series_id, Option::<String>::None);
+    image_client
+        .upload_image(file_path, SERIES_FOLDER, &format_filename)
+        .await
+        .map_err(|_| ApiErrors::UnknownError(String::from("Image upload error.")))
+}
+
+pub(super) async fn rename_series_image(
+    image_client_provider: &ImageClientProvider,
+    user_id: &str,
+    series_id: &str,
+    old_filename: &str,
+    new_name: &str,
+) -> Result<String, ApiErrors> {
+    let image_client = handle_image_client_provider(image_client_provider)?;
+
+    let old_name = extract_image_name(old_filename)?;
+
+    let format_filename =
+        build_series_image_filename(user_id, series_id, Some(String::from(new_name)));
+    image_client
+        .rename_image(SERIES_FOLDER, &old_name, &format_filename)
+        .await
+        .map_err(|_| ApiErrors::UnknownError(String::from("Image rename error.")))
+}
+
+pub(super) async fn delete_series_image(
+    image_client_provider: &ImageClientProvider,
+    filename: &str,
+) -> Result<(), ApiErrors> {
+    let image_client = handle_image_client_provider(image_client_provider)?;
+
+    let name = extract_image_name(filename)?;
+
+    image_client
+        .delete_image(SERIES_FOLDER, &name)
+        .await
+        .map_err(|_| ApiErrors::UnknownError(String::from("Image delete error.")))
+}
+
+fn build_series_image_filename(user_id: &str, series_id: &str, name: Option<String>) -> String {
+    build_image_filename(user_id, series_id, SERIES_IMAGE_SUFFIX, name)
+}
@@ -0,0 +1,176 @@
+use sqlx::PgPool;
+
+use crate::entities::SeriesSearch;
+use crate::errors::{error_message_builder, ApiErrors};
+use crate::models::{NewSeriesDTO, SearchDTO, SeriesDTO, SeriesPageResult};
+use crate::providers::ImageClientProvider;
+use crate::repository::series_repository;
+
+use super::base::{
+    create_merged, handle_action_result, handle_already_exists_result, handle_create_result,
+    handle_get_list_paged_result, handle_get_result, handle_not_found_result, handle_query_mapping,
+    handle_update_result, update_merged,
+};
+use super::series_image_service;
+
+pub async fn get_series(
+    pool: &PgPool,
+    user_id: &str,
+    series_id: &str,
+) -> Result<SeriesDTO, ApiErrors> {
+    let find_result = series_repository::find_by_id(pool, user_id, series_id).await;
+    handle_get_result(find_result)
+}
+
+pub async fn search_series(
+    pool: &PgPool,
+    user_id: &str,
+    search: SearchDTO,
+    quicksearch: Option<String>,
+) -> Result<SeriesPageResult, ApiErrors> {
+    let search = handle_query_mapping::<SeriesDTO, SeriesSearch>(search, quicksearch)?;
+    let find_result = series_repository::search_all(pool, user_id, search).await;
+    handle_get_list_paged_result(find_result)
+}
+
+pub async fn create_series(
+    pool: &PgPool,
+    user_id: &str,
+    series: NewSeriesDTO,
+) -> Result<SeriesDTO, ApiErrors> {
+    create_merged(
+        series,
+        async move |created_series_id| get_series(pool, user_id, &created_series_id).await,
+        async move |series_to_create| {
+            let exists_result =
+                series_repository::exists_with_unique(pool, user_id, &series_to_create).await;
+            handle_already_exists_result::<SeriesDTO>(exists_result)?;
+
+            let create_result = series_repository::create(pool, user_id, &series_to_create).await;
+            handle_create_result::<String, SeriesDTO>(create_result)
+        },
+    )
+    .await
+}
+
+pub async fn update_series(
+    pool: &PgPool,
+    user_id: &str,
+    series_id: &str,
+    series: NewSeriesDTO,
+) -> Result<(), ApiErrors> {
+    update_merged(
+        series,
+        async move || get_series(pool, user_id, series_id).await,
+        async move |series_to_update| {
+            let exists_result = series_repository::exists_with_unique_except_id(
+                pool,
+                user_id,
+                &series_to_update,
+                series_id,
+            )
+            .await;
+            handle_already_exists_result::<SeriesDTO>(exists_result)?;
+
+            let update_result =
+                series_repository::update_by_id(pool, user_id, series_id, &series_to_update).await;
+            handle_update_result::<SeriesDTO>(update_result)
+        },
+    )
+    .await
+}
+
+pub async fn delete_series(
+    pool: &PgPool,
+    image_client_provider: &ImageClientProvider,
+    user_id: &str,
+    series_id: &str,
+) -> Result<(), ApiErrors> {
+    let series = get_series(pool, user_id, series_id).await?;
+
+    if let Some(image_filename) = &series.image_filename {
+        let delete_image_result =
+            series_image_service::delete_series_image(image_client_provider, image_filename).await;
+        if delete_image_result.is_err() {
+            log::warn!("Series deletion - Image client could not delete Series with image.")
+        }
+    }
+
+    let delete_result = series_repository::delete_by_id(pool, user_id, series_id).await;
+    handle_action_result::<SeriesDTO>(delete_result)
+}
+
+pub async fn set_series_image(
+    pool: &PgPool,
+    image_client_provider: &ImageClientProvider,
+    user_id: &str,
+    series_id: &str,
+    file_path: &str,
+) -> Result<(), ApiErrors> {
+    exists_series(pool, user_id, series_id).await?;
+    let filename = series_image_service::set_series_image(
+        image_client_provider,
+        user_id,
+        series_id,
+        file_path,
+    )
+    .await?;
+    set_series_image_filename(pool, user_id, series_id, Some(filename)).await
+}
+
+pub async fn rename_series_image(
+    pool: &PgPool,
+    image_client_provider: &ImageClientProvider,
+    user_id: &str,
+    series_id: &str,
+    new_name: &str,
+) -> Result<(), ApiErrors> {
+    let old_filename = get_series_image_filename(pool, user_id, series_id).await?;
+    let new_filename = series_image_service::rename_series_image(
+        image_client_provider,
+        user_id,
+        series_id,
+        &old_filename,
+        new_name,
+    )
+    .await?;
+    set_series_image_filename(pool, user_id, series_id, Some(new_filename)).await
+}
+
+pub async fn delete_series_image(
+    pool: &PgPool,
+    image_client_provider: &ImageClientProvider,
+    user_id: &str,
+    series_id: &str,
+) -> Result<(), ApiErrors> {
+    let filename = get_series_image_filename(pool, user_id, series_id).await?;
+    series_image_service::delete_series_image(image_client_provider, &filename).await?;
+    set_series_image_filename(pool, user_id, series_id, Option::<String>::None).await
+}
+
+pub async fn exists_series(pool: &PgPool, user_id: &str, series_id: &str) -> Result<(), ApiErrors> {
+    let exists_result = series_repository::exists_by_id(pool, user_id, series_id).await;
+    handle_not_found_result::<SeriesDTO>(exists_result)
+}
+
+async fn get_series_image_filename(
+    pool: &PgPool,
+    user_id: &str,
+    series_id: &str,
+) -> Result<String, ApiErrors> {
+    let series = get_series(pool, user_id, series_id).await?;
+    series.image_filename.ok_or_else(|| {
+        ApiErrors::InvalidParameter(error_message_builder::empty_param("Series image"))
+    })
+}
+
+async fn set_series_image_filename(
+    pool: &PgPool,
+    user_id: &str,
+    series_id: &str,
+    filename: Option<String>,
+) -> Result<(), ApiErrors> {
+    let update_result =
+        series_repository::update_image_filename_by_id(pool, user_id, series_id, filename).await;
+    handle_action_result::<SeriesDTO>(update_result)
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::entities::{Availability, DLCPurchase, GamePurchase};
+use crate::errors::ApiErrors;
+use crate::models::{DurationDef, GameSpendingReviewDTO, SpendingReviewDTO};
+
+use super::base::check_start_end;
+use super::{dlc_available_service, game_available_service, game_logs_service};
+
+pub async fn get_spending_review(
+    pool: &PgPool,
+    user_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<SpendingReviewDTO, ApiErrors> {
+    check_start_end(start_date, end_date)?;
+
+    let game_purchases =
+        game_available_service::find_purchases_between(pool, user_id, start_date, end_date).await?;
+    let dlc_purchases =
+        dlc_available_service::find_purchases_between(pool, user_id, start_date, end_date).await?;
+
+    let mut review = build_review(game_purchases, dlc_purchases);
+
+    let game_ids = review.games.iter().map(|game| game.id.clone()).collect();
+    let total_times: HashMap<String, i64> =
+        game_logs_service::find_sum_game_logs_by_games(pool, user_id, game_ids)
+            .await?
+            .into_iter()
+            .map(|total| {
+                (
+                    total.game_id.to_string(),
+                    DurationDef::from(total.query_time).micros,
+                )
+            })
+            .collect();
+    for game in &mut review.games {
+        if let Some(micros) = total_times.get(&game.id) {
+            game.total_time = DurationDef::microseconds(*micros);
+        }
+        fill_cost_per_hour(game);
+    }
+
+    Ok(review)
+}
+
+fn build_review(
+    game_purchases: Vec<GamePurchase>,
+    dlc_purchases: Vec<DLCPurchase>,
+) -> SpendingReviewDTO {
+    let mut review = SpendingReviewDTO {
+        total_purchases: 0,
+        total_gifts: 0,
+        total_subscriptions: 0,
+        total_spent: HashMap::new(),
+        total_spent_by_month: HashMap::new(),
+        total_spent_by_platform: HashMap::new(),
+        total_spent_by_store: HashMap::new(),
+        games: vec![],
+    };
+    let mut games_map = HashMap::<String, GameSpendingReviewDTO>::new();
+
+    for purchase in game_purchases {
+        let availability = Availability {
+            added_date: purchase.query_date,
+            end_date: None,
+            price: purchase.price,
+            currency: purchase.currency,
+            store: purchase.store,
+            gift: purchase.gift,
+            subscription: purchase.subscription,
+        };
+        add_purchase(&mut review, &purchase.platform_name, &availability);
+
+        let game = games_map.entry(purchase.id.to_string()).or_insert_with(|| {
+            new_game_review(
+                purchase.id.to_string(),
+                purchase.name,
+                purchase.edition,
+                purchase.cover_filename,
+            )
+        });
+        add_spent(&mut game.total_spent, &availability);
+    }
+
+    for purchase in dlc_purchases {
+        let availability = Availability {
+            added_date: purchase.query_date,
+            end_date: None,
+            price: purchase.price,
+            currency: purchase.currency,
+            store: purchase.store,
+            gift: purchase.gift,
+            subscription: purchase.subscription,
+        };
+        add_purchase(&mut review, &purchase.platform_name, &availability);
+
+        // DLCs count towards their base game, if any
+        if let Some(base_game_id) = purchase.base_game_id {
+            let game = games_map
+                .entry(base_game_id.to_string())
+                .or_insert_with(|| {
+                    new_game_review(
+                        base_game_id.to_string(),
+                        purchase.base_game_name.unwrap_or_default(),
+                        purchase.base_game_edition.unwrap_or_default(),
+                        purchase.base_game_cover_filename,
+                    )
+                });
+            add_spent(&mut game.total_spent, &availability);
+        }
+    }
+
+    let mut games: Vec<GameSpendingReviewDTO> = games_map.into_values().collect();
+    games.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.edition.cmp(&b.edition)));
+    review.games = games;
+
+    review
+}
+
+fn add_purchase(review: &mut SpendingReviewDTO, platform_name: &str, availability: &Availability) {
+    review.total_purchases += 1;
+    if availability.gift {
+        review.total_gifts += 1;
+    }
+    if availability.subscription {
+        review.total_subscriptions += 1;
+    }
+
+    add_spent(&mut review.total_spent, availability);
+    add_spent(
+        review
+            .total_spent_by_month
+            .entry(availability.added_date.format("%Y-%m").to_string())
+            .or_default(),
+        availability,
+    );
+    add_spent(
+        review
+            .total_spent_by_platform
+            .entry(String::from(platform_name))
+            .or_default(),
+        availability,
+    );
+    if let Some(store) = &availability.store {
+        add_spent(
+            review
+                .total_spent_by_store
+                .entry(store.clone())
+                .or_default(),
+            availability,
+        );
+    }
+}
+
+fn add_spent(spent_map: &mut HashMap<String, i64>, availability: &Availability) {
+    if let (Some(price), Some(currency)) = (availability.price, &availability.currency) {
+        *spent_map.entry(currency.clone()).or_default() += price;
+    }
+}
+
+fn new_game_review(
+    id: String,
+    name: String,
+    edition: String,
+    cover_filename: Option<String>,
+) -> GameSpendingReviewDTO {
+    GameSpendingReviewDTO {
+        id,
+        name,
+        edition,
+        cover_filename,
+        cover_url: None,
+        total_spent: HashMap::new(),
+        total_time: DurationDef::microseconds(0),
+        cost_per_hour: HashMap::new(),
+    }
+}
+
+fn fill_cost_per_hour(game: &mut GameSpendingReviewDTO) {
+    if game.total_time.micros <= 0 {
+        return;
+    }
+
+    let micros_per_hour =
+        (crate::date_utils::SECONDS_PER_HOUR * crate::date_utils::MICROS_PER_SECOND) as f64;
+    let hours = game.total_time.micros as f64 / micros_per_hour;
+    game.cost_per_hour = game
+        .total_spent
+        .iter()
+        .map(|(currency, spent)| (currency.clone(), (*spent as f64 / hours).round() as i64))
+        .collect();
+}
@@ -1,16 +1,27 @@
+use std::collections::HashMap;
+
 use sqlx::PgPool;
+use uuid::Uuid;
 
-use crate::entities::TagSearch;
+use crate::entities::{TagDescendant, TagSearch};
 use crate::errors::ApiErrors;
-use crate::models::{NewTagDTO, SearchDTO, TagDTO, TagPageResult};
+use crate::models::{
+    FilterDTO, FilterGroupDTO, FilterNodeDTO, NewTagDTO, OperatorType, SearchDTO, SearchValue,
+    TagDTO, TagPageResult, TagTreeDTO,
+};
 use crate::repository::tag_repository;
 
 use super::base::{
     create_merged, handle_action_result, handle_already_exists_result, handle_create_result,
-    handle_get_list_paged_result, handle_get_result, handle_not_found_result, handle_query_mapping,
-    handle_update_result, update_merged,
+    handle_get_list_paged_result, handle_get_list_result, handle_get_result,
+    handle_not_found_result, handle_query_mapping, handle_result, handle_update_result,
+    update_merged,
 };
 
+/// Game search field of a tag including all of its descendants, translated into the tag field
+const TAG_TREE_FIELD: &str = "tag_tree";
+const TAG_FIELD: &str = "tag";
+
 pub async fn get_tag(pool: &PgPool, user_id: &str, tag_id: &str) -> Result<TagDTO, ApiErrors> {
     let find_result = tag_repository::find_by_id(pool, user_id, tag_id).await;
     handle_get_result(find_result)
@@ -27,7 +38,28 @@ pub async fn search_tags(
     handle_get_list_paged_result(find_result)
 }
 
+/// Every tag under its parent, sorted by name
+pub async fn get_tag_tree(pool: &PgPool, user_id: &str) -> Result<Vec<TagTreeDTO>, ApiErrors> {
+    let find_result = tag_repository::find_all(pool, user_id).await;
+    let tags: Vec<TagDTO> = handle_get_list_result(find_result)?;
+
+    let mut children_map = HashMap::<Option<String>, Vec<TagDTO>>::new();
+    for tag in tags {
+        children_map
+            .entry(tag.parent_id.clone())
+            .or_default()
+            .push(tag);
+    }
+
+    Ok(build_tree(None, &mut children_map))
+}
+
 pub async fn create_tag(pool: &PgPool, user_id: &str, tag: NewTagDTO) -> Result<TagDTO, ApiErrors> {
+    check_color(&tag.color)?;
+    if let Some(parent_id) = &tag.parent_id {
+        exists_tag(pool, user_id, parent_id).await?;
+    }
+
     create_merged(
         tag,
         async move |created_tag_id| get_tag(pool, user_id, &created_tag_id).await,
@@ -49,6 +81,22 @@ pub async fn update_tag(
     tag_id: &str,
     tag: NewTagDTO,
 ) -> Result<(), ApiErrors> {
+    check_color(&tag.color)?;
+    if let Some(parent_id) = &tag.parent_id {
+        exists_tag(pool, user_id, parent_id).await?;
+
+        let descendant_ids = find_descendant_ids(pool, user_id, vec![String::from(tag_id)]).await?;
+        let parent_id = canonical_id(parent_id);
+        if descendant_ids
+            .get(&canonical_id(tag_id))
+            .is_some_and(|ids| ids.contains(&parent_id))
+        {
+            return Err(ApiErrors::InvalidParameter(String::from(
+                "Tag cannot be under itself or one of its descendants",
+            )));
+        }
+    }
+
     update_merged(
         tag,
         async move || get_tag(pool, user_id, tag_id).await,
@@ -77,3 +125,147 @@ pub async fn exists_tag(pool: &PgPool, user_id: &str, tag_id: &str) -> Result<()
     let exists_result = tag_repository::exists_by_id(pool, user_id, tag_id).await;
     handle_not_found_result::<TagDTO>(exists_result)
 }
+
+/// Translates filters by a tag and its descendants into filters by any of those tags
+pub(super) async fn map_tag_tree_search(
+    pool: &PgPool,
+    user_id: &str,
+    mut search: SearchDTO,
+) -> Result<SearchDTO, ApiErrors> {
+    let mut tag_ids = vec![];
+    for_each_tag_tree_filter(&mut search, &mut |filter| {
+        tag_ids.extend(search_values(&filter.value))
+    });
+    if tag_ids.is_empty() {
+        return Ok(search);
+    }
+
+    let descendants_map = find_descendant_ids(pool, user_id, tag_ids).await?;
+    let mut map_result = Ok(());
+    for_each_tag_tree_filter(&mut search, &mut |filter| {
+        if let Err(error) = map_tag_tree_filter(filter, &descendants_map) {
+            map_result = Err(error);
+        }
+    });
+
+    map_result.map(|_| search)
+}
+
+/// Descendants of each of the tags by its id, including itself.
+/// Ids are in canonical form, see [canonical_id].
+async fn find_descendant_ids(
+    pool: &PgPool,
+    user_id: &str,
+    tag_ids: Vec<String>,
+) -> Result<HashMap<String, Vec<String>>, ApiErrors> {
+    let find_result = tag_repository::find_all_descendants_with_id_in(pool, user_id, tag_ids).await;
+    let descendants = handle_result::<Vec<TagDescendant>, TagDTO>(find_result)?;
+
+    let mut descendants_map = HashMap::<String, Vec<String>>::new();
+    for descendant in descendants {
+        descendants_map
+            .entry(descendant.root_id.to_string())
+            .or_default()
+            .push(descendant.id.to_string());
+    }
+    Ok(descendants_map)
+}
+
+fn build_tree(
+    parent_id: Option<String>,
+    children_map: &mut HashMap<Option<String>, Vec<TagDTO>>,
+) -> Vec<TagTreeDTO> {
+    children_map
+        .remove(&parent_id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|tag| {
+            let id = tag.id.clone();
+            let mut node = TagTreeDTO::from(tag);
+            node.children = build_tree(Some(id), children_map);
+            node
+        })
+        .collect()
+}
+
+fn check_color(color: &Option<String>) -> Result<(), ApiErrors> {
+    if let Some(color) = color {
+        let valid = color.len() == 7
+            && color.starts_with('#')
+            && color.chars().skip(1).all(|c| c.is_ascii_hexdigit());
+        if !valid {
+            return Err(ApiErrors::InvalidParameter(String::from(
+                "Color must be in #RRGGBB format",
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn for_each_tag_tree_filter(search: &mut SearchDTO, action: &mut impl FnMut(&mut FilterDTO)) {
+    for filter in search.filter.iter_mut().flatten() {
+        if filter.field == TAG_TREE_FIELD {
+            action(filter);
+        }
+    }
+    if let Some(filter_group) = &mut search.filter_group {
+        for_each_filter_group_tag_tree_filter(filter_group, action);
+    }
+}
+
+fn for_each_filter_group_tag_tree_filter(
+    filter_group: &mut FilterGroupDTO,
+    action: &mut impl FnMut(&mut FilterDTO),
+) {
+    for node in &mut filter_group.filters {
+        match node {
+            FilterNodeDTO::Filter(filter) if filter.field == TAG_TREE_FIELD => action(filter),
+            FilterNodeDTO::Filter(_) => (),
+            FilterNodeDTO::Group(group) => for_each_filter_group_tag_tree_filter(group, action),
+        }
+    }
+}
+
+fn map_tag_tree_filter(
+    filter: &mut FilterDTO,
+    descendants_map: &HashMap<String, Vec<String>>,
+) -> Result<(), ApiErrors> {
+    filter.field = String::from(TAG_FIELD);
+    let operator = match filter.operator {
+        OperatorType::Eq | OperatorType::In => OperatorType::In,
+        OperatorType::NotEq | OperatorType::NotIn => OperatorType::NotIn,
+        OperatorType::IsNull | OperatorType::IsNotNull => return Ok(()),
+        _ => {
+            return Err(ApiErrors::InvalidParameter(format!(
+                "Filter by {TAG_TREE_FIELD} only supports equal and in operators"
+            )))
+        }
+    };
+
+    // Unknown tags are kept, so they match nothing
+    let tag_ids = search_values(&filter.value)
+        .into_iter()
+        .flat_map(|tag_id| {
+            descendants_map
+                .get(&canonical_id(&tag_id))
+                .cloned()
+                .unwrap_or_else(|| vec![tag_id])
+        })
+        .collect();
+    filter.operator = operator;
+    filter.value = Some(SearchValue::Values(tag_ids));
+    Ok(())
+}
+
+/// Ids as returned by the database, so they can be compared regardless of how they were sent
+fn canonical_id(id: &str) -> String {
+    Uuid::parse_str(id).map_or_else(|_| String::from(id), |id| id.to_string())
+}
+
+fn search_values(value: &Option<SearchValue>) -> Vec<String> {
+    match value {
+        Some(SearchValue::Value(value)) => vec![value.clone()],
+        Some(SearchValue::Values(values)) => values.clone(),
+        None => vec![],
+    }
+}
@@ -0,0 +1,187 @@
+use sqlx::PgPool;
+
+use crate::entities::UserTotp;
+use crate::errors::{error_message_builder, ApiErrors};
+use crate::models::{ModelInfo, TotpCodeDTO, TotpEnrollmentDTO, TotpRecoveryCodesDTO};
+use crate::repository::user_totp_repository;
+
+use super::base::{handle_action_result, handle_get_result_raw, handle_result};
+use super::{login_attempts_service, users_service};
+
+pub async fn enroll_totp(pool: &PgPool, user_id: &str) -> Result<TotpEnrollmentDTO, ApiErrors> {
+    let user = users_service::get_user(pool, user_id).await?;
+
+    let secret = crate::auth::generate_totp_secret()
+        .map_err(|_| ApiErrors::UnknownError(String::from("TOTP secret generation error.")))?;
+
+    let create_result = user_totp_repository::create(pool, user_id, &secret).await;
+    let created = handle_result::<bool, TotpEnrollmentDTO>(create_result)?;
+    if !created {
+        return Err(totp_already_enabled_error());
+    }
+
+    let otpauth_uri = crate::auth::build_totp_uri(&secret, &user.username);
+    Ok(TotpEnrollmentDTO {
+        secret,
+        otpauth_uri,
+    })
+}
+
+pub async fn activate_totp(
+    pool: &PgPool,
+    user_id: &str,
+    code: TotpCodeDTO,
+) -> Result<TotpRecoveryCodesDTO, ApiErrors> {
+    let user_totp = get_user_totp(pool, user_id).await?;
+    if user_totp.enabled {
+        return Err(totp_already_enabled_error());
+    }
+
+    check_totp_locked(pool, user_id).await?;
+    let step = match verify_totp_code(&user_totp, &code.code)? {
+        Some(step) => step,
+        None => return Err(register_wrong_code(pool, user_id).await),
+    };
+    login_attempts_service::reset_totp_attempts(pool, user_id).await?;
+
+    let (recovery_codes, recovery_code_hashes) = generate_recovery_codes()?;
+    let update_result =
+        user_totp_repository::update_enabled(pool, user_id, step, &recovery_code_hashes).await;
+    handle_action_result::<TotpEnrollmentDTO>(update_result)?;
+
+    Ok(TotpRecoveryCodesDTO { recovery_codes })
+}
+
+pub async fn regenerate_recovery_codes(
+    pool: &PgPool,
+    user_id: &str,
+    code: TotpCodeDTO,
+) -> Result<TotpRecoveryCodesDTO, ApiErrors> {
+    let user_totp = get_enabled_user_totp(pool, user_id).await?;
+
+    check_totp_locked(pool, user_id).await?;
+    let step = match verify_totp_code(&user_totp, &code.code)? {
+        Some(step) => step,
+        None => return Err(register_wrong_code(pool, user_id).await),
+    };
+    login_attempts_service::reset_totp_attempts(pool, user_id).await?;
+
+    let (recovery_codes, recovery_code_hashes) = generate_recovery_codes()?;
+    let update_result =
+        user_totp_repository::replace_recovery_codes(pool, user_id, step, &recovery_code_hashes)
+            .await;
+    let replaced = handle_result::<bool, TotpEnrollmentDTO>(update_result)?;
+    if !replaced {
+        // The code was used at the same time
+        return Err(wrong_code_error());
+    }
+
+    Ok(TotpRecoveryCodesDTO { recovery_codes })
+}
+
+pub async fn disable_totp(
+    pool: &PgPool,
+    user_id: &str,
+    code: TotpCodeDTO,
+) -> Result<(), ApiErrors> {
+    get_enabled_user_totp(pool, user_id).await?;
+
+    check_totp_locked(pool, user_id).await?;
+    if !verify_login_code(pool, user_id, &code.code).await? {
+        return Err(register_wrong_code(pool, user_id).await);
+    }
+    login_attempts_service::reset_totp_attempts(pool, user_id).await?;
+
+    let delete_result = user_totp_repository::delete_by_user_id(pool, user_id).await;
+    handle_action_result::<TotpEnrollmentDTO>(delete_result)
+}
+
+pub async fn is_totp_enabled(pool: &PgPool, user_id: &str) -> Result<bool, ApiErrors> {
+    let find_result = user_totp_repository::find_by_user_id(pool, user_id).await;
+    let user_totp = handle_result::<Option<UserTotp>, TotpEnrollmentDTO>(find_result)?;
+    Ok(user_totp.is_some_and(|totp| totp.enabled))
+}
+
+/// Accepts either a TOTP code or an unused recovery code, which gets consumed
+pub async fn verify_login_code(
+    pool: &PgPool,
+    user_id: &str,
+    code: &str,
+) -> Result<bool, ApiErrors> {
+    let user_totp = get_enabled_user_totp(pool, user_id).await?;
+
+    if let Some(step) = verify_totp_code(&user_totp, code)? {
+        // Rejected if the code was used at the same time
+        let update_result = user_totp_repository::update_last_used_step(pool, user_id, step).await;
+        return handle_result::<bool, TotpEnrollmentDTO>(update_result);
+    }
+
+    let code_hash = crate::auth::hash_recovery_code(code);
+    let delete_result = user_totp_repository::delete_recovery_code(pool, user_id, &code_hash).await;
+    handle_result::<bool, TotpEnrollmentDTO>(delete_result)
+}
+
+async fn get_user_totp(pool: &PgPool, user_id: &str) -> Result<UserTotp, ApiErrors> {
+    let find_result = user_totp_repository::find_by_user_id(pool, user_id).await;
+    handle_get_result_raw::<UserTotp, TotpEnrollmentDTO>(find_result)
+}
+
+async fn get_enabled_user_totp(pool: &PgPool, user_id: &str) -> Result<UserTotp, ApiErrors> {
+    let user_totp = get_user_totp(pool, user_id).await?;
+    if !user_totp.enabled {
+        return Err(ApiErrors::InvalidParameter(String::from(
+            "Two-factor authentication is not enabled.",
+        )));
+    }
+    Ok(user_totp)
+}
+
+fn verify_totp_code(user_totp: &UserTotp, code: &str) -> Result<Option<i64>, ApiErrors> {
+    let timestamp = crate::date_utils::now().and_utc().timestamp();
+    let step = crate::auth::verify_totp_code(&user_totp.secret, code, timestamp)
+        .map_err(|_| ApiErrors::UnknownError(String::from("TOTP code verification failed.")))?;
+
+    // Codes cannot be reused
+    Ok(step.filter(|step| {
+        user_totp
+            .last_used_step
+            .map_or(true, |last_used_step| *step > last_used_step)
+    }))
+}
+
+fn generate_recovery_codes() -> Result<(Vec<String>, Vec<String>), ApiErrors> {
+    let recovery_codes = crate::auth::generate_recovery_codes()
+        .map_err(|_| ApiErrors::UnknownError(String::from("Recovery codes generation error.")))?;
+    let recovery_code_hashes = recovery_codes
+        .iter()
+        .map(|code| crate::auth::hash_recovery_code(code))
+        .collect();
+    Ok((recovery_codes, recovery_code_hashes))
+}
+
+fn totp_already_enabled_error() -> ApiErrors {
+    ApiErrors::AlreadyExists(error_message_builder::already_exists(
+        TotpEnrollmentDTO::MODEL_NAME,
+        TotpEnrollmentDTO::ID_FIELDS,
+    ))
+}
+
+async fn check_totp_locked(pool: &PgPool, user_id: &str) -> Result<(), ApiErrors> {
+    if login_attempts_service::is_totp_locked(pool, user_id).await? {
+        return Err(ApiErrors::TooManyAttempts(String::from(
+            "Too many wrong codes. Try again later.",
+        )));
+    }
+    Ok(())
+}
+
+async fn register_wrong_code(pool: &PgPool, user_id: &str) -> ApiErrors {
+    match login_attempts_service::register_failed_totp(pool, user_id).await {
+        Ok(()) => wrong_code_error(),
+        Err(err) => err,
+    }
+}
+
+fn wrong_code_error() -> ApiErrors {
+    ApiErrors::InvalidParameter(String::from("Wrong code."))
+}
@@ -2,7 +2,10 @@ use sqlx::PgPool;
 
 use crate::entities::{User, UserSearch};
 use crate::errors::ApiErrors;
-use crate::models::{NewUserDTO, PasswordChangeDTO, SearchDTO, UserDTO, UserPageResult};
+use crate::models::{
+    NewUserDTO, PasswordChangeDTO, PasswordPolicy, RegistrationConfig, RegistrationDTO,
+    RegistrationMode, SearchDTO, UserDTO, UserPageResult,
+};
 use crate::repository::user_repository;
 
 use super::base::{
@@ -11,6 +14,7 @@ use super::base::{
     handle_not_found_result, handle_query_mapping, handle_result, handle_update_result,
     update_merged,
 };
+use super::login_attempts_service;
 
 pub async fn get_user(pool: &PgPool, user_id: &str) -> Result<UserDTO, ApiErrors> {
     let repository_result = user_repository::find_by_id(pool, user_id).await;
@@ -29,8 +33,29 @@ pub async fn search_users(
 
 pub async fn create_user(
     pool: &PgPool,
+    password_policy: &PasswordPolicy,
     user: NewUserDTO,
     password: &str,
+) -> Result<UserDTO, ApiErrors> {
+    check_password_policy(password_policy, &user.username, password)?;
+
+    create_user_with_password(pool, user, password, false).await
+}
+
+/// Creates a user whose password must be changed on first login, so no policy is enforced
+pub async fn create_user_with_temporary_password(
+    pool: &PgPool,
+    user: NewUserDTO,
+    temporary_password: &str,
+) -> Result<UserDTO, ApiErrors> {
+    create_user_with_password(pool, user, temporary_password, true).await
+}
+
+async fn create_user_with_password(
+    pool: &PgPool,
+    user: NewUserDTO,
+    password: &str,
+    must_change_password: bool,
 ) -> Result<UserDTO, ApiErrors> {
     create_merged(
         user,
@@ -41,14 +66,90 @@ pub async fn create_user(
 
             let password_hash = crate::auth::hash_password(password)
                 .map_err(|_| ApiErrors::UnknownError(String::from("Password hashing error.")))?;
-            let create_result =
-                user_repository::create(pool, &password_hash, &user_to_create).await;
+            let create_result = user_repository::create(
+                pool,
+                &password_hash,
+                must_change_password,
+                &user_to_create,
+            )
+            .await;
             handle_create_result::<String, UserDTO>(create_result)
         },
     )
     .await
 }
 
+pub async fn register_user(
+    pool: &PgPool,
+    config: &RegistrationConfig,
+    password_policy: &PasswordPolicy,
+    registration: RegistrationDTO,
+) -> Result<UserDTO, ApiErrors> {
+    if crate::string_utils::string_is_blank(&registration.username)
+        || crate::string_utils::string_is_blank(&registration.password)
+    {
+        return Err(ApiErrors::InvalidParameter(String::from(
+            "Username and password are required.",
+        )));
+    }
+
+    let user = NewUserDTO {
+        username: registration.username,
+    };
+    match config.mode {
+        RegistrationMode::Closed => Err(ApiErrors::NotSupported(String::from(
+            "Registration is disabled.",
+        ))),
+        RegistrationMode::Open => {
+            create_user(pool, password_policy, user, &registration.password).await
+        }
+        RegistrationMode::Invitation => {
+            let invitation_code = match registration.invitation_code {
+                Some(code) if !crate::string_utils::string_is_blank(&code) => code,
+                _ => {
+                    return Err(ApiErrors::InvalidParameter(String::from(
+                        "Invitation code is required.",
+                    )))
+                }
+            };
+            check_password_policy(password_policy, &user.username, &registration.password)?;
+            create_invited_user(pool, user, &registration.password, &invitation_code).await
+        }
+    }
+}
+
+async fn create_invited_user(
+    pool: &PgPool,
+    user: NewUserDTO,
+    password: &str,
+    invitation_code: &str,
+) -> Result<UserDTO, ApiErrors> {
+    create_merged(
+        user,
+        async move |created_user_id| get_user(pool, &created_user_id).await,
+        async move |user_to_create| {
+            let exists_result = user_repository::exists_with_unique(pool, &user_to_create).await;
+            handle_already_exists_result::<UserDTO>(exists_result)?;
+
+            let password_hash = crate::auth::hash_password(password)
+                .map_err(|_| ApiErrors::UnknownError(String::from("Password hashing error.")))?;
+            let create_result = user_repository::create_with_invitation(
+                pool,
+                &password_hash,
+                &user_to_create,
+                invitation_code,
+            )
+            .await;
+            handle_create_result::<Option<String>, UserDTO>(create_result)?.ok_or_else(|| {
+                ApiErrors::InvalidParameter(String::from(
+                    "Invitation code is not valid or has expired.",
+                ))
+            })
+        },
+    )
+    .await
+}
+
 pub async fn update_user(pool: &PgPool, user_id: &str, user: NewUserDTO) -> Result<(), ApiErrors> {
     update_merged(
         user,
@@ -67,6 +168,7 @@ pub async fn update_user(pool: &PgPool, user_id: &str, user: NewUserDTO) -> Resu
 
 pub async fn change_user_password(
     pool: &PgPool,
+    password_policy: &PasswordPolicy,
     user_id: &str,
     password_change: PasswordChangeDTO,
 ) -> Result<(), ApiErrors> {
@@ -77,15 +179,71 @@ pub async fn change_user_password(
         crate::auth::verify_password(&password_change.current_password, &user.password)
             .map_err(|_| ApiErrors::UnknownError(String::from("Password verification failed.")))?;
 
-    if verify_pass {
-        let password_hash = crate::auth::hash_password(&password_change.new_password)
-            .map_err(|_| ApiErrors::UnknownError(String::from("Password hashing error.")))?;
+    if !verify_pass {
+        return Err(ApiErrors::InvalidParameter(String::from("Wrong password.")));
+    }
 
-        let update_result = user_repository::update_password(pool, user_id, &password_hash).await;
-        handle_update_result::<UserDTO>(update_result)
-    } else {
-        Err(ApiErrors::InvalidParameter(String::from("Wrong password.")))
+    if password_change.new_password == password_change.current_password {
+        return Err(ApiErrors::InvalidParameter(String::from(
+            "New password must be different from current password.",
+        )));
     }
+
+    check_password_policy(
+        password_policy,
+        &user.username,
+        &password_change.new_password,
+    )?;
+
+    let password_hash = crate::auth::hash_password(&password_change.new_password)
+        .map_err(|_| ApiErrors::UnknownError(String::from("Password hashing error.")))?;
+
+    let update_result =
+        user_repository::update_password(pool, user_id, &password_hash, false).await;
+    handle_update_result::<UserDTO>(update_result)
+}
+
+/// Sets a temporary password the user will be forced to change on next login
+pub async fn reset_user_password(
+    pool: &PgPool,
+    user_id: &str,
+    temporary_password: &str,
+) -> Result<(), ApiErrors> {
+    exists_user(pool, user_id).await?;
+
+    if crate::string_utils::string_is_blank(temporary_password) {
+        return Err(ApiErrors::InvalidParameter(String::from(
+            "Password is required.",
+        )));
+    }
+
+    let password_hash = crate::auth::hash_password(temporary_password)
+        .map_err(|_| ApiErrors::UnknownError(String::from("Password hashing error.")))?;
+
+    let update_result = user_repository::update_password(pool, user_id, &password_hash, true).await;
+    handle_update_result::<UserDTO>(update_result)
+}
+
+/// Replaces the stored hash with one using the current default algorithm
+pub async fn rehash_user_password(
+    pool: &PgPool,
+    user_id: &str,
+    password: &str,
+) -> Result<(), ApiErrors> {
+    let password_hash = crate::auth::hash_password(password)
+        .map_err(|_| ApiErrors::UnknownError(String::from("Password hashing error.")))?;
+
+    let update_result = user_repository::update_password_hash(pool, user_id, &password_hash).await;
+    handle_update_result::<UserDTO>(update_result)
+}
+
+fn check_password_policy(
+    password_policy: &PasswordPolicy,
+    username: &str,
+    password: &str,
+) -> Result<(), ApiErrors> {
+    crate::auth::check_password_policy(password_policy, username, password)
+        .map_err(|err| ApiErrors::InvalidParameter(err.0))
 }
 
 pub async fn promote_user(pool: &PgPool, user_id: &str) -> Result<(), ApiErrors> {
@@ -113,6 +271,12 @@ async fn change_user_admin(pool: &PgPool, user_id: &str, admin: bool) -> Result<
     handle_update_result::<UserDTO>(update_result)
 }
 
+pub async fn unlock_user(pool: &PgPool, user_id: &str) -> Result<(), ApiErrors> {
+    let user = get_user(pool, user_id).await?;
+
+    login_attempts_service::unlock_username(pool, &user.username).await
+}
+
 pub async fn delete_user(pool: &PgPool, user_id: &str) -> Result<(), ApiErrors> {
     exists_user(pool, user_id).await?;
 